@@ -60,7 +60,7 @@ impl AffectedVersionLister {
             .unwrap_or_else(|| panic!("expected crate {} to exist", crate_name));
         for version in crate_info.versions {
             let parsed_version = rustsec::Version::parse(&version.version).unwrap();
-            if advisory.versions.is_vulnerable(&parsed_version) {
+            if advisory.is_version_affected(&parsed_version) {
                 println!("{} vulnerable", version.version)
             } else {
                 println!("{} OK", version.version)
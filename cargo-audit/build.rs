@@ -0,0 +1,7 @@
+//! Records the target `cargo-audit` is built for, which is the host whose
+//! advisories lockfile scans report by default
+
+fn main() {
+    let target = std::env::var("TARGET").expect("TARGET is set by cargo");
+    println!("cargo:rustc-env=CARGO_AUDIT_HOST_TARGET={}", target);
+}
@@ -1,20 +1,28 @@
 //! Core auditing functionality
 
 use crate::{
-    binary_format::BinaryFormat, config::AuditConfig, error::display_err_with_source, prelude::*,
-    presenter::Presenter,
+    binary_format::BinaryFormat, config::AuditConfig, error::display_err_with_source, lockfile,
+    prelude::*, presenter::Presenter,
+};
+use rustsec::{
+    advisory, package, registry, report, repository::git::FetchOptions, Error, ErrorKind, Lockfile,
+    Warning, WarningKind,
 };
-use rustsec::{registry, report, Error, ErrorKind, Lockfile, Warning, WarningKind};
 use std::{
+    borrow::Cow,
+    fs,
     io::{self, Read},
     path::Path,
-    process::exit,
+    process::{exit, Command},
     time::Duration,
 };
 
 // TODO: make configurable
 const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5 * 60);
 
+/// How long cached lookups from a sparse crates.io index are reused for
+const INDEX_CACHE_MAX_AGE: Duration = Duration::from_secs(60 * 60);
+
 /// Security vulnerability auditor
 pub struct Auditor {
     /// RustSec Advisory Database
@@ -28,6 +36,12 @@ pub struct Auditor {
 
     /// Audit report settings
     report_settings: report::Settings,
+
+    /// Crates whose advisories are all ignored
+    ignore_crates: Vec<package::Name>,
+
+    /// Glob patterns for lockfile paths whose findings are excluded
+    exclude_paths: Vec<String>,
 }
 
 impl Auditor {
@@ -47,27 +61,35 @@ impl Auditor {
             .cloned()
             .unwrap_or_else(rustsec::repository::git::Repository::default_path);
 
-        let database = if config.database.fetch {
+        if config.database.locked && config.database.commit.is_some() {
+            status_err!(
+                "the advisory database can't be pinned to a commit when it's locked \
+                (`database.commit` or --db-commit with `database.locked` or --locked-db)"
+            );
+            exit(1);
+        }
+
+        let database = if config.database.locked {
+            Self::load_locked_database(config, &advisory_db_path)
+        } else if config.database.fetch {
             if !config.output.is_quiet() {
                 status_ok!("Fetching", "advisory database from `{}`", advisory_db_url);
             }
 
-            let mut result = rustsec::repository::git::Repository::fetch(
+            let mut result = rustsec::repository::git::Repository::fetch_with_options(
                 advisory_db_url,
                 &advisory_db_path,
-                !config.database.stale,
-                Duration::from_secs(0),
+                &Self::fetch_options(config, Duration::from_secs(0)),
             );
             // If the directory is locked, print a message and wait for it to become unlocked.
             // If we don't print the message, `cargo audit` would just hang with no explanation.
             if let Err(e) = &result {
                 if e.kind() == ErrorKind::LockTimeout {
                     status_warn!("directory {} is locked, waiting for up to {} seconds for it to become available", advisory_db_path.display(), DEFAULT_LOCK_TIMEOUT.as_secs());
-                    result = rustsec::repository::git::Repository::fetch(
+                    result = rustsec::repository::git::Repository::fetch_with_options(
                         advisory_db_url,
                         &advisory_db_path,
-                        !config.database.stale,
-                        DEFAULT_LOCK_TIMEOUT,
+                        &Self::fetch_options(config, DEFAULT_LOCK_TIMEOUT),
                     );
                 }
             }
@@ -80,7 +102,19 @@ impl Auditor {
                 exit(1);
             });
 
-            rustsec::Database::load_from_repo(&advisory_db_repo).unwrap_or_else(|e| {
+            if !config.output.is_quiet() {
+                if let Some(status) = advisory_db_repo.fetch_status() {
+                    status_ok!("Fetched", "advisory database ({})", status);
+                }
+            }
+
+            if config.database.prune {
+                Self::prune_database(config, &advisory_db_repo);
+            }
+
+            // The fetch keeps its lock, so a concurrent fetch can't change
+            // the checkout before it's loaded
+            Self::load_from_repo(config, &advisory_db_repo).unwrap_or_else(|e| {
                 status_err!(
                     "error loading advisory database: {}",
                     display_err_with_source(&e)
@@ -88,7 +122,21 @@ impl Auditor {
                 exit(1);
             })
         } else {
-            rustsec::Database::open(&advisory_db_path).unwrap_or_else(|e| {
+            // Record the commit when the database is a git checkout, without fetching
+            // Pinning to a commit modifies the checkout, so it needs the exclusive lock
+            let opened = Self::open_locked(&advisory_db_path, config.database.commit.is_some());
+
+            let result = match opened {
+                Ok(repo) => Self::load_from_repo(config, &repo),
+                // Pinning to a commit requires a git checkout
+                Err(e) if config.database.commit.is_some() => Err(e),
+                Err(_) => rustsec::Database::open_with_options(
+                    &advisory_db_path,
+                    &Self::open_options(config),
+                ),
+            };
+
+            result.unwrap_or_else(|e| {
                 status_err!(
                     "error loading advisory database: {}",
                     display_err_with_source(&e)
@@ -104,10 +152,56 @@ impl Auditor {
                 database.iter().count(),
                 advisory_db_path.display()
             );
+
+            let orphaned = database.orphaned_advisories();
+            if !orphaned.is_empty() {
+                status_warn!(
+                    "advisory database checkout contains {} {} not in its latest commit \
+                    (stale checkout?): {}",
+                    orphaned.len(),
+                    if orphaned.len() == 1 {
+                        "advisory"
+                    } else {
+                        "advisories"
+                    },
+                    orphaned
+                        .iter()
+                        .map(|path| path.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+
+            let skipped = &database.load_report().skipped_symlinks;
+            if !skipped.is_empty() {
+                status_warn!(
+                    "skipped {} symlinked {} in the advisory database: {}",
+                    skipped.len(),
+                    if skipped.len() == 1 {
+                        "entry"
+                    } else {
+                        "entries"
+                    },
+                    skipped
+                        .iter()
+                        .map(|path| path.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+
+            if let Some(commit) = database.latest_commit() {
+                status_ok!(
+                    "Using",
+                    "advisory database commit {} ({})",
+                    commit.commit_id.to_hex(),
+                    commit.timestamp
+                );
+            }
         }
 
         let registry_index = if config.yanked.enabled {
-            if config.yanked.update_index && config.database.fetch {
+            if config.yanked.update_index && config.database.should_fetch() {
                 if !config.output.is_quiet() {
                     status_ok!("Updating", "crates.io index");
                 }
@@ -124,7 +218,16 @@ impl Auditor {
                 }
 
                 match result {
-                    Ok(index) => Some(index),
+                    Ok(mut index) => {
+                        // Release dates for `unmaintained` warnings come from
+                        // the crates.io API, since the index doesn't record them
+                        if let Err(err) = index.release_dates_from_api(None) {
+                            if !config.output.is_quiet() {
+                                status_warn!("couldn't look up crate release dates: {}", err);
+                            }
+                        }
+                        Some(index)
+                    }
                     Err(err) => {
                         if !config.output.is_quiet() {
                             status_warn!("couldn't update crates.io index: {}", err);
@@ -160,12 +263,196 @@ impl Auditor {
             None
         };
 
+        let registry_index = registry_index.map(|index| match config.yanked.cache_dir() {
+            Some(dir) => index.disk_cache(dir, INDEX_CACHE_MAX_AGE),
+            None => index,
+        });
+
         Self {
             database,
             registry_index,
             presenter: Presenter::new(&config.output),
-            report_settings: config.report_settings(),
+            report_settings: Self::report_settings(config),
+            ignore_crates: config.advisories.ignore_crates.clone(),
+            exclude_paths: config.advisories.exclude_paths.clone(),
+        }
+    }
+
+    /// Report settings from the configuration, including the reachable
+    /// functions from the `--reachability` hint file, if any
+    fn report_settings(config: &AuditConfig) -> report::Settings {
+        let mut settings = config.report_settings();
+
+        if let Some(path) = &config.advisories.reachability {
+            let result = fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|hints| toml::from_str(&hints).map_err(|e| e.to_string()));
+
+            settings.reachable_functions = result.unwrap_or_else(|e| {
+                status_err!("couldn't load reachability hints {}: {}", path.display(), e);
+                exit(2);
+            });
         }
+
+        settings
+    }
+
+    /// Options for fetching the advisory database, waiting up to `lock_timeout` for its lock
+    fn fetch_options(config: &AuditConfig, lock_timeout: Duration) -> FetchOptions {
+        FetchOptions::new()
+            .ensure_fresh(!config.database.stale)
+            .lock_timeout(lock_timeout)
+            .keep_lock(true)
+            .url_mismatch(config.database.url_mismatch)
+            .ca_bundle_from_env()
+    }
+
+    /// Garbage-collect the advisory database checkout for `--prune-db`.
+    ///
+    /// gix can't repack objects yet, so this runs `git gc`. `repo` holds
+    /// the exclusive lock from fetching, so nothing else reads the checkout
+    /// while it's repacked. Failing to prune doesn't stop the audit.
+    fn prune_database(config: &AuditConfig, repo: &rustsec::repository::git::Repository) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(repo.path())
+            .args(["gc", "--prune=now", "--quiet"])
+            .status();
+
+        match status {
+            Ok(status) if status.success() => {
+                if !config.output.is_quiet() {
+                    status_ok!("Pruned", "advisory database checkout");
+                }
+            }
+            Ok(status) => status_warn!("couldn't prune advisory database: `git gc` {}", status),
+            Err(e) => status_warn!(
+                "couldn't prune advisory database (is git installed?): {}",
+                e
+            ),
+        }
+    }
+
+    /// Load the advisory database from `repo`, at the commit given with
+    /// `--db-commit` if any.
+    ///
+    /// The checkout is returned to the commit it was at afterwards, so later
+    /// audits without `--db-commit` aren't pinned too. `repo` must hold the
+    /// exclusive lock if a commit is given.
+    fn load_from_repo(
+        config: &AuditConfig,
+        repo: &rustsec::repository::git::Repository,
+    ) -> rustsec::Result<rustsec::Database> {
+        let options = Self::open_options(config);
+        let Some(commit) = &config.database.commit else {
+            return rustsec::Database::load_from_repo_with_options(repo, &options);
+        };
+
+        let checkout = |id| {
+            repo.checkout_commit(id).unwrap_or_else(|e| {
+                status_err!(
+                    "couldn't check out advisory database commit: {}",
+                    display_err_with_source(&e)
+                );
+                exit(1);
+            })
+        };
+
+        let previous = repo.latest_commit()?.commit_id;
+        checkout(commit.parse()?);
+
+        if !config.output.is_quiet() {
+            status_ok!("Pinned", "advisory database to commit {}", commit);
+        }
+
+        let result = rustsec::Database::load_from_repo_with_options(repo, &options);
+        checkout(previous);
+        result
+    }
+
+    /// Open the advisory database repository with a shared lock (or the
+    /// exclusive one if `for_writing`), so that a concurrent fetch can't
+    /// modify it while it's being read
+    fn open_locked(
+        path: &Path,
+        for_writing: bool,
+    ) -> rustsec::Result<rustsec::repository::git::Repository> {
+        let open = |lock_timeout| {
+            if for_writing {
+                rustsec::repository::git::Repository::open_with_write_lock(path, lock_timeout)
+            } else {
+                rustsec::repository::git::Repository::open_with_read_lock(path, lock_timeout)
+            }
+        };
+
+        // As when fetching, explain why we're waiting rather than just hanging
+        match open(Duration::from_secs(0)) {
+            Err(e) if e.kind() == ErrorKind::LockTimeout => {
+                status_warn!(
+                    "directory {} is being updated, waiting for up to {} seconds for it to become available",
+                    path.display(),
+                    DEFAULT_LOCK_TIMEOUT.as_secs()
+                );
+                open(DEFAULT_LOCK_TIMEOUT)
+            }
+            result => result,
+        }
+    }
+
+    /// Options for loading the advisory database
+    fn open_options(config: &AuditConfig) -> rustsec::database::OpenOptions {
+        // `--show-raw` needs the TOML source of each advisory
+        let options = rustsec::database::OpenOptions::new()
+            .retain_raw_source(config.output.show_raw)
+            .require_collections(config.database.require_collections.iter().copied());
+
+        #[cfg(feature = "manifest-signature")]
+        let options = match config.database.manifest_key {
+            Some(key) => options.verify_manifest(key),
+            None => options,
+        };
+
+        options
+    }
+
+    /// Load the advisory database for `--locked-db`, without fetching or modifying it
+    fn load_locked_database(config: &AuditConfig, path: &Path) -> rustsec::Database {
+        let repo = Self::open_locked(path, false).unwrap_or_else(|e| {
+            status_err!(
+                "advisory database not found (required by --locked-db): {}",
+                display_err_with_source(&e)
+            );
+            exit(1);
+        });
+
+        let database =
+            rustsec::Database::load_from_repo_with_options(&repo, &Self::open_options(config))
+                .unwrap_or_else(|e| {
+                    status_err!(
+                        "error loading advisory database: {}",
+                        display_err_with_source(&e)
+                    );
+                    exit(1);
+                });
+
+        let commit = database
+            .latest_commit()
+            .expect("database loaded from a repository has a commit");
+
+        if !config.database.stale && !commit.is_fresh() {
+            status_err!(
+                "advisory database is stale (last commit: {}) and --locked-db forbids updating it",
+                commit.timestamp
+            );
+            exit(1);
+        }
+
+        status_ok!(
+            "Locked",
+            "advisory database at commit {}",
+            commit.commit_id.to_hex()
+        );
+        database
     }
 
     /// Perform an audit of a textual `Cargo.lock` file
@@ -181,9 +468,11 @@ impl Auditor {
             }
         };
 
+        let ignore = lockfile::sidecar_ignores(lockfile_path)?;
+
         self.presenter.before_report(lockfile_path, &lockfile);
 
-        let report = self.audit(&lockfile, None, None);
+        let report = self.audit(&lockfile, None, None, &ignore);
 
         let self_advisories = self.self_advisories();
 
@@ -194,6 +483,57 @@ impl Auditor {
         report
     }
 
+    /// Perform an audit of multiple `Cargo.lock` files
+    pub fn audit_lockfiles<P>(&mut self, lockfile_paths: &[P]) -> MultiFileReportSummmary
+    where
+        P: AsRef<Path>,
+    {
+        let mut summary = MultiFileReportSummmary::default();
+        for path in lockfile_paths {
+            let path = path.as_ref();
+            let result = self
+                .load_lockfile(path)
+                .map_err(|e| {
+                    Error::with_source(
+                        ErrorKind::NotFound,
+                        format!("Couldn't load {}", path.display()),
+                        e,
+                    )
+                })
+                .and_then(|lockfile| {
+                    let ignore = lockfile::sidecar_ignores(path)?;
+                    self.presenter.before_report(path, &lockfile);
+                    self.audit(&lockfile, Some(path), None, &ignore)
+                });
+
+            match result {
+                Ok(report) => {
+                    if self.presenter.should_exit_with_failure(&report) {
+                        summary.vulnerabilities_found = true;
+                    }
+                }
+                Err(e) => {
+                    status_err!("{}", display_err_with_source(&e));
+                    summary.errors_encountered = true;
+                }
+            }
+        }
+
+        let self_advisories = self.self_advisories();
+
+        if self.presenter.is_print_report() {
+            self.presenter.print_self_report(self_advisories.as_slice());
+        }
+
+        if self
+            .presenter
+            .should_exit_with_failure_due_to_self(&self_advisories)
+        {
+            summary.errors_encountered = true;
+        }
+        summary
+    }
+
     #[cfg(feature = "binary-scanning")]
     /// Perform an audit of multiple binary files
     pub fn audit_binaries<P>(&mut self, binaries: &[P]) -> MultiFileReportSummmary
@@ -201,13 +541,18 @@ impl Auditor {
         P: AsRef<Path>,
     {
         let mut summary = MultiFileReportSummmary::default();
+        let mut json_reports = std::collections::BTreeMap::new();
         for path in binaries {
-            let result = self.audit_binary(path.as_ref());
+            let result = self.audit_binary(path.as_ref(), &mut summary);
             match result {
                 Ok(report) => {
                     if self.presenter.should_exit_with_failure(&report) {
                         summary.vulnerabilities_found = true;
                     }
+
+                    if self.presenter.is_json() {
+                        json_reports.insert(path.as_ref().display().to_string(), report);
+                    }
                 }
                 Err(e) => {
                     status_err!("{}", display_err_with_source(&e));
@@ -216,6 +561,10 @@ impl Auditor {
             }
         }
 
+        if self.presenter.is_json() && self.presenter.is_print_report() {
+            self.presenter.print_json_reports(&json_reports);
+        }
+
         let self_advisories = self.self_advisories();
 
         self.presenter.print_self_report(self_advisories.as_slice());
@@ -231,13 +580,24 @@ impl Auditor {
 
     #[cfg(feature = "binary-scanning")]
     /// Perform an audit of a binary file with dependency data embedded by `cargo auditable`
-    fn audit_binary(&mut self, binary_path: &Path) -> rustsec::Result<rustsec::Report> {
+    ///
+    /// Binaries without `cargo auditable` data are recorded in the `summary`.
+    fn audit_binary(
+        &mut self,
+        binary_path: &Path,
+        summary: &mut MultiFileReportSummmary,
+    ) -> rustsec::Result<rustsec::Report> {
         use crate::binary_deps::BinaryReport::*;
         let (binary_type, report) = crate::binary_deps::load_deps_from_binary(binary_path)?;
         self.presenter.binary_scan_report(&report, binary_path);
+
+        if !matches!(report, Complete(_)) {
+            summary.missing_audit_info.push(binary_path.to_owned());
+        }
+
         match report {
             Complete(lockfile) | Incomplete(lockfile) => {
-                self.audit(&lockfile, Some(binary_path), Some(binary_type))
+                self.audit(&lockfile, Some(binary_path), Some(binary_type), &[])
             }
             None => Err(Error::new(
                 ErrorKind::Parse,
@@ -247,14 +607,25 @@ impl Auditor {
     }
 
     /// The part of the auditing process that is shared between auditing lockfiles and binary files
+    ///
+    /// Advisories in `ignore` are ignored along with the configured ones,
+    /// e.g. those listed in a lockfile's `.rustsec-ignore` file.
     fn audit(
         &mut self,
         lockfile: &Lockfile,
         path: Option<&Path>,
-        #[allow(unused_variables)] // May be unused when the "binary-scanning" feature is disabled
         binary_format: Option<BinaryFormat>,
+        ignore: &[advisory::Id],
     ) -> rustsec::Result<rustsec::Report> {
-        let mut report = rustsec::Report::generate(&self.database, lockfile, &self.report_settings);
+        let settings = if ignore.is_empty() {
+            Cow::Borrowed(&self.report_settings)
+        } else {
+            let mut settings = self.report_settings.clone();
+            settings.ignore.extend_from_slice(ignore);
+            Cow::Owned(settings)
+        };
+
+        let mut report = rustsec::Report::generate(&self.database, lockfile, &settings);
 
         #[cfg(feature = "binary-scanning")]
         if let Some(format) = binary_format {
@@ -262,6 +633,9 @@ impl Auditor {
             filter_report_by_binary_type(&format, &mut report);
         }
 
+        // Show how long unmaintained crates have gone without a release
+        self.annotate_latest_releases(&mut report);
+
         // Warn for yanked crates
         let mut yanked = self.check_for_yanked_crates(lockfile);
         if !yanked.is_empty() {
@@ -272,26 +646,107 @@ impl Auditor {
                 .append(&mut yanked);
         }
 
-        if self.presenter.is_print_report() {
+        // Warn for crate versions which don't exist in the registry
+        let mut unpublished = self.check_for_unpublished_crates(lockfile);
+        if !unpublished.is_empty() {
+            report
+                .warnings
+                .entry(WarningKind::Unpublished)
+                .or_default()
+                .append(&mut unpublished);
+        }
+
+        self.suppress_ignored_crates(&mut report);
+
+        if let Some(path) = path {
+            self.suppress_excluded_path(&mut report, path);
+        }
+
+        // JSON reports for binaries are printed together once all are scanned
+        let combined_json = binary_format.is_some() && self.presenter.is_json();
+        if self.presenter.is_print_report() && !combined_json {
             self.presenter.print_report(&report, lockfile, path);
+            self.presenter.print_raw_sources(&report, &self.database);
         }
 
         Ok(report)
     }
 
+    /// Remove all findings from a lockfile whose path matches one of the
+    /// `exclude_paths` patterns, noting how many were excluded
+    fn suppress_excluded_path(&self, report: &mut rustsec::Report, path: &Path) {
+        let Some(pattern) = self
+            .exclude_paths
+            .iter()
+            .find(|pattern| lockfile::path_matches(pattern, path))
+        else {
+            return;
+        };
+
+        let excluded = report.vulnerabilities.list.len()
+            + report.warnings.values().map(Vec::len).sum::<usize>();
+
+        report.vulnerabilities = report::VulnerabilityInfo::new(vec![]);
+        report.warnings.clear();
+
+        if excluded > 0 {
+            status_warn!(
+                "excluded {} {} from {} (matches --exclude-path {})",
+                excluded,
+                if excluded == 1 { "finding" } else { "findings" },
+                path.display(),
+                pattern
+            );
+        }
+    }
+
+    /// Remove all findings for crates listed in `ignore_crates`, warning about
+    /// which advisories were suppressed
+    fn suppress_ignored_crates(&self, report: &mut rustsec::Report) {
+        if self.ignore_crates.is_empty() {
+            return;
+        }
+
+        let is_ignored = |package: &package::Package| self.ignore_crates.contains(&package.name);
+        let mut suppressed = vec![];
+
+        let (ignored, kept): (Vec<_>, Vec<_>) = report
+            .vulnerabilities
+            .list
+            .drain(..)
+            .partition(|vuln| is_ignored(&vuln.package));
+
+        suppressed.extend(ignored.iter().map(|vuln| vuln.advisory.id.to_string()));
+        report.vulnerabilities = report::VulnerabilityInfo::new(kept);
+
+        for warnings in report.warnings.values_mut() {
+            warnings.retain(|warning| {
+                if !is_ignored(&warning.package) {
+                    return true;
+                }
+
+                if let Some(advisory) = &warning.advisory {
+                    suppressed.push(advisory.id.to_string());
+                }
+                false
+            });
+        }
+        report.warnings.retain(|_, warnings| !warnings.is_empty());
+
+        if !suppressed.is_empty() {
+            suppressed.sort();
+            suppressed.dedup();
+            status_warn!(
+                "ignoring advisories for crates listed in --ignore-crate: {}",
+                suppressed.join(", ")
+            );
+        }
+    }
+
     fn check_for_yanked_crates(&mut self, lockfile: &Lockfile) -> Vec<Warning> {
         let mut result = Vec::new();
         if let Some(index) = &mut self.registry_index {
-            let pkgs_to_check: Vec<_> = lockfile
-                .packages
-                .iter()
-                .filter(|pkg| match &pkg.source {
-                    Some(source) => source.is_default_registry(),
-                    None => false,
-                })
-                .collect();
-
-            let yanked = index.find_yanked(pkgs_to_check);
+            let yanked = index.find_yanked(registry_packages(lockfile));
 
             for pkg in yanked {
                 match pkg {
@@ -299,6 +754,8 @@ impl Auditor {
                         let warning = Warning::new(WarningKind::Yanked, pkg, None, None, None);
                         result.push(warning);
                     }
+                    // Reported as unpublished instead
+                    Err(e) if e.kind() == ErrorKind::NotFound => (),
                     Err(e) => status_err!(
                         "couldn't check if the package is yanked: {}",
                         display_err_with_source(&e)
@@ -309,6 +766,52 @@ impl Auditor {
         result
     }
 
+    /// Record the latest release of each crate with an `unmaintained` warning,
+    /// so users can see how long it's gone without one
+    fn annotate_latest_releases(&mut self, report: &mut rustsec::Report) {
+        let Some(index) = &mut self.registry_index else {
+            return;
+        };
+
+        let Some(warnings) = report.warnings.get_mut(&WarningKind::Unmaintained) else {
+            return;
+        };
+
+        for warning in warnings {
+            match index.latest_release(&warning.package) {
+                Ok(latest_release) => warning.latest_release = latest_release,
+                Err(e) => status_err!(
+                    "couldn't look up the latest release of {}: {}",
+                    warning.package.name,
+                    display_err_with_source(&e)
+                ),
+            }
+        }
+    }
+
+    /// Find packages pinned to a version which isn't published on crates.io
+    fn check_for_unpublished_crates(&mut self, lockfile: &Lockfile) -> Vec<Warning> {
+        let mut result = Vec::new();
+        if let Some(index) = &mut self.registry_index {
+            for pkg in index.find_unpublished(registry_packages(lockfile)) {
+                match pkg {
+                    Ok(pkg) => result.push(Warning::new(
+                        WarningKind::Unpublished,
+                        pkg,
+                        None,
+                        None,
+                        None,
+                    )),
+                    Err(e) => status_err!(
+                        "couldn't check if the package is published: {}",
+                        display_err_with_source(&e)
+                    ),
+                }
+            }
+        }
+        result
+    }
+
     /// Load the lockfile to be audited
     fn load_lockfile(&self, lockfile_path: &Path) -> rustsec::Result<Lockfile> {
         if lockfile_path == Path::new("-") {
@@ -341,6 +844,11 @@ impl Auditor {
         results
     }
 
+    /// Advisory database loaded by this auditor
+    pub fn database(&self) -> &rustsec::Database {
+        &self.database
+    }
+
     /// Determines whether the process should exit with failure based on configuration
     /// such as `--deny=warnings`.
     /// **Performance:** calls `Auditor.self_advisories()`, which is costly.
@@ -353,11 +861,25 @@ impl Auditor {
     }
 }
 
+/// Packages in the lockfile which come from crates.io
+fn registry_packages(lockfile: &Lockfile) -> Vec<&package::Package> {
+    lockfile
+        .packages
+        .iter()
+        .filter(|pkg| match &pkg.source {
+            Some(source) => source.is_default_registry(),
+            None => false,
+        })
+        .collect()
+}
+
 /// Summary of the report over multiple scanned files
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct MultiFileReportSummmary {
     /// Whether any vulnerabilities were found
     pub vulnerabilities_found: bool,
     /// Whether any errors were encountered during scanning
     pub errors_encountered: bool,
+    /// Scanned binaries which weren't built with `cargo auditable`
+    pub missing_audit_info: Vec<std::path::PathBuf>,
 }
@@ -2,7 +2,12 @@
 //! 1. Recovers the dependency list embedded by `cargo auditable` (using `auditable-info`)
 //! 2. Failing that, recovers as many crates as possible from panic messages (using `quitters`)
 
-use std::{path::Path, str::FromStr};
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use auditable_serde::VersionInfo;
 use cargo_lock::{Dependency, Lockfile, Package};
@@ -22,7 +27,7 @@ pub enum BinaryReport {
 /// Load the dependency tree from a binary file
 pub fn load_deps_from_binary(binary_path: &Path) -> rustsec::Result<(BinaryFormat, BinaryReport)> {
     // TODO: input size limit
-    let file_contents = std::fs::read(binary_path)?;
+    let file_contents = fs::read(binary_path)?;
     let format = detect_format(&file_contents);
     let stuff = auditable_info::audit_info_from_slice(&file_contents, 8 * 1024 * 1024);
 
@@ -65,6 +70,41 @@ pub fn load_deps_from_binary(binary_path: &Path) -> rustsec::Result<(BinaryForma
     }
 }
 
+/// Find every executable file (or WebAssembly module) in the directory tree
+/// under `root`, in sorted order.
+///
+/// Files are recognized by their header, so scripts and other files lying
+/// next to the binaries (e.g. in a release directory) are skipped.
+pub fn discover(root: &Path) -> rustsec::Result<Vec<PathBuf>> {
+    let mut binaries = vec![];
+    discover_in(root, &mut binaries)?;
+    binaries.sort();
+    Ok(binaries)
+}
+
+/// Recursively search `dir` for binaries
+fn discover_in(dir: &Path, binaries: &mut Vec<PathBuf>) -> rustsec::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if entry.file_type()?.is_dir() {
+            discover_in(&path, binaries)?;
+        } else if is_binary(&path)? {
+            binaries.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Does the file at `path` start with the header of a known binary format?
+fn is_binary(path: &Path) -> rustsec::Result<bool> {
+    let mut header = vec![];
+    fs::File::open(path)?.take(4096).read_to_end(&mut header)?;
+    Ok(detect_format(&header) != BinaryFormat::Unknown)
+}
+
 fn detect_format(data: &[u8]) -> BinaryFormat {
     match binfarce::detect_format(data) {
         binfarce::Format::Unknown => {
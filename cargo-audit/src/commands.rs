@@ -3,10 +3,15 @@
 mod audit;
 
 use self::audit::AuditCommand;
-use crate::config::AuditConfig;
-use abscissa_core::{config::Override, Command, Configurable, FrameworkError, Runnable};
+use crate::{config::AuditConfig, prelude::*};
+use abscissa_core::{config::Override, Configurable, FrameworkError};
 use clap::Parser;
-use std::{ops::Deref, path::PathBuf};
+use std::{
+    fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+    process::exit,
+};
 
 /// Name of the configuration file
 ///
@@ -43,14 +48,24 @@ impl Runnable for CargoAuditCommand {
 impl Configurable<AuditConfig> for CargoAuditCommand {
     /// Location of `audit.toml` (if it exists)
     fn config_path(&self) -> Option<PathBuf> {
+        // A file given with `--audit-config` must exist and is used as-is
+        if let Some(path) = self.audit_config() {
+            check_config_file(path);
+            return Some(path.to_owned());
+        }
+
         // Check if the config file exists, and if it does not, ignore it.
         //
         // The order of precedence for which config file to use is:
-        // 1. The current project's `.cargo` configuration directory.
+        // 1. The nearest `.cargo` configuration directory, searching upward
+        //    from the lockfile's directory (or the current directory).
         // 2. The current user's home directory configuration.
+        //
+        // Only one file is loaded. Command-line flags are applied on top of it:
+        // list options (e.g. `--ignore`) add to the file's lists, and other
+        // options replace the file's values.
 
-        let project_config_filename = PathBuf::from("./.cargo").join(CONFIG_FILE);
-        if project_config_filename.exists() {
+        if let Some(project_config_filename) = find_project_config(&self.config_search_dir()) {
             return Some(project_config_filename);
         }
 
@@ -73,6 +88,31 @@ impl Configurable<AuditConfig> for CargoAuditCommand {
     }
 }
 
+/// Exit with an error naming `path` if it isn't a readable, valid config file.
+///
+/// Otherwise a bad file passed explicitly would only produce a generic
+/// framework error.
+fn check_config_file(path: &Path) {
+    let result = fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|toml| toml::from_str::<AuditConfig>(&toml).map_err(|e| e.to_string()));
+
+    if let Err(e) = result {
+        status_err!("couldn't load config file {}: {}", path.display(), e);
+        exit(2);
+    }
+}
+
+/// Find the nearest `.cargo/audit.toml` in `start` or any of its ancestors
+fn find_project_config(start: &Path) -> Option<PathBuf> {
+    let start = start.canonicalize().ok()?;
+
+    start
+        .ancestors()
+        .map(|dir| dir.join(".cargo").join(CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
 impl Deref for CargoAuditCommand {
     type Target = AuditCommand;
 
@@ -6,26 +6,40 @@ mod fix;
 #[cfg(feature = "binary-scanning")]
 mod binary_scanning;
 
+mod crate_query;
+
 use crate::{
     auditor::Auditor,
     config::{AuditConfig, DenyOption, FilterList, OutputFormat},
     error::display_err_with_source,
     lockfile,
     prelude::*,
+    presenter::Presenter,
 };
 use abscissa_core::{
     config::Override, error::Context, terminal::ColorChoice, FrameworkError, FrameworkErrorKind,
 };
-use clap::{Parser, ValueEnum};
-use rustsec::platforms::target::{Arch, OS};
-use std::{fmt, path::PathBuf, process::exit};
+use clap::{Parser, Subcommand, ValueEnum};
+use rustsec::{
+    advisory,
+    cargo_lock::Package,
+    platforms::target::{Arch, OS},
+    report::SchemaVersion,
+    repository::git::{CommitHash, Repository},
+    Collection, Lockfile, Version,
+};
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+use self::crate_query::CrateCommand;
 
 #[cfg(feature = "binary-scanning")]
 use self::binary_scanning::BinCommand;
 #[cfg(feature = "fix")]
 use self::fix::FixCommand;
-#[cfg(any(feature = "fix", feature = "binary-scanning"))]
-use clap::Subcommand;
 
 #[derive(Debug, Clone, Copy, Default, ValueEnum)]
 #[value(rename_all = "kebab-case")] // If you change this, remember to update `fmt::Display` impl.
@@ -63,8 +77,7 @@ impl fmt::Display for Color {
 #[derive(Command, Clone, Default, Debug, Parser)]
 #[command(version)]
 pub struct AuditCommand {
-    /// Optional subcommand (used for `cargo audit fix` and `cargo audit bin`)
-    #[cfg(any(feature = "fix", feature = "binary-scanning"))]
+    /// Optional subcommand (used for `cargo audit fix`, `cargo audit bin` and `cargo audit crate`)
     #[command(subcommand)]
     subcommand: Option<AuditSubcommand>,
 
@@ -76,6 +89,14 @@ pub struct AuditCommand {
     )]
     color: Option<Color>,
 
+    /// Configuration file to load instead of searching for `audit.toml`
+    #[arg(
+        long = "audit-config",
+        value_name = "PATH",
+        help = "load configuration from this TOML file instead of discovering .cargo/audit.toml"
+    )]
+    audit_config: Option<PathBuf>,
+
     /// Filesystem path to the advisory database git repository
     #[arg(
         short,
@@ -100,14 +121,48 @@ pub struct AuditCommand {
     )]
     file: Option<PathBuf>,
 
+    /// Directory to search for `Cargo.lock` files
+    #[arg(
+        long = "workspace-root",
+        value_name = "DIR",
+        conflicts_with = "file",
+        help = "audit every Cargo.lock found under this directory (skips target/, vendor/ and .gitignored paths)"
+    )]
+    workspace_root: Option<PathBuf>,
+
+    /// Maximum directory depth to search for `Cargo.lock` files
+    #[arg(
+        long = "max-depth",
+        value_name = "DEPTH",
+        requires = "workspace_root",
+        help = "maximum directory depth to search with --workspace-root"
+    )]
+    max_depth: Option<usize>,
+
     /// Advisory IDs to ignore
     #[arg(
         long = "ignore",
         value_name = "ADVISORY_ID",
-        help = "Advisory id to ignore (can be specified multiple times)"
+        help = "Advisory id or alias (e.g. a GHSA id) to ignore (can be specified multiple times)"
     )]
     ignore: Vec<String>,
 
+    /// Crates to ignore all advisories for
+    #[arg(
+        long = "ignore-crate",
+        value_name = "CRATE",
+        help = "Ignore all advisories for the named crate (can be specified multiple times)"
+    )]
+    ignore_crate: Vec<String>,
+
+    /// Lockfile paths to exclude findings from
+    #[arg(
+        long = "exclude-path",
+        value_name = "GLOB",
+        help = "Scan, but exclude findings from, lockfiles whose path matches this glob (can be specified multiple times)"
+    )]
+    exclude_path: Vec<String>,
+
     /// Ignore the sources of packages in Cargo.toml
     #[arg(
         long = "ignore-source",
@@ -115,6 +170,64 @@ pub struct AuditCommand {
     )]
     ignore_source: bool,
 
+    /// Advisory collection to consider
+    #[arg(
+        long = "collection",
+        value_name = "COLLECTION",
+        help = "Only consider advisories in this collection: crates or rust (default: crates)"
+    )]
+    collection: Option<Collection>,
+
+    /// Report unsound advisories as vulnerabilities
+    #[arg(
+        long = "unsound-as-vulnerabilities",
+        help = "report unsound advisories as vulnerabilities (counted and failing the audit) instead of warnings"
+    )]
+    unsound_as_vulnerabilities: bool,
+
+    /// File listing the functions reachable in each crate
+    #[arg(
+        long = "reachability",
+        value_name = "FILE",
+        help = "TOML file mapping crate names to their reachable functions, to tag findings as reachable or not"
+    )]
+    reachability: Option<PathBuf>,
+
+    /// Report unreachable vulnerabilities as warnings
+    #[arg(
+        long = "unreachable-as-warnings",
+        help = "report vulnerabilities whose affected functions are all unreachable (per --reachability) as warnings"
+    )]
+    unreachable_as_warnings: bool,
+
+    /// Flag vulnerabilities without a CVSS score for manual assessment
+    #[arg(
+        long = "require-severity",
+        help = "flag vulnerabilities in advisories without a CVSS score as \"unscored\", for manual assessment"
+    )]
+    require_severity: bool,
+
+    /// Report crates.io packages without a checksum
+    #[arg(
+        long = "require-checksums",
+        help = "fail the audit if any crates.io package has no checksum in the lockfile"
+    )]
+    require_checksums: bool,
+
+    /// Only report vulnerabilities in direct dependencies
+    #[arg(
+        long = "direct-only",
+        help = "only report vulnerabilities in direct dependencies of workspace members"
+    )]
+    direct_only: bool,
+
+    /// Report vulnerabilities in transitive dependencies as warnings
+    #[arg(
+        long = "transitive-as-warnings",
+        help = "with --direct-only, list vulnerabilities in transitive dependencies as warnings"
+    )]
+    transitive_as_warnings: bool,
+
     /// Skip fetching the advisory database git repository
     #[arg(
         short = 'n',
@@ -127,20 +240,139 @@ pub struct AuditCommand {
     #[arg(long = "stale", help = "allow stale database")]
     stale: bool,
 
+    /// Forbid any modification of the advisory database
+    #[arg(
+        long = "locked-db",
+        help = "use the local advisory DB as-is: never fetch or modify it, and fail if it is missing or stale"
+    )]
+    locked_db: bool,
+
+    /// Advisory database commit to audit against
+    #[arg(
+        long = "db-commit",
+        value_name = "HASH",
+        conflicts_with = "locked_db",
+        help = "check out this advisory DB commit before auditing, for reproducible results"
+    )]
+    db_commit: Option<CommitHash>,
+
+    /// Garbage-collect the advisory database after fetching it
+    #[arg(
+        long = "prune-db",
+        conflicts_with_all = ["no_fetch", "locked_db"],
+        help = "repack the advisory DB checkout with 'git gc' after fetching it, to reclaim space in long-lived caches"
+    )]
+    prune_db: bool,
+
+    /// Public key the advisory database's manifest must be signed with
+    #[cfg(feature = "manifest-signature")]
+    #[arg(
+        long = "db-manifest-key",
+        value_name = "KEY",
+        conflicts_with = "from_report",
+        help = "fail unless the advisory DB has a manifest of its file hashes signed by this hex-encoded Ed25519 public key"
+    )]
+    db_manifest_key: Option<rustsec::database::ManifestKey>,
+
+    /// Collections the advisory database must have advisories in
+    #[arg(
+        long = "require-collection",
+        value_name = "COLLECTION",
+        help = "fail unless the advisory DB has advisories in this collection: crates or rust (can be specified multiple times)"
+    )]
+    require_collection: Vec<Collection>,
+
+    /// Report advisories added since the commit recorded in this file
+    #[arg(
+        long = "new-advisories",
+        value_name = "STATE_FILE",
+        help = "list advisories added since the DB commit recorded in STATE_FILE, then record the current commit"
+    )]
+    new_advisories: Option<PathBuf>,
+
+    /// Render a previously generated JSON report instead of scanning
+    #[arg(
+        long = "from-report",
+        value_name = "REPORT",
+        conflicts_with_all = [
+            "workspace_root",
+            "max_depth",
+            "ignore",
+            "ignore_crate",
+            "exclude_path",
+            "ignore_source",
+            "collection",
+            "unsound_as_vulnerabilities",
+            "reachability",
+            "unreachable_as_warnings",
+            "require_severity",
+            "require_checksums",
+            "direct_only",
+            "transitive_as_warnings",
+            "db",
+            "no_fetch",
+            "stale",
+            "locked_db",
+            "db_commit",
+            "prune_db",
+            "require_collection",
+            "new_advisories",
+            "target_arch",
+            "target_os",
+            "all_targets",
+            "rust_version",
+        ],
+        help = "re-render a JSON report from an earlier run in another --format, without scanning"
+    )]
+    from_report: Option<PathBuf>,
+
+    /// Print the details of a single advisory instead of scanning
+    #[arg(
+        long = "explain",
+        value_name = "ADVISORY_ID",
+        conflicts_with_all = ["file", "workspace_root", "new_advisories", "from_report"],
+        help = "print the full details of an advisory (or alias) from the local DB, without scanning"
+    )]
+    explain: Option<advisory::Id>,
+
+    /// Scan the dependencies embedded in this `cargo-audit` binary
+    #[arg(
+        long = "self",
+        conflicts_with_all = ["file", "workspace_root", "new_advisories", "from_report", "explain"],
+        help = "audit the dependencies compiled into this cargo-audit binary instead of a Cargo.lock"
+    )]
+    self_scan: bool,
+
     /// Target CPU architecture to find vulnerabilities for
     #[arg(
         long = "target-arch",
-        help = "filter vulnerabilities by CPU (default: no filter). Can be specified multiple times"
+        help = "filter vulnerabilities by CPU (default: the host's). Can be specified multiple times"
     )]
     target_arch: Vec<Arch>,
 
     /// Target OS to find vulnerabilities for
     #[arg(
         long = "target-os",
-        help = "filter vulnerabilities by OS (default: no filter). Can be specified multiple times"
+        help = "filter vulnerabilities by OS (default: the host's). Can be specified multiple times"
     )]
     target_os: Vec<OS>,
 
+    /// Find vulnerabilities for all targets
+    #[arg(
+        long = "all-targets",
+        conflicts_with_all = ["target_arch", "target_os"],
+        help = "find vulnerabilities affecting any target, not only the host (recommended in CI). Always the default for 'cargo audit bin' and 'cargo audit crate'"
+    )]
+    all_targets: bool,
+
+    /// Version of `rustc` to check toolchain advisories against
+    #[arg(
+        long = "rust-version",
+        value_name = "VERSION",
+        help = "check Rust toolchain advisories (e.g. for std) against this rustc version"
+    )]
+    rust_version: Option<Version>,
+
     /// URL to the advisory database git repository
     #[arg(short = 'u', long = "url", help = "URL for advisory database git repo")]
     url: Option<String>,
@@ -153,13 +385,56 @@ pub struct AuditCommand {
     )]
     quiet: bool,
 
+    /// Summarize vulnerabilities by advisory keyword
+    #[arg(
+        long = "group-by-keyword",
+        help = "Summarize vulnerabilities by advisory keyword"
+    )]
+    group_by_keyword: bool,
+
+    /// Print the TOML source of each advisory
+    #[arg(
+        long = "show-raw",
+        help = "print the TOML source of the advisory behind each finding, to check it by hand"
+    )]
+    show_raw: bool,
+
+    /// Print how many candidate advisories were checked
+    #[arg(
+        long = "stats",
+        help = "report how many candidate advisories (those filed under a crate's name) were checked, in total and for each crate, to profile custom advisory databases"
+    )]
+    stats: bool,
+
+    /// Report findings without failing
+    #[arg(
+        long = "report-only",
+        help = "report all findings but always exit successfully, e.g. to collect data without blocking builds"
+    )]
+    report_only: bool,
+
     /// Output reports as JSON
     #[arg(long = "json", help = "Output report in JSON format")]
     output_json: bool,
+
+    /// Output format
+    #[arg(
+        long = "format",
+        value_name = "FORMAT",
+        help = "Output format: terminal, json, plain (one finding per line, no styling), markdown, csv, dot (dependency graph), oneline (one finding per line, most severe first), or tree (dependency tree with findings inline)"
+    )]
+    format: Option<OutputFormat>,
+
+    /// Version of the JSON report schema
+    #[arg(
+        long = "json-schema-version",
+        value_name = "VERSION",
+        help = "JSON report schema version to emit (default: latest)"
+    )]
+    json_schema_version: Option<SchemaVersion>,
 }
 
 /// Subcommands of `cargo audit`
-#[cfg(any(feature = "fix", feature = "binary-scanning"))]
 #[derive(Subcommand, Clone, Debug, Runnable)]
 pub enum AuditSubcommand {
     /// `cargo audit fix` subcommand
@@ -177,9 +452,30 @@ Performs a complete scan if the binary is built with 'cargo auditable'.
 If not, recovers a part of the dependency list from panic messages."
     )]
     Bin(BinCommand),
+
+    /// `cargo audit crate` subcommand
+    #[command(
+        about = "look up advisories for a single crate version",
+        long_about = "Look up advisories affecting a single crate version.
+
+Queries the advisory database for the given crate name and version directly,
+without needing a Cargo.lock. Exits with status 1 if any advisories match."
+    )]
+    Crate(CrateCommand),
 }
 
 impl AuditCommand {
+    /// Is this an audit of lockfiles, rather than of binaries or a single
+    /// crate (via the `bin` and `crate` subcommands)?
+    fn is_lockfile_scan(&self) -> bool {
+        match &self.subcommand {
+            #[cfg(feature = "binary-scanning")]
+            Some(AuditSubcommand::Bin(_)) => false,
+            Some(AuditSubcommand::Crate(_)) => false,
+            _ => true,
+        }
+    }
+
     /// Get the color configuration
     pub fn term_colors(&self) -> ColorChoice {
         if let Some(color) = self.color {
@@ -193,6 +489,26 @@ impl AuditCommand {
             }
         }
     }
+
+    /// Configuration file given with `--audit-config`, if any
+    pub fn audit_config(&self) -> Option<&Path> {
+        self.audit_config.as_deref()
+    }
+
+    /// Directory to start searching upward from for `.cargo/audit.toml`.
+    ///
+    /// This is the directory containing the lockfile given with `--file`,
+    /// or `--workspace-root`, falling back to the current directory.
+    pub fn config_search_dir(&self) -> PathBuf {
+        let dir = match (&self.file, &self.workspace_root) {
+            (Some(file), _) if file != Path::new("-") => file.parent().map(Path::to_owned),
+            (_, Some(root)) => Some(root.clone()),
+            _ => None,
+        };
+
+        dir.filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or_else(|| PathBuf::from("."))
+    }
 }
 
 impl Override<AuditConfig> for AuditCommand {
@@ -210,9 +526,54 @@ impl Override<AuditConfig> for AuditCommand {
             );
         }
 
+        for crate_name in &self.ignore_crate {
+            config.advisories.ignore_crates.push(
+                crate_name
+                    .parse()
+                    .map_err(|e| Context::new(FrameworkErrorKind::ParseError, Some(Box::new(e))))?,
+            );
+        }
+
+        config
+            .advisories
+            .exclude_paths
+            .extend(self.exclude_path.iter().cloned());
         config.advisories.ignore_source |= self.ignore_source;
+
+        if let Some(collection) = self.collection {
+            config.advisories.collection = Some(collection);
+        }
+
+        config.advisories.unsound_as_vulnerabilities |= self.unsound_as_vulnerabilities;
+
+        if let Some(path) = &self.reachability {
+            config.advisories.reachability = Some(path.clone());
+        }
+
+        config.advisories.unreachable_as_warnings |= self.unreachable_as_warnings;
+        config.advisories.require_severity |= self.require_severity;
+        config.advisories.require_checksums |= self.require_checksums;
+        config.advisories.direct_only |= self.direct_only;
+        config.advisories.transitive_as_warnings |= self.transitive_as_warnings;
+
         config.database.fetch |= !self.no_fetch;
         config.database.stale |= self.stale;
+        config.database.locked |= self.locked_db;
+        config.database.prune |= self.prune_db;
+
+        if let Some(commit) = &self.db_commit {
+            config.database.commit = Some(commit.to_hex());
+        }
+
+        #[cfg(feature = "manifest-signature")]
+        if let Some(key) = self.db_manifest_key {
+            config.database.manifest_key = Some(key);
+        }
+
+        config
+            .database
+            .require_collections
+            .extend(self.require_collection.iter().copied());
 
         if !self.target_arch.is_empty() {
             config.target.arch = Some(FilterList::Many(self.target_arch.clone()));
@@ -222,6 +583,18 @@ impl Override<AuditConfig> for AuditCommand {
             config.target.os = Some(FilterList::Many(self.target_os.clone()));
         }
 
+        config.target.all_targets |= self.all_targets;
+
+        // Binaries and crates looked up by name aren't necessarily built for
+        // this machine, so only lockfile scans default to the host's targets
+        if !self.is_lockfile_scan() {
+            config.target.all_targets = true;
+        }
+
+        if let Some(version) = &self.rust_version {
+            config.target.rust_version = Some(version.clone());
+        }
+
         if let Some(url) = &self.url {
             config.database.url = Some(url.clone())
         }
@@ -235,11 +608,23 @@ impl Override<AuditConfig> for AuditCommand {
         }
 
         config.output.quiet |= self.quiet;
+        config.output.group_by_keyword |= self.group_by_keyword;
+        config.output.report_only |= self.report_only;
+        config.output.show_raw |= self.show_raw;
+        config.output.stats |= self.stats;
+
+        if let Some(format) = self.format {
+            config.output.format = format;
+        }
 
         if self.output_json {
             config.output.format = OutputFormat::Json;
         }
 
+        if let Some(version) = self.json_schema_version {
+            config.output.json_schema_version = Some(version);
+        }
+
         Ok(config)
     }
 }
@@ -258,6 +643,31 @@ impl Runnable for AuditCommand {
             exit(0)
         }
 
+        if let Some(AuditSubcommand::Crate(krate)) = &self.subcommand {
+            krate.run();
+            exit(0)
+        }
+
+        if let Some(state_file) = &self.new_advisories {
+            self.run_new_advisories(state_file);
+        }
+
+        if let Some(report_file) = &self.from_report {
+            self.run_from_report(report_file);
+        }
+
+        if let Some(id) = &self.explain {
+            self.run_explain(id);
+        }
+
+        if self.self_scan {
+            self.run_self_scan();
+        }
+
+        if let Some(root) = &self.workspace_root {
+            self.run_workspace(root);
+        }
+
         let maybe_path = self.file.as_deref();
         // It is important to generate the lockfile before initializing the auditor,
         // otherwise we might deadlock because both need the Cargo package lock
@@ -266,6 +676,8 @@ impl Runnable for AuditCommand {
             exit(2);
         });
         let mut auditor = self.auditor();
+        exit_if_database_empty(auditor.database());
+
         let report = auditor.audit_lockfile(&path);
         match report {
             Ok(report) => {
@@ -287,4 +699,277 @@ impl AuditCommand {
     pub fn auditor(&self) -> Auditor {
         Auditor::new(&APP.config())
     }
+
+    /// Print a report emitted by an earlier `--format json` run in the
+    /// configured output format, then exit.
+    ///
+    /// Reports in any supported schema version are accepted. Dependency
+    /// trees come from the lockfile given with `--file`, if any. Otherwise
+    /// only the affected crates themselves are known.
+    fn run_from_report(&self, report_file: &Path) -> ! {
+        let report = fs::read_to_string(report_file)
+            .map_err(|e| e.to_string())
+            .and_then(|json| parse_report(&json))
+            .unwrap_or_else(|e| {
+                status_err!("couldn't read report {}: {}", report_file.display(), e);
+                exit(2);
+            });
+
+        let path = self.file.as_deref().filter(|path| *path != Path::new("-"));
+        let lockfile = match path {
+            Some(path) => Lockfile::load(path).unwrap_or_else(|e| {
+                status_err!("{}", display_err_with_source(&e));
+                exit(2);
+            }),
+            None => lockfile_for_report(&report),
+        };
+
+        let config = APP.config();
+        let mut presenter = Presenter::new(&config.output);
+        presenter.print_report(&report, &lockfile, path);
+
+        if presenter.should_exit_with_failure(&report) {
+            exit(1)
+        } else {
+            exit(0)
+        }
+    }
+
+    /// Print the details of the advisory with the given ID or alias, then exit.
+    ///
+    /// An existing local database is used as-is, so this works offline.
+    /// The database is only fetched if there isn't one yet.
+    fn run_explain(&self, id: &advisory::Id) -> ! {
+        let mut config = AuditConfig::clone(&APP.config());
+        let db_path = config
+            .database
+            .path
+            .clone()
+            .unwrap_or_else(Repository::default_path);
+
+        if fs::read_dir(&db_path).map_or(false, |mut entries| entries.next().is_some()) {
+            config.database.fetch = false;
+        }
+
+        // Nothing is scanned, so the crates.io index isn't needed
+        config.yanked.enabled = false;
+
+        let auditor = Auditor::new(&config);
+        let Some(advisory) = auditor.database().get(id) else {
+            status_err!("advisory {} not found in the advisory database", id);
+            exit(2);
+        };
+
+        Presenter::new(&config.output).print_advisory(advisory, config.advisories.cvss_preference);
+        exit(0)
+    }
+
+    /// Audit the dependency list embedded in the running `cargo-audit`
+    /// executable, then exit.
+    ///
+    /// Uses the `cargo auditable` data if present, and otherwise the partial
+    /// dependency list recovered from panic messages, as `cargo audit bin` does.
+    fn run_self_scan(&self) -> ! {
+        let exe = std::env::current_exe().unwrap_or_else(|e| {
+            status_err!("couldn't locate the cargo-audit executable: {}", e);
+            exit(2);
+        });
+
+        #[cfg(feature = "binary-scanning")]
+        {
+            let mut auditor = self.auditor();
+            exit_if_database_empty(auditor.database());
+
+            let summary = auditor.audit_binaries(&[exe]);
+
+            if summary.vulnerabilities_found {
+                exit(1)
+            } else if summary.errors_encountered {
+                exit(2)
+            } else {
+                exit(0)
+            }
+        }
+
+        #[cfg(not(feature = "binary-scanning"))]
+        {
+            status_err!(
+                "scanning {} requires cargo-audit to be built with the `binary-scanning` feature",
+                exe.display()
+            );
+            exit(2)
+        }
+    }
+
+    /// List the advisories added to the database since the commit recorded
+    /// in `state_file`, record the current commit in it, then exit.
+    ///
+    /// If the state file doesn't exist yet, only the current commit is recorded.
+    fn run_new_advisories(&self, state_file: &Path) -> ! {
+        let auditor = self.auditor();
+        let database = auditor.database();
+
+        let repo = Repository::open(database.path()).unwrap_or_else(|e| {
+            status_err!(
+                "listing new advisories requires a git checkout of the advisory database: {}",
+                display_err_with_source(&e)
+            );
+            exit(2);
+        });
+
+        let head = repo
+            .latest_commit()
+            .unwrap_or_else(|e| {
+                status_err!("{}", display_err_with_source(&e));
+                exit(2);
+            })
+            .commit_id;
+
+        match fs::read_to_string(state_file) {
+            Ok(contents) => {
+                let previous: CommitHash = contents.trim().parse().unwrap_or_else(|e| {
+                    status_err!(
+                        "invalid commit in {}: {}",
+                        state_file.display(),
+                        display_err_with_source(&e)
+                    );
+                    exit(2);
+                });
+
+                let added = repo.added_advisories(previous).unwrap_or_else(|e| {
+                    status_err!("{}", display_err_with_source(&e));
+                    exit(2);
+                });
+
+                let advisories: Vec<_> = added
+                    .iter()
+                    .filter_map(|id| {
+                        let advisory = database.get(id);
+                        if advisory.is_none() {
+                            status_warn!("new advisory {} couldn't be loaded", id);
+                        }
+                        advisory
+                    })
+                    .collect();
+
+                Presenter::new(&APP.config().output).print_advisory_list(&advisories);
+
+                status_ok!(
+                    "Found",
+                    "{} new {} since commit {}",
+                    added.len(),
+                    if added.len() == 1 {
+                        "advisory"
+                    } else {
+                        "advisories"
+                    },
+                    previous
+                );
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                status_ok!(
+                    "Recorded",
+                    "advisory database commit {} (new advisories are listed from the next run)",
+                    head
+                );
+            }
+            Err(e) => {
+                status_err!("couldn't read {}: {}", state_file.display(), e);
+                exit(2);
+            }
+        }
+
+        if let Err(e) = fs::write(state_file, format!("{}\n", head)) {
+            status_err!("couldn't write {}: {}", state_file.display(), e);
+            exit(2);
+        }
+
+        exit(0)
+    }
+
+    /// Audit every `Cargo.lock` under `root`, then exit
+    fn run_workspace(&self, root: &Path) -> ! {
+        let paths = lockfile::discover(root, self.max_depth).unwrap_or_else(|e| {
+            status_err!("{}", display_err_with_source(&e));
+            exit(2);
+        });
+
+        if paths.is_empty() {
+            status_err!("no Cargo.lock files found under {}", root.display());
+            exit(2);
+        }
+
+        let mut auditor = self.auditor();
+        exit_if_database_empty(auditor.database());
+
+        let summary = auditor.audit_lockfiles(&paths);
+
+        if summary.vulnerabilities_found {
+            exit(1)
+        } else if summary.errors_encountered {
+            exit(2)
+        } else {
+            exit(0)
+        }
+    }
+}
+
+/// Exit with status 2 if `database` has no advisories to match against, so
+/// a scan against it isn't mistaken for a clean result.
+///
+/// Called before scanning, so no report claiming a clean result is printed.
+fn exit_if_database_empty(database: &rustsec::Database) {
+    if !rustsec::report::DatabaseInfo::new(database).loaded {
+        status_err!(
+            "no advisories available in the advisory database at {}",
+            database.path().display()
+        );
+        exit(2);
+    }
+}
+
+/// Parse a JSON report, checking its schema version is one this version
+/// of cargo-audit understands
+fn parse_report(json: &str) -> Result<rustsec::Report, String> {
+    let report: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+
+    // V1 reports predate the `schema-version` field
+    if let Some(version) = report.get("schema-version") {
+        serde_json::from_value::<SchemaVersion>(version.clone()).map_err(|e| e.to_string())?;
+    }
+
+    serde_json::from_value(report).map_err(|e| e.to_string())
+}
+
+/// Lockfile containing only the crates with findings in `report`, for
+/// presenting a report when the original lockfile isn't available
+fn lockfile_for_report(report: &rustsec::Report) -> Lockfile {
+    let mut packages: Vec<_> = report
+        .vulnerabilities
+        .list
+        .iter()
+        .map(|vuln| &vuln.package)
+        .chain(
+            report
+                .warnings
+                .values()
+                .flatten()
+                .map(|warning| &warning.package),
+        )
+        .map(|package| Package {
+            dependencies: vec![],
+            ..package.clone()
+        })
+        .collect();
+
+    packages.sort();
+    packages.dedup();
+
+    Lockfile {
+        version: Default::default(),
+        packages,
+        root: None,
+        metadata: Default::default(),
+        patch: Default::default(),
+    }
 }
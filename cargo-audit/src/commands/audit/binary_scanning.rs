@@ -1,6 +1,6 @@
 //! The `cargo audit bin` subcommand
 
-use crate::{auditor::Auditor, prelude::*};
+use crate::{auditor::Auditor, binary_deps, error::display_err_with_source, prelude::*};
 use clap::Parser;
 use std::{path::PathBuf, process::exit};
 
@@ -13,17 +13,54 @@ pub struct BinCommand {
     #[arg(
         value_parser,
         required = true,
-        help = "Paths to the binaries to be scanned"
+        help = "Paths to the binaries to be scanned, or directories to search for binaries"
     )]
     binary_paths: Vec<PathBuf>,
+
+    /// Fail if any binary lacks `cargo auditable` data
+    #[arg(
+        long = "require-audit-info",
+        help = "exit with an error if any binary wasn't built with 'cargo auditable'"
+    )]
+    require_audit_info: bool,
 }
 
 impl Runnable for BinCommand {
     fn run(&self) {
-        let report = self.auditor().audit_binaries(&self.binary_paths);
+        let binaries = self.binaries();
+        let report = self.auditor().audit_binaries(&binaries);
+
+        if !report.missing_audit_info.is_empty() {
+            let message = format!(
+                "{} of {} binaries {} not built with 'cargo auditable':",
+                report.missing_audit_info.len(),
+                binaries.len(),
+                if report.missing_audit_info.len() == 1 {
+                    "was"
+                } else {
+                    "were"
+                }
+            );
+
+            let paths = report
+                .missing_audit_info
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            if self.require_audit_info {
+                status_err!("{} {}", message, paths);
+            } else {
+                status_warn!("{} {}", message, paths);
+            }
+        }
+
         if report.vulnerabilities_found {
             exit(1)
-        } else if report.errors_encountered {
+        } else if report.errors_encountered
+            || (self.require_audit_info && !report.missing_audit_info.is_empty())
+        {
             exit(2)
         } else {
             exit(0)
@@ -36,4 +73,30 @@ impl BinCommand {
     pub fn auditor(&self) -> Auditor {
         Auditor::new(&APP.config())
     }
+
+    /// Paths of the binaries to scan, with directories replaced by the
+    /// binaries found anywhere inside them
+    fn binaries(&self) -> Vec<PathBuf> {
+        let mut binaries = vec![];
+
+        for path in &self.binary_paths {
+            if !path.is_dir() {
+                binaries.push(path.clone());
+                continue;
+            }
+
+            match binary_deps::discover(path) {
+                Ok(found) if found.is_empty() => {
+                    status_warn!("no binaries found in {}", path.display());
+                }
+                Ok(found) => binaries.extend(found),
+                Err(e) => {
+                    status_err!("{}", display_err_with_source(&e));
+                    exit(2);
+                }
+            }
+        }
+
+        binaries
+    }
 }
@@ -0,0 +1,76 @@
+//! The `cargo audit crate` subcommand
+
+use crate::{auditor::Auditor, prelude::*};
+use clap::Parser;
+use rustsec::{database::Query, package, Collection, Version};
+use std::process::exit;
+
+/// The `cargo audit crate` subcommand
+#[derive(Command, Clone, Debug, Parser)]
+#[command()]
+pub struct CrateCommand {
+    /// Name of the crate to look up
+    #[arg(help = "name of the crate")]
+    name: package::Name,
+
+    /// Version of the crate to look up
+    #[arg(help = "version of the crate")]
+    version: Version,
+}
+
+impl Runnable for CrateCommand {
+    fn run(&self) {
+        let auditor = self.auditor();
+        super::exit_if_database_empty(auditor.database());
+
+        // Informational advisories are included, since there is no report
+        // to sort them into warnings and the user asked about this crate
+        let query = Query::new()
+            .collection(Collection::Crates)
+            .withdrawn(false)
+            .package_name(self.name.clone())
+            .package_version(self.version.clone());
+
+        let advisories = auditor.database().query(&query);
+
+        for advisory in &advisories {
+            println!(
+                "{}\t{}\t{}\t{}",
+                advisory.id(),
+                self.name,
+                self.version,
+                advisory.title()
+            );
+        }
+
+        if advisories.is_empty() {
+            status_ok!(
+                "Success",
+                "no advisories found for {} {}",
+                self.name,
+                self.version
+            );
+            exit(0)
+        }
+
+        status_warn!(
+            "{} {} is affected by {} {}",
+            self.name,
+            self.version,
+            advisories.len(),
+            if advisories.len() == 1 {
+                "advisory"
+            } else {
+                "advisories"
+            }
+        );
+        exit(1)
+    }
+}
+
+impl CrateCommand {
+    /// Initialize `Auditor`
+    pub fn auditor(&self) -> Auditor {
+        Auditor::new(&APP.config())
+    }
+}
@@ -4,7 +4,7 @@ use crate::{auditor::Auditor, lockfile, prelude::*};
 use abscissa_core::{Command, Runnable};
 use cargo_lock::Lockfile;
 use clap::Parser;
-use rustsec::{advisory::Id, Fixer};
+use rustsec::{advisory::Id, CompatibleFix, Fixer, Vulnerability};
 use std::{
     collections::BTreeSet,
     path::{Path, PathBuf},
@@ -21,6 +21,13 @@ pub struct FixCommand {
     /// Perform a dry run
     #[arg(long = "dry-run", help = "perform a dry run for the fix")]
     dry_run: bool,
+
+    /// Only apply upgrades which are semver-compatible with the locked versions
+    #[arg(
+        long = "compatible-only",
+        help = "only apply semver-compatible upgrades, reporting advisories which need a breaking change"
+    )]
+    compatible_only: bool,
 }
 
 impl FixCommand {
@@ -61,8 +68,10 @@ impl Runnable for FixCommand {
         let lockfile = Lockfile::load(&path).expect("Failed to load Cargo.lock");
 
         // TODO: allow specifying manifest path
+        // The workspace's own requirements decide which upgrades are compatible
+        let manifest_path = Some(path.with_file_name("Cargo.toml")).filter(|path| path.is_file());
         let path_to_cargo: Option<PathBuf> = std::env::var_os("CARGO").map(|path| path.into());
-        let fixer = Fixer::new(lockfile, None, path_to_cargo);
+        let fixer = Fixer::new(lockfile, manifest_path, path_to_cargo);
 
         let dry_run = self.dry_run;
         if dry_run {
@@ -80,27 +89,79 @@ impl Runnable for FixCommand {
                     vulnerability.advisory.id,
                     vulnerability.package.name
                 );
+            } else if fixer.vulnerable_packages(vulnerability).is_empty() {
+                // Rust toolchain advisories (e.g. for `std`) can't be fixed with `cargo update`
+                unpatchable_vulns.insert(vulnerability.advisory.id.clone());
+                status_warn!(
+                    "{} affects the Rust toolchain ({}), upgrade rustc to fix it",
+                    vulnerability.advisory.id,
+                    vulnerability.package.name
+                );
+            } else if self.compatible_only
+                && compatible_fix(&fixer, vulnerability)
+                    == Some(CompatibleFix::RequiresBreakingChange)
+            {
+                unpatchable_vulns.insert(vulnerability.advisory.id.clone());
+                status_warn!(
+                    "{} in crate {} {} requires breaking change (patched: {})",
+                    vulnerability.advisory.id,
+                    vulnerability.package.name,
+                    vulnerability.package.version,
+                    vulnerability
+                        .versions
+                        .patched()
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(" OR ")
+                );
             } else {
-                let mut command = fixer.get_fix_command(vulnerability, dry_run);
-                // If the path to Cargo.lock has been specified explicitly,
-                // run the `cargo update` command in that directory
-                if let Some(path) = self.cargo_lock_path() {
-                    // documentation on .current_dir() recommends canonicalizing the path
-                    let canonical_path = path.canonicalize().unwrap();
-                    let dir = canonical_path.parent().unwrap();
-                    command.current_dir(dir);
-                }
-                // When calling `.status()` the stdout and stderr are inherited from the parent,
-                // so any status or error messages from `cargo update` will automatically be forwarded
-                // to the user of `cargo audit fix`.
-                let status = command.status();
-                if let Err(e) = status {
-                    failed_patches += 1;
-                    status_warn!(
-                        "Failed to run `cargo update` for package {}: {}",
-                        vulnerability.package.name,
-                        e
-                    );
+                // Transitive dependencies are bumped to a specific patched release
+                // in the lockfile, since nothing in the workspace's manifests names them.
+                // This is only done when every vulnerable copy of the crate is transitive,
+                // otherwise run a regular `cargo update` for all of them.
+                let transitive_fixes = fixer.transitive_fixes(vulnerability);
+                let commands = if !transitive_fixes.is_empty()
+                    && transitive_fixes.len() == fixer.vulnerable_packages(vulnerability).len()
+                {
+                    transitive_fixes
+                        .iter()
+                        .map(|fix| {
+                            status_ok!(
+                                "Updating",
+                                "transitive dependency {} {} -> {} (lockfile only)",
+                                fix.package.name,
+                                fix.package.version,
+                                fix.version
+                            );
+                            fixer.get_precise_fix_command(fix, dry_run)
+                        })
+                        .collect()
+                } else {
+                    vec![fixer.get_fix_command(vulnerability, dry_run)]
+                };
+
+                for mut command in commands {
+                    // If the path to Cargo.lock has been specified explicitly,
+                    // run the `cargo update` command in that directory
+                    if let Some(path) = self.cargo_lock_path() {
+                        // documentation on .current_dir() recommends canonicalizing the path
+                        let canonical_path = path.canonicalize().unwrap();
+                        let dir = canonical_path.parent().unwrap();
+                        command.current_dir(dir);
+                    }
+                    // When calling `.status()` the stdout and stderr are inherited from the parent,
+                    // so any status or error messages from `cargo update` will automatically be forwarded
+                    // to the user of `cargo audit fix`.
+                    let status = command.status();
+                    if let Err(e) = status {
+                        failed_patches += 1;
+                        status_warn!(
+                            "Failed to run `cargo update` for package {}: {}",
+                            vulnerability.package.name,
+                            e
+                        );
+                    }
                 }
             }
         }
@@ -164,3 +225,12 @@ impl Runnable for FixCommand {
         }
     }
 }
+
+/// Get the compatible upgrade for a vulnerability, exiting if `Cargo.toml`
+/// can't be read
+fn compatible_fix(fixer: &Fixer, vulnerability: &Vulnerability) -> Option<CompatibleFix> {
+    fixer.compatible_fix(vulnerability).unwrap_or_else(|e| {
+        status_err!("{}", e);
+        exit(2);
+    })
+}
@@ -1,12 +1,17 @@
 //! The configuration file
 
 use rustsec::{
-    advisory,
-    platforms::target::{Arch, OS},
-    report, Error, ErrorKind, WarningKind,
+    advisory, package,
+    platforms::{
+        target::{Arch, OS},
+        Platform,
+    },
+    report,
+    repository::git::UrlMismatch,
+    Collection, Error, ErrorKind, Version, WarningKind,
 };
 use serde::{Deserialize, Serialize};
-use std::{path::PathBuf, str::FromStr};
+use std::{collections::BTreeMap, path::PathBuf, str::FromStr};
 
 /// `cargo audit` configuration:
 ///
@@ -27,6 +32,10 @@ pub struct AuditConfig {
     #[serde(default)]
     pub output: OutputConfig,
 
+    /// Policy configuration
+    #[serde(default)]
+    pub policy: PolicyConfig,
+
     /// Target-related configuration
     #[serde(default)]
     pub target: TargetConfig,
@@ -42,8 +51,19 @@ impl AuditConfig {
         let mut settings = report::Settings {
             ignore: self.advisories.ignore.clone(),
             severity: self.advisories.severity_threshold,
+            cvss_preference: self.advisories.cvss_preference,
             target_arch: self.target.arch(),
             target_os: self.target.os(),
+            collection: self.advisories.collection,
+            rust_version: self.target.rust_version.clone(),
+            unsound_as_vulnerabilities: self.advisories.unsound_as_vulnerabilities,
+            unreachable_as_warnings: self.advisories.unreachable_as_warnings,
+            require_severity: self.advisories.require_severity,
+            require_checksums: self.advisories.require_checksums,
+            direct_only: self.advisories.direct_only,
+            transitive_as_warnings: self.advisories.transitive_as_warnings,
+            crate_severity: self.policy.crates.clone(),
+            stats: self.output.stats,
             ..Default::default()
         };
 
@@ -100,6 +120,14 @@ pub struct AdvisoryConfig {
     #[serde(default)]
     pub ignore: Vec<advisory::Id>,
 
+    /// Ignore all advisories for the crates with the given names
+    #[serde(default)]
+    pub ignore_crates: Vec<package::Name>,
+
+    /// Exclude findings from lockfiles whose path matches any of these globs
+    #[serde(default)]
+    pub exclude_paths: Vec<String>,
+
     /// Ignore the source of this advisory, matching any package of the same name.
     #[serde(default)]
     pub ignore_source: bool,
@@ -112,6 +140,47 @@ pub struct AdvisoryConfig {
     /// Vulnerabilities with explicit CVSS info which have a severity below
     /// this threshold will be ignored.
     pub severity_threshold: Option<advisory::Severity>,
+
+    /// CVSS version severities are taken from, for advisories with vectors
+    /// for more than one (default: the highest version)
+    #[serde(default)]
+    pub cvss_preference: advisory::CvssPreference,
+
+    /// Only consider advisories in this collection (default: crates)
+    #[serde(default)]
+    pub collection: Option<Collection>,
+
+    /// Report `unsound` advisories as vulnerabilities instead of warnings
+    #[serde(default)]
+    pub unsound_as_vulnerabilities: bool,
+
+    /// TOML file listing the functions known to be reachable in each crate
+    #[serde(default)]
+    pub reachability: Option<PathBuf>,
+
+    /// Report vulnerabilities whose affected functions are all unreachable
+    /// as warnings instead
+    #[serde(default)]
+    pub unreachable_as_warnings: bool,
+
+    /// Flag vulnerabilities in advisories without a CVSS score as
+    /// "unscored", so they fail the audit even below the severity threshold
+    #[serde(default)]
+    pub require_severity: bool,
+
+    /// Report crates.io packages without a checksum in the lockfile as
+    /// "missing-checksum", which always fails the audit
+    #[serde(default)]
+    pub require_checksums: bool,
+
+    /// Only report vulnerabilities in direct dependencies of workspace members
+    #[serde(default)]
+    pub direct_only: bool,
+
+    /// With `direct_only`, report vulnerabilities in transitive dependencies
+    /// as warnings instead of dropping them
+    #[serde(default)]
+    pub transitive_as_warnings: bool,
 }
 
 /// Advisory Database configuration.
@@ -132,6 +201,48 @@ pub struct DatabaseConfig {
 
     /// Allow a stale advisory database? (i.e. one which hasn't been updated in 90 days)
     pub stale: bool,
+
+    /// Use the local advisory database exactly as-is (default: false).
+    ///
+    /// Nothing is fetched or written, and a missing or stale database is an
+    /// error rather than something to update. This makes audits reproducible
+    /// against a pinned database commit.
+    #[serde(default)]
+    pub locked: bool,
+
+    /// Load the advisory database as of this commit, returning the checkout
+    /// to its previous commit afterwards. Can't be combined with `locked`.
+    #[serde(default)]
+    pub commit: Option<String>,
+
+    /// Fail unless the advisory database's manifest of file hashes is
+    /// signed by this Ed25519 public key and matches its files
+    #[cfg(feature = "manifest-signature")]
+    #[serde(default)]
+    pub manifest_key: Option<rustsec::database::ManifestKey>,
+
+    /// Fail unless each of these collections contains at least one advisory,
+    /// e.g. to catch a broken mirror which is missing the `crates` collection
+    #[serde(default)]
+    pub require_collections: Vec<Collection>,
+
+    /// What to do when the checkout at `path` was cloned from a different
+    /// URL than `url`: `error` (default), `reclone` or `update-remote`
+    #[serde(default)]
+    pub url_mismatch: UrlMismatch,
+
+    /// Garbage-collect the advisory database checkout with `git gc` after
+    /// fetching it, so long-lived checkouts (e.g. in CI caches) don't keep
+    /// growing with every fetch. Requires git to be installed.
+    #[serde(default)]
+    pub prune: bool,
+}
+
+impl DatabaseConfig {
+    /// Should the advisory database (and crates.io index) be fetched?
+    pub fn should_fetch(&self) -> bool {
+        self.fetch && !self.locked
+    }
 }
 
 /// Output configuration
@@ -146,19 +257,40 @@ pub struct OutputConfig {
     #[serde(default)]
     pub format: OutputFormat,
 
+    /// Version of the JSON report schema to emit (default: latest)
+    #[serde(default)]
+    pub json_schema_version: Option<report::SchemaVersion>,
+
     /// Enable quiet mode
     pub quiet: bool,
 
+    /// Summarize vulnerabilities by advisory keyword
+    #[serde(default)]
+    pub group_by_keyword: bool,
+
     /// Show inverse dependency trees along with advisories (default: true)
     pub show_tree: Option<bool>,
 
+    /// Report findings without failing, i.e. always exit successfully
+    #[serde(default)]
+    pub report_only: bool,
+
+    /// Print the TOML source of the advisory behind each finding
+    #[serde(default)]
+    pub show_raw: bool,
+
+    /// Report how many candidate advisories were checked for each crate
+    #[serde(default)]
+    pub stats: bool,
+
+    #[serde(default)]
     pub disable_print_report: bool,
 }
 
 impl OutputConfig {
     /// Is quiet mode enabled?
     pub fn is_quiet(&self) -> bool {
-        self.quiet || self.format == OutputFormat::Json
+        self.quiet || self.format != OutputFormat::Terminal
     }
 }
 
@@ -199,6 +331,7 @@ impl DenyOption {
                 WarningKind::Unmaintained,
                 WarningKind::Unsound,
                 WarningKind::Yanked,
+                WarningKind::Unpublished,
             ],
             DenyOption::Unmaintained => &[WarningKind::Unmaintained],
             DenyOption::Unsound => &[WarningKind::Unsound],
@@ -235,6 +368,65 @@ pub enum OutputFormat {
     #[serde(rename = "terminal")]
     #[default]
     Terminal,
+
+    /// Display one finding per line without any styling, for diffing and grepping
+    #[serde(rename = "plain")]
+    Plain,
+
+    /// Display a Markdown report suitable for pasting into issues and PRs
+    #[serde(rename = "markdown")]
+    Markdown,
+
+    /// Display vulnerabilities as CSV, for tracking in spreadsheets
+    #[serde(rename = "csv")]
+    Csv,
+
+    /// Display the dependency graph in Graphviz DOT, with vulnerable crates highlighted
+    #[serde(rename = "dot")]
+    Dot,
+
+    /// Display one line per finding, most severe first, for `grep` and `awk`
+    #[serde(rename = "oneline")]
+    Oneline,
+
+    /// Display the dependency tree, `cargo tree` style, with vulnerable crates annotated inline
+    #[serde(rename = "tree")]
+    Tree,
+}
+
+impl OutputFormat {
+    /// Can raw advisory sources (`--show-raw`) be appended to this format?
+    ///
+    /// Only the human-readable formats can: appending TOML to the others would
+    /// break whatever parses or renders them. New formats don't get raw sources
+    /// unless they're added here.
+    pub fn shows_raw_sources(self) -> bool {
+        matches!(
+            self,
+            OutputFormat::Terminal | OutputFormat::Plain | OutputFormat::Markdown
+        )
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "json" => Ok(OutputFormat::Json),
+            "terminal" => Ok(OutputFormat::Terminal),
+            "plain" => Ok(OutputFormat::Plain),
+            "markdown" => Ok(OutputFormat::Markdown),
+            "csv" => Ok(OutputFormat::Csv),
+            "dot" => Ok(OutputFormat::Dot),
+            "oneline" => Ok(OutputFormat::Oneline),
+            "tree" => Ok(OutputFormat::Tree),
+            other => Err(Error::new(
+                ErrorKind::Parse,
+                &format!("invalid output format: {}", other),
+            )),
+        }
+    }
 }
 
 /// Helper enum for configuring filter values
@@ -263,39 +455,98 @@ pub struct TargetConfig {
 
     /// Target OS to find vulnerabilities for
     pub os: Option<FilterList<OS>>,
+
+    /// Version of `rustc` to check Rust toolchain advisories against
+    #[serde(default)]
+    pub rust_version: Option<Version>,
+
+    /// Find vulnerabilities for every target, rather than only the host's
+    /// when neither `arch` nor `os` is configured
+    #[serde(default)]
+    pub all_targets: bool,
 }
 
 impl TargetConfig {
-    /// Returns list of configured target architectures, cloning if needed
+    /// Returns list of configured target architectures, cloning if needed.
+    ///
+    /// Defaults to the host's architecture if no target is configured.
     pub fn arch(&self) -> Vec<Arch> {
         match &self.arch {
             Some(FilterList::Single(single)) => vec![*single],
             Some(FilterList::Many(many)) => many.clone(),
+            None if self.is_host_only() => host_platform()
+                .map(|platform| platform.target_arch)
+                .into_iter()
+                .collect(),
             None => vec![],
         }
     }
 
-    /// Returns list of configured target operating systems, cloning if needed
+    /// Returns list of configured target operating systems, cloning if needed.
+    ///
+    /// Defaults to the host's operating system if no target is configured.
     pub fn os(&self) -> Vec<OS> {
         match &self.os {
             Some(FilterList::Single(single)) => vec![*single],
             Some(FilterList::Many(many)) => many.clone(),
+            None if self.is_host_only() => host_platform()
+                .map(|platform| platform.target_os)
+                .into_iter()
+                .collect(),
             None => vec![],
         }
     }
+
+    /// Are vulnerabilities only found for the host, since no target was
+    /// configured and `all_targets` isn't set?
+    fn is_host_only(&self) -> bool {
+        self.arch.is_none() && self.os.is_none() && !self.all_targets
+    }
+}
+
+/// Platform this copy of `cargo-audit` was built for, i.e. the host it runs
+/// on, or `None` if it isn't one `platforms` knows about
+fn host_platform() -> Option<&'static Platform> {
+    Platform::find(env!("CARGO_AUDIT_HOST_TARGET"))
+}
+
+/// Policy configuration
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyConfig {
+    /// Minimum severity of a vulnerability which fails the audit for each
+    /// listed crate, overriding `advisories.severity_threshold`
+    #[serde(default)]
+    pub crates: BTreeMap<package::Name, advisory::Severity>,
 }
 
 /// Configuration for auditing for yanked crates
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct YankedConfig {
-    /// Is auditing for yanked crates enabled?
+    /// Is auditing for yanked (and unpublished) crates enabled?
     #[serde(default = "default_true")]
     pub enabled: bool,
 
     /// Should the crates.io index be updated before checking for yanked crates?
     #[serde(default = "default_true")]
     pub update_index: bool,
+
+    /// Directory crates.io index lookups are cached in between runs
+    /// (default: `~/.cargo/audit-index-cache`)
+    #[serde(default)]
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl YankedConfig {
+    /// Directory to cache index lookups in, if there is one
+    pub fn cache_dir(&self) -> Option<PathBuf> {
+        self.cache_dir.clone().or_else(|| {
+            home::cargo_home()
+                .ok()
+                .map(|cargo_home| cargo_home.join("audit-index-cache"))
+        })
+    }
 }
 
 impl Default for YankedConfig {
@@ -303,6 +554,7 @@ impl Default for YankedConfig {
         Self {
             enabled: true,
             update_index: true,
+            cache_dir: None,
         }
     }
 }
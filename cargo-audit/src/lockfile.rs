@@ -1,7 +1,8 @@
 //! Cargo.lock-related utilities
 
-use rustsec::{Error, ErrorKind};
+use rustsec::{advisory, Error, ErrorKind};
 use std::{
+    fs,
     path::{Path, PathBuf},
     process::Command,
 };
@@ -9,6 +10,12 @@ use std::{
 /// Name of `Cargo.lock`
 const CARGO_LOCK_FILE: &str = "Cargo.lock";
 
+/// Name of the file next to `Cargo.lock` listing advisory IDs to ignore
+pub const IGNORE_FILE: &str = ".rustsec-ignore";
+
+/// Directories which are never searched when discovering lockfiles
+const SKIPPED_DIRS: &[&str] = &["target", "vendor"];
+
 /// Tries to locate the lockfile at the specified file path. If it's missing, tries to generate it from `Cargo.toml`.
 /// Defaults to `Cargo.lock` in the current directory if passed `None` as the path.
 pub fn locate_or_generate(maybe_lockfile_path: Option<&Path>) -> rustsec::Result<PathBuf> {
@@ -53,3 +60,168 @@ pub fn generate() -> rustsec::Result<()> {
     }
     Ok(())
 }
+
+/// Load the advisory IDs listed in the [`IGNORE_FILE`] next to the given
+/// lockfile, if there is one.
+///
+/// The file has one advisory ID per line. Anything after a `#` is a comment,
+/// and blank lines are skipped.
+pub fn sidecar_ignores(lockfile_path: &Path) -> rustsec::Result<Vec<advisory::Id>> {
+    // Lockfiles read from stdin don't have a directory
+    if lockfile_path == Path::new("-") {
+        return Ok(vec![]);
+    }
+
+    let path = match lockfile_path.parent() {
+        Some(dir) => dir.join(IGNORE_FILE),
+        None => PathBuf::from(IGNORE_FILE),
+    };
+
+    if !path.is_file() {
+        return Ok(vec![]);
+    }
+
+    let mut ids = vec![];
+
+    for (n, line) in fs::read_to_string(&path)?.lines().enumerate() {
+        let id = line.split('#').next().unwrap_or_default().trim();
+        if id.is_empty() {
+            continue;
+        }
+
+        ids.push(id.parse().map_err(|e| {
+            Error::with_source(
+                ErrorKind::Parse,
+                format!("invalid advisory ID in {} line {}", path.display(), n + 1),
+                e,
+            )
+        })?);
+    }
+
+    Ok(ids)
+}
+
+/// Find every `Cargo.lock` file in the directory tree under `root`.
+///
+/// Hidden directories, `target/` and `vendor/` are skipped, as are paths
+/// excluded by `.gitignore` files. Only entries naming a file or directory
+/// (optionally with `*` wildcards, a leading `/` or a trailing `/`) are
+/// understood; other `.gitignore` patterns are ignored.
+///
+/// `max_depth` limits how many directories deep below `root` to search.
+pub fn discover(root: &Path, max_depth: Option<usize>) -> rustsec::Result<Vec<PathBuf>> {
+    let mut lockfiles = vec![];
+    discover_in(root, 0, max_depth, &[], &mut lockfiles)?;
+    lockfiles.sort();
+    Ok(lockfiles)
+}
+
+/// Recursively search `dir` for lockfiles, honoring the inherited ignore patterns
+fn discover_in(
+    dir: &Path,
+    depth: usize,
+    max_depth: Option<usize>,
+    inherited: &[String],
+    lockfiles: &mut Vec<PathBuf>,
+) -> rustsec::Result<()> {
+    let lockfile = dir.join(CARGO_LOCK_FILE);
+    if lockfile.is_file() {
+        lockfiles.push(lockfile);
+    }
+
+    if max_depth.map_or(false, |max| depth >= max) {
+        return Ok(());
+    }
+
+    let (anchored, mut patterns) = read_gitignore(dir);
+    patterns.extend_from_slice(inherited);
+
+    let mut entries = fs::read_dir(dir)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        let name = entry.file_name();
+        let name = match name.to_str() {
+            Some(name) => name,
+            None => continue,
+        };
+
+        if name.starts_with('.')
+            || SKIPPED_DIRS.contains(&name)
+            || anchored
+                .iter()
+                .chain(&patterns)
+                .any(|p| glob_match(p, name))
+        {
+            continue;
+        }
+
+        discover_in(&entry.path(), depth + 1, max_depth, &patterns, lockfiles)?;
+    }
+
+    Ok(())
+}
+
+/// Read the `.gitignore` in `dir`, if any, returning the patterns which apply
+/// only to `dir` itself and those which apply to all of its descendants
+fn read_gitignore(dir: &Path) -> (Vec<String>, Vec<String>) {
+    let mut anchored = vec![];
+    let mut unanchored = vec![];
+
+    let contents = fs::read_to_string(dir.join(".gitignore")).unwrap_or_default();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+
+        let pattern = line.trim_end_matches('/');
+        if let Some(pattern) = pattern.strip_prefix('/') {
+            if !pattern.contains('/') {
+                anchored.push(pattern.to_owned());
+            }
+        } else if !pattern.contains('/') {
+            unanchored.push(pattern.to_owned());
+        }
+    }
+
+    (anchored, unanchored)
+}
+
+/// Does the glob `pattern` match the lockfile at `path` or its directory?
+///
+/// `*` matches any sequence of characters, including `/`, so `vendor/*`
+/// matches every lockfile below `vendor`.
+pub fn path_matches(pattern: &str, path: &Path) -> bool {
+    let normalize = |path: &Path| {
+        let path = path.to_string_lossy().replace('\\', "/");
+        match path.strip_prefix("./") {
+            Some(path) => path.to_owned(),
+            None => path,
+        }
+    };
+    let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+
+    glob_match(pattern, &normalize(path))
+        || path
+            .parent()
+            .map_or(false, |dir| glob_match(pattern, &normalize(dir)))
+}
+
+/// Match a file name against a pattern where `*` matches any run of characters
+fn glob_match(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => match name.strip_prefix(prefix) {
+            Some(name) => (0..=name.len())
+                .filter(|&i| name.is_char_boundary(i))
+                .any(|i| glob_match(rest, &name[i..])),
+            None => false,
+        },
+    }
+}
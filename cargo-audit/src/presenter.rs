@@ -9,19 +9,32 @@ use abscissa_core::terminal::{
     Color::{self, Red, Yellow},
 };
 use rustsec::{
-    advisory::License,
+    advisory::{CvssPreference, License},
     cargo_lock::{
-        dependency::{self, graph::EdgeDirection, Dependency},
+        dependency::{
+            self,
+            graph::{EdgeDirection, NodeIndex},
+            Dependency,
+        },
         Lockfile, Package,
     },
+    report::DependencyPaths,
     WarningKind,
 };
-use std::{collections::BTreeSet as Set, io, path::Path};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet as Set, VecDeque},
+    io,
+    path::Path,
+};
 use std::{io::Write as _, string::ToString as _};
 
 #[cfg(feature = "binary-scanning")]
 use crate::binary_deps::BinaryReport;
 
+/// Number of crates listed by `--stats` in the terminal report (JSON has all of them)
+const HOTTEST_CRATES: usize = 10;
+
 /// Vulnerability information presenter
 #[derive(Clone, Debug)]
 pub struct Presenter {
@@ -41,11 +54,14 @@ impl Presenter {
     pub fn new(config: &OutputConfig) -> Self {
         Self {
             displayed_packages: Set::new(),
+            // Missing checksums are only reported with `--require-checksums`,
+            // so they always fail the audit
             deny_warning_kinds: config
                 .deny
                 .iter()
                 .flat_map(|k| k.get_warning_kind())
                 .copied()
+                .chain([WarningKind::MissingChecksum])
                 .collect(),
             config: config.clone(),
         }
@@ -104,11 +120,42 @@ impl Presenter {
         path: Option<&Path>,
     ) {
         if self.config.format == OutputFormat::Json {
-            serde_json::to_writer(io::stdout(), &report).unwrap();
+            let version = self.config.json_schema_version.unwrap_or_default();
+            serde_json::to_writer(io::stdout(), &report.versioned(version)).unwrap();
             io::stdout().flush().unwrap();
             return;
         }
 
+        if self.config.format == OutputFormat::Plain {
+            self.print_plain_report(report);
+            return;
+        }
+
+        if self.config.format == OutputFormat::Markdown {
+            self.print_markdown_report(report, path);
+            return;
+        }
+
+        if self.config.format == OutputFormat::Csv {
+            self.print_csv_report(report, lockfile);
+            return;
+        }
+
+        if self.config.format == OutputFormat::Dot {
+            self.print_dot_report(report, lockfile);
+            return;
+        }
+
+        if self.config.format == OutputFormat::Oneline {
+            self.print_oneline_report(report);
+            return;
+        }
+
+        if self.config.format == OutputFormat::Tree {
+            self.print_tree_report(report, lockfile);
+            return;
+        }
+
         let tree = lockfile
             .dependency_tree()
             .expect("invalid Cargo.lock dependency tree");
@@ -116,13 +163,20 @@ impl Presenter {
         // NOTE: when modifying the following logic, be sure to also update should_exit_with_failure()
 
         // Print out vulnerabilities and warnings
-        for vulnerability in &report.vulnerabilities.list {
-            self.print_vulnerability(vulnerability, &tree);
+        // The same advisory often applies to one crate version through many
+        // dependency paths, so only show it once (JSON keeps every occurrence)
+        for deduped in report.vulnerabilities.deduplicated(lockfile) {
+            self.print_vulnerability(
+                deduped.vulnerability,
+                deduped.paths,
+                &tree,
+                report.settings.cvss_preference,
+            );
         }
 
         for warnings in report.warnings.values() {
             for warning in warnings.iter() {
-                self.print_warning(warning, &tree)
+                self.print_warning(warning, &tree, report.settings.cvss_preference)
             }
         }
 
@@ -142,6 +196,18 @@ impl Presenter {
                     None => status_err!("{} vulnerabilities found!", report.vulnerabilities.count),
                 }
             }
+
+            let unscored = report.vulnerabilities.unscored().count();
+            if unscored > 0 {
+                status_err!("{} unscored, needing manual assessment", unscored);
+            }
+
+            if self.config.group_by_keyword {
+                for (keyword, vulns) in report.vulnerabilities.group_by_keyword() {
+                    let word = if vulns.len() == 1 { "issue" } else { "issues" };
+                    status_err!("{} {} {}", vulns.len(), keyword, word);
+                }
+            }
         }
 
         let (num_denied, num_not_denied) = self.count_warnings(report);
@@ -178,6 +244,519 @@ impl Presenter {
                 }
             }
         }
+
+        self.print_stats(report);
+    }
+
+    /// Print the number of candidate advisories, and the crates with the
+    /// most of them, if the report has `--stats`
+    fn print_stats(&self, report: &rustsec::Report) {
+        let Some(stats) = &report.stats else {
+            return;
+        };
+
+        status_ok!(
+            "Checked",
+            "{} candidate advisories for {} crates",
+            stats.candidate_advisories,
+            stats.packages.len()
+        );
+
+        for (name, counts) in stats.hottest().into_iter().take(HOTTEST_CRATES) {
+            status_ok!(
+                "Checked",
+                "{} candidate advisories for {} ({} matched)",
+                counts.candidates,
+                name,
+                counts.matched
+            );
+        }
+    }
+
+    /// Print the report as one tab-separated line per finding.
+    ///
+    /// Each line is `kind`, advisory ID, crate name, crate version, crate
+    /// source and title, with `-` for fields that don't apply (e.g. yanked crates have no advisory).
+    /// Lines are sorted so that output is stable across runs.
+    fn print_plain_report(&self, report: &rustsec::Report) {
+        let mut lines = vec![];
+
+        for vulnerability in &report.vulnerabilities.list {
+            lines.push(plain_line(
+                "vulnerability",
+                Some(&vulnerability.advisory),
+                &vulnerability.package,
+            ));
+        }
+
+        for warnings in report.warnings.values() {
+            for warning in warnings {
+                lines.push(plain_line(
+                    warning.kind.as_str(),
+                    warning.advisory.as_ref(),
+                    &warning.package,
+                ));
+            }
+        }
+
+        lines.sort();
+        lines.dedup();
+
+        let mut stdout = io::stdout().lock();
+        for line in lines {
+            writeln!(stdout, "{}", line).unwrap();
+        }
+        stdout.flush().unwrap();
+    }
+
+    /// Print the report as Markdown, e.g. for pasting into an issue or PR.
+    ///
+    /// Vulnerabilities are listed in a table, followed by a section per
+    /// warning which includes the advisory's own Markdown description.
+    fn print_markdown_report(&self, report: &rustsec::Report, path: Option<&Path>) {
+        let mut stdout = io::stdout().lock();
+
+        if let Some(path) = path {
+            writeln!(stdout, "# `{}`\n", path.display()).unwrap();
+        }
+
+        writeln!(stdout, "## Vulnerabilities\n").unwrap();
+
+        if report.vulnerabilities.list.is_empty() {
+            writeln!(stdout, "No vulnerabilities found.\n").unwrap();
+        } else {
+            writeln!(stdout, "| Crate | Version | Advisory | Severity | Title |").unwrap();
+            writeln!(stdout, "|-------|---------|----------|----------|-------|").unwrap();
+
+            for vulnerability in &report.vulnerabilities.list {
+                let advisory = &vulnerability.advisory;
+                let severity = advisory
+                    .severity(report.settings.cvss_preference)
+                    .map_or("-".to_owned(), |severity| severity.to_string());
+
+                writeln!(
+                    stdout,
+                    "| {} | {} | {} | {} | {} |",
+                    vulnerability.package.name,
+                    vulnerability.package.version,
+                    markdown_link(advisory),
+                    severity,
+                    markdown_cell(&advisory.title),
+                )
+                .unwrap();
+            }
+
+            writeln!(stdout).unwrap();
+        }
+
+        let warnings: Vec<_> = report.warnings.values().flatten().collect();
+
+        if !warnings.is_empty() {
+            writeln!(stdout, "## Warnings\n").unwrap();
+        }
+
+        for warning in warnings {
+            writeln!(
+                stdout,
+                "### {}: {} {}\n",
+                warning.kind, warning.package.name, warning.package.version
+            )
+            .unwrap();
+
+            if let Some(advisory) = &warning.advisory {
+                writeln!(stdout, "{}: {}\n", markdown_link(advisory), advisory.title).unwrap();
+
+                let description = advisory.description.trim();
+                if !description.is_empty() {
+                    writeln!(stdout, "{}\n", description).unwrap();
+                }
+            }
+        }
+
+        stdout.flush().unwrap();
+    }
+
+    /// Print each finding as `ID crate@version severity title`, most severe
+    /// first.
+    ///
+    /// Unlike [`OutputFormat::Plain`] the fields are space separated, so only
+    /// the title may contain spaces. Findings without an advisory use their
+    /// kind (e.g. `yanked`) as the ID, and unknown severities are shown as `-`.
+    fn print_oneline_report(&self, report: &rustsec::Report) {
+        let mut findings = report.all_findings();
+        findings.dedup();
+        // Stable, so findings of equal severity stay sorted by crate
+        findings.sort_by_key(|finding| Reverse(finding.severity));
+
+        let mut stdout = io::stdout().lock();
+        for finding in findings {
+            let mut line = format!(
+                "{} {}@{} {} {}",
+                finding
+                    .id
+                    .as_ref()
+                    .map_or(finding.kind.to_string(), ToString::to_string),
+                finding.package,
+                finding.version,
+                finding
+                    .source
+                    .as_ref()
+                    .map_or("-".to_owned(), ToString::to_string),
+                finding.severity.map_or("-", |severity| severity.as_str()),
+            );
+
+            if let Some(title) = &finding.title {
+                line.push(' ');
+                line.push_str(&title.replace(['\n', '\r'], " "));
+            }
+
+            writeln!(stdout, "{}", line).unwrap();
+        }
+        stdout.flush().unwrap();
+    }
+
+    /// Print the TOML source of the advisory behind each finding, if `--show-raw`
+    /// was given, so it can be checked by hand.
+    ///
+    /// This is skipped for formats whose output it would break; see
+    /// [`OutputFormat::shows_raw_sources`].
+    pub fn print_raw_sources(&self, report: &rustsec::Report, database: &rustsec::Database) {
+        if !self.config.show_raw || !self.config.format.shows_raw_sources() {
+            return;
+        }
+
+        let mut findings = report.all_findings();
+        findings.dedup();
+
+        let mut stdout = io::stdout().lock();
+        for finding in findings {
+            let Some(id) = &finding.id else {
+                continue;
+            };
+
+            let Some(raw_source) = database.raw_source(id) else {
+                continue;
+            };
+
+            writeln!(
+                stdout,
+                "{} ({} {}):\n```toml\n{}\n```\n",
+                id,
+                finding.package,
+                finding.version,
+                raw_source.trim_end()
+            )
+            .unwrap();
+        }
+        stdout.flush().unwrap();
+    }
+
+    /// Print the vulnerabilities in the report as CSV, one row per vulnerability.
+    ///
+    /// Missing values (e.g. the severity of an advisory without CVSS) are left
+    /// empty. The dependency path runs from a workspace member to the crate.
+    fn print_csv_report(&self, report: &rustsec::Report, lockfile: &Lockfile) {
+        let mut stdout = io::stdout().lock();
+
+        writeln!(
+            stdout,
+            "crate,version,advisory_id,severity,title,patched_versions,dependency_path"
+        )
+        .unwrap();
+
+        let paths = DependencyPaths::new(lockfile);
+
+        for vulnerability in &report.vulnerabilities.list {
+            let advisory = &vulnerability.advisory;
+            let dependency_path: Vec<_> = paths
+                .shortest(&Dependency::from(&vulnerability.package))
+                .iter()
+                .map(|dependency| format!("{} {}", dependency.name, dependency.version))
+                .collect();
+            let fields = [
+                vulnerability.package.name.to_string(),
+                vulnerability.package.version.to_string(),
+                advisory.id.to_string(),
+                advisory
+                    .severity(report.settings.cvss_preference)
+                    .map_or(String::new(), |severity| severity.to_string()),
+                advisory.title.clone(),
+                vulnerability
+                    .versions
+                    .patched()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" OR "),
+                dependency_path.join(" > "),
+            ];
+
+            let row: Vec<_> = fields.iter().map(|field| csv_field(field)).collect();
+            writeln!(stdout, "{}", row.join(",")).unwrap();
+        }
+
+        stdout.flush().unwrap();
+    }
+
+    /// Print the dependency graph in Graphviz DOT, e.g. for `dot -Tsvg`.
+    ///
+    /// Vulnerable crates are filled in red, and the crates and edges on
+    /// every path leading to them are outlined in red.
+    fn print_dot_report(&self, report: &rustsec::Report, lockfile: &Lockfile) {
+        let tree = lockfile
+            .dependency_tree()
+            .expect("invalid Cargo.lock dependency tree");
+        let graph = tree.graph();
+
+        let vulnerable: Set<_> = report
+            .vulnerabilities
+            .list
+            .iter()
+            .filter_map(|vuln| tree.nodes().get(&Dependency::from(&vuln.package)))
+            .copied()
+            .collect();
+
+        // Everything which (transitively) depends on a vulnerable crate
+        let mut on_path = vulnerable.clone();
+        let mut queue: VecDeque<_> = vulnerable.iter().copied().collect();
+        while let Some(node) = queue.pop_front() {
+            for parent in graph.neighbors_directed(node, EdgeDirection::Incoming) {
+                if on_path.insert(parent) {
+                    queue.push_back(parent);
+                }
+            }
+        }
+
+        let mut stdout = io::stdout().lock();
+        writeln!(stdout, "digraph dependencies {{").unwrap();
+        writeln!(stdout, "    node [shape=box];").unwrap();
+
+        for node in graph.node_indices() {
+            let package = &graph[node];
+            let id = dot_id(package);
+
+            if vulnerable.contains(&node) {
+                writeln!(
+                    stdout,
+                    "    {} [color=red, style=filled, fillcolor=red, fontcolor=white];",
+                    id
+                )
+                .unwrap();
+            } else if on_path.contains(&node) {
+                writeln!(stdout, "    {} [color=red];", id).unwrap();
+            } else {
+                writeln!(stdout, "    {};", id).unwrap();
+            }
+        }
+
+        for edge in graph.raw_edges() {
+            let (source, target) = (edge.source(), edge.target());
+            let attrs = if on_path.contains(&source) && on_path.contains(&target) {
+                " [color=red]"
+            } else {
+                ""
+            };
+
+            writeln!(
+                stdout,
+                "    {} -> {}{};",
+                dot_id(&graph[source]),
+                dot_id(&graph[target]),
+                attrs
+            )
+            .unwrap();
+        }
+
+        writeln!(stdout, "}}").unwrap();
+        stdout.flush().unwrap();
+    }
+
+    /// Print the dependency tree of each workspace root, like `cargo tree`,
+    /// with the advisories affecting vulnerable crates (and their severity,
+    /// if scored) after them, e.g. `base64 0.5.1 [RUSTSEC-2017-0004 critical]`.
+    ///
+    /// Crates whose dependencies were already shown are marked with `(*)`.
+    fn print_tree_report(&self, report: &rustsec::Report, lockfile: &Lockfile) {
+        let tree = lockfile
+            .dependency_tree()
+            .expect("invalid Cargo.lock dependency tree");
+        let graph = tree.graph();
+
+        let mut findings: BTreeMap<NodeIndex, Vec<String>> = BTreeMap::new();
+        for vuln in &report.vulnerabilities.list {
+            // Toolchain packages (e.g. `std`) aren't in the lockfile
+            let Some(&node) = tree.nodes().get(&Dependency::from(&vuln.package)) else {
+                continue;
+            };
+
+            let finding = match vuln.advisory.severity(report.settings.cvss_preference) {
+                Some(severity) => format!("{} {}", vuln.advisory.id, severity),
+                None => vuln.advisory.id.to_string(),
+            };
+
+            let node_findings = findings.entry(node).or_default();
+            if !node_findings.contains(&finding) {
+                node_findings.push(finding);
+            }
+        }
+
+        let mut roots = tree.roots();
+        roots.sort_by_key(|&node| &graph[node]);
+
+        let mut stdout = io::stdout().lock();
+        let mut visited = Set::new();
+        for root in roots {
+            print_tree_node(
+                &mut stdout,
+                graph,
+                root,
+                &findings,
+                &mut vec![],
+                &mut visited,
+            )
+            .unwrap();
+        }
+        stdout.flush().unwrap();
+    }
+
+    /// Print the reports for several scanned binaries as a single JSON
+    /// object, keyed by the path of each binary
+    #[cfg(feature = "binary-scanning")]
+    pub fn print_json_reports(&self, reports: &BTreeMap<String, rustsec::Report>) {
+        let version = self.config.json_schema_version.unwrap_or_default();
+        let reports: BTreeMap<_, _> = reports
+            .iter()
+            .map(|(path, report)| (path, report.versioned(version)))
+            .collect();
+
+        let mut stdout = io::stdout().lock();
+        serde_json::to_writer(&mut stdout, &reports).unwrap();
+        stdout.flush().unwrap();
+    }
+
+    /// Print advisories outside of a report, e.g. for `--new-advisories`.
+    ///
+    /// Each advisory is a line of its tab-separated ID, crate and title, or
+    /// for [`OutputFormat::Json`] the list is an array of advisory metadata.
+    pub fn print_advisory_list(&self, advisories: &[&rustsec::Advisory]) {
+        let mut stdout = io::stdout().lock();
+
+        if self.config.format == OutputFormat::Json {
+            let metadata: Vec<_> = advisories
+                .iter()
+                .map(|advisory| &advisory.metadata)
+                .collect();
+            serde_json::to_writer(&mut stdout, &metadata).unwrap();
+        } else {
+            for advisory in advisories {
+                writeln!(
+                    stdout,
+                    "{}\t{}\t{}",
+                    advisory.id(),
+                    advisory.metadata.package,
+                    plain_field(advisory.title())
+                )
+                .unwrap();
+            }
+        }
+
+        stdout.flush().unwrap();
+    }
+
+    /// Print everything known about an advisory, for `--explain`
+    pub fn print_advisory(&self, advisory: &rustsec::Advisory, preference: CvssPreference) {
+        let metadata = &advisory.metadata;
+        let versions = &advisory.versions;
+        let mut stdout = io::stdout().lock();
+
+        writeln!(stdout, "{}: {}\n", metadata.id, metadata.title).unwrap();
+
+        let join = |reqs: &[rustsec::VersionReq]| {
+            reqs.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" OR ")
+        };
+
+        let mut attrs = vec![
+            ("Crate:", metadata.package.to_string()),
+            ("Date:", metadata.date.to_string()),
+        ];
+
+        if let Some(informational) = &metadata.informational {
+            attrs.push(("Type:", informational.to_string()));
+        }
+
+        if let Some(withdrawn) = &metadata.withdrawn {
+            attrs.push(("Withdrawn:", withdrawn.to_string()));
+        }
+
+        if !metadata.aliases.is_empty() {
+            let aliases: Vec<_> = metadata.aliases.iter().map(ToString::to_string).collect();
+            attrs.push(("Aliases:", aliases.join(", ")));
+        }
+
+        if let Some(severity) = cvss_severity(metadata, preference) {
+            attrs.push(("Severity:", severity));
+        }
+
+        if let Some(cvss) = &metadata.cvss {
+            attrs.push(("CVSS:", cvss.to_string()));
+        }
+
+        if let Some(cvss) = &metadata.cvss_v4 {
+            attrs.push(("CVSS:", cvss.to_string()));
+        }
+
+        if !versions.affected_ranges().is_empty() {
+            attrs.push(("Affected:", join(versions.affected_ranges())));
+        }
+
+        if !versions.affected().is_empty() {
+            let affected: Vec<_> = versions
+                .affected()
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            attrs.push(("Affected:", affected.join(", ")));
+        }
+
+        attrs.push((
+            "Patched:",
+            if versions.patched().is_empty() {
+                "no patched versions".to_owned()
+            } else {
+                join(versions.patched())
+            },
+        ));
+
+        if !versions.unaffected().is_empty() {
+            attrs.push(("Unaffected:", join(versions.unaffected())));
+        }
+
+        if let Some(url) = metadata
+            .id
+            .url()
+            .or_else(|| metadata.url.as_ref().map(ToString::to_string))
+        {
+            attrs.push(("URL:", url));
+        }
+
+        for (name, value) in attrs {
+            writeln!(stdout, "{:<12}{}", name, value).unwrap();
+        }
+
+        for (n, reference) in metadata.references.iter().enumerate() {
+            let name = if n == 0 { "References:" } else { "" };
+            writeln!(stdout, "{:<12}{}", name, reference).unwrap();
+        }
+
+        let description = metadata.description.trim();
+        if !description.is_empty() {
+            writeln!(stdout, "\n{}", description).unwrap();
+        }
+
+        stdout.flush().unwrap();
     }
 
     /// Whether to print the report
@@ -185,6 +764,11 @@ impl Presenter {
         !self.config.disable_print_report
     }
 
+    /// Is the report printed as JSON?
+    pub fn is_json(&self) -> bool {
+        self.config.format == OutputFormat::Json
+    }
+
     /// Print the vulnerability report for cargo-audit
     pub fn print_self_report(&mut self, self_advisories: &[rustsec::Advisory]) {
         if self_advisories.is_empty() {
@@ -204,6 +788,7 @@ impl Presenter {
             self.print_metadata(
                 &advisory.metadata,
                 self.warning_color(self.config.deny.contains(&DenyOption::Warnings)),
+                CvssPreference::default(),
             );
         }
         println!();
@@ -213,6 +798,9 @@ impl Presenter {
     /// such as --deny=warnings
     #[must_use]
     pub fn should_exit_with_failure(&self, report: &rustsec::Report) -> bool {
+        if self.config.report_only {
+            return false;
+        }
         if report.vulnerabilities.found {
             return true;
         }
@@ -230,7 +818,9 @@ impl Presenter {
         &self,
         self_advisories: &[rustsec::Advisory],
     ) -> bool {
-        !self_advisories.is_empty() && self.config.deny.contains(&DenyOption::Warnings)
+        !self.config.report_only
+            && !self_advisories.is_empty()
+            && self.config.deny.contains(&DenyOption::Warnings)
     }
 
     /// Count up the warnings, sorting into denied and allowed.
@@ -253,11 +843,57 @@ impl Presenter {
     fn print_vulnerability(
         &mut self,
         vulnerability: &rustsec::Vulnerability,
+        paths: usize,
         tree: &dependency::Tree,
+        preference: CvssPreference,
     ) {
         self.print_attr(Red, "Crate:    ", &vulnerability.package.name);
         self.print_attr(Red, "Version:  ", vulnerability.package.version.to_string());
-        self.print_metadata(&vulnerability.advisory, Red);
+
+        if paths > 1 {
+            self.print_attr(Red, "Paths:    ", format!("{} dependency paths", paths));
+        }
+
+        self.print_metadata(&vulnerability.advisory, Red, preference);
+
+        if vulnerability.unscored {
+            self.print_attr(Red, "Severity: ", "unscored, needs manual assessment");
+        }
+
+        if let Some(affected) = &vulnerability.affected {
+            if let Some(cfg) = &affected.cfg {
+                self.print_attr(Red, "Platform: ", format!("only affected on cfg({})", cfg));
+            }
+
+            if !affected.features.is_empty() {
+                // Without feature data, the crate is reported in case they're enabled
+                let caveat = match vulnerability.features_enabled {
+                    Some(_) => "",
+                    None => " (enabled features unknown)",
+                };
+                self.print_attr(
+                    Red,
+                    "Features: ",
+                    format!(
+                        "only affected with {} enabled{}",
+                        affected.features.join(" OR "),
+                        caveat
+                    ),
+                );
+            }
+        }
+
+        if let Some(reachable) = vulnerability.reachable {
+            self.print_attr(
+                Red,
+                "Reachable:",
+                if reachable {
+                    "yes, affected functions are reachable"
+                } else {
+                    "no, affected functions are unreachable"
+                },
+            );
+        }
 
         if vulnerability.versions.patched().is_empty() {
             self.print_attr(Red, "Solution: ", "No fixed upgrade is available!");
@@ -266,7 +902,7 @@ impl Presenter {
                 Red,
                 "Solution: ",
                 format!(
-                    "Upgrade to {}",
+                    "Upgrade to {} ({})",
                     vulnerability
                         .versions
                         .patched()
@@ -274,7 +910,8 @@ impl Presenter {
                         .map(ToString::to_string)
                         .collect::<Vec<_>>()
                         .as_slice()
-                        .join(" OR ")
+                        .join(" OR "),
+                    vulnerability.remediation_confidence
                 ),
             );
         }
@@ -284,7 +921,12 @@ impl Presenter {
     }
 
     /// Print information about a given warning
-    fn print_warning(&mut self, warning: &rustsec::Warning, tree: &dependency::Tree) {
+    fn print_warning(
+        &mut self,
+        warning: &rustsec::Warning,
+        tree: &dependency::Tree,
+        preference: CvssPreference,
+    ) {
         let color = self.warning_color(self.deny_warning_kinds.contains(&warning.kind));
 
         self.print_attr(color, "Crate:    ", &warning.package.name);
@@ -292,7 +934,17 @@ impl Presenter {
         self.print_attr(color, "Warning:  ", warning.kind.as_str());
 
         if let Some(metadata) = &warning.advisory {
-            self.print_metadata(metadata, color)
+            self.print_metadata(metadata, color, preference)
+        }
+
+        if let Some(latest) = &warning.latest_release {
+            let released = match (&latest.date, latest.age_days) {
+                (Some(date), Some(age)) => format!("{} ({} days ago)", date, age),
+                (Some(date), None) => date.to_string(),
+                _ => "unknown".to_owned(),
+            };
+            self.print_attr(color, "Latest:   ", latest.version.to_string());
+            self.print_attr(color, "Released: ", released);
         }
 
         self.print_tree(color, &warning.package, tree);
@@ -309,11 +961,20 @@ impl Presenter {
     }
 
     /// Print a warning about a particular advisory
-    fn print_metadata(&self, metadata: &rustsec::advisory::Metadata, color: Color) {
+    fn print_metadata(
+        &self,
+        metadata: &rustsec::advisory::Metadata,
+        color: Color,
+        preference: CvssPreference,
+    ) {
         self.print_attr(color, "Title:    ", &metadata.title);
         self.print_attr(color, "Date:     ", &metadata.date);
         self.print_attr(color, "ID:       ", &metadata.id);
 
+        if let Some(collection) = &metadata.collection {
+            self.print_attr(color, "Source:   ", collection.as_str());
+        }
+
         if metadata.license == License::CcBy40 {
             // We must preserve the original URL from the `url` field
             if let Some(url) = &metadata.url {
@@ -331,12 +992,12 @@ impl Presenter {
             }
         }
 
-        if let Some(cvss) = &metadata.cvss {
-            self.print_attr(
-                color,
-                "Severity: ",
-                format!("{} ({})", cvss.score().value(), cvss.score().severity()),
-            );
+        if let Some(severity) = cvss_severity(metadata, preference) {
+            self.print_attr(color, "Severity: ", severity);
+        }
+
+        if let Some(context) = &metadata.context {
+            self.print_attr(color, "Context:  ", context);
         }
     }
 
@@ -368,7 +1029,11 @@ impl Presenter {
         //     .print_stdout("")
         //     .unwrap();
 
-        let package_node = tree.nodes()[&Dependency::from(package)];
+        // Toolchain packages (e.g. `std`) aren't in the lockfile
+        let Some(&package_node) = tree.nodes().get(&Dependency::from(package)) else {
+            return;
+        };
+
         tree.render(
             &mut io::stdout(),
             package_node,
@@ -378,3 +1043,116 @@ impl Presenter {
         .unwrap();
     }
 }
+
+/// Format a single finding for [`OutputFormat::Plain`]
+fn plain_line(
+    kind: &str,
+    advisory: Option<&rustsec::advisory::Metadata>,
+    package: &Package,
+) -> String {
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}",
+        kind,
+        advisory.map_or("-".to_owned(), |advisory| advisory.id.to_string()),
+        package.name,
+        package.version,
+        package
+            .source
+            .as_ref()
+            .map_or("-".to_owned(), ToString::to_string),
+        advisory.map_or("-".to_owned(), |advisory| plain_field(&advisory.title)),
+    )
+}
+
+/// Replace tabs and newlines, which would break the layout of tab-separated fields
+fn plain_field(s: &str) -> String {
+    s.replace(['\t', '\n', '\r'], " ")
+}
+
+/// Escape text for use in a single Markdown table cell
+fn markdown_cell(s: &str) -> String {
+    s.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+/// Quote a CSV field if it contains a separator, quote, or line break
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+/// Print `node` for [`OutputFormat::Tree`], followed by its dependencies the
+/// first time it's seen. `levels` records whether each ancestor has further
+/// siblings, which decides the lines drawn to its left.
+fn print_tree_node(
+    w: &mut impl io::Write,
+    graph: &dependency::graph::Graph,
+    node: NodeIndex,
+    findings: &BTreeMap<NodeIndex, Vec<String>>,
+    levels: &mut Vec<bool>,
+    visited: &mut Set<NodeIndex>,
+) -> io::Result<()> {
+    if let Some((&last_continues, rest)) = levels.split_last() {
+        for &continues in rest {
+            write!(w, "{}   ", if continues { "│" } else { " " })?;
+        }
+        write!(w, "{}── ", if last_continues { "├" } else { "└" })?;
+    }
+
+    let package = &graph[node];
+    write!(w, "{} {}", package.name, package.version)?;
+    if let Some(node_findings) = findings.get(&node) {
+        write!(w, " [{}]", node_findings.join(", "))?;
+    }
+
+    let mut dependencies: Vec<_> = graph
+        .neighbors_directed(node, EdgeDirection::Outgoing)
+        .collect();
+    dependencies.sort_by_key(|&dependency| &graph[dependency]);
+    dependencies.dedup();
+
+    if !visited.insert(node) {
+        if !dependencies.is_empty() {
+            write!(w, " (*)")?;
+        }
+        return writeln!(w);
+    }
+    writeln!(w)?;
+
+    for (i, &dependency) in dependencies.iter().enumerate() {
+        levels.push(i + 1 < dependencies.len());
+        print_tree_node(w, graph, dependency, findings, levels, visited)?;
+        levels.pop();
+    }
+
+    Ok(())
+}
+
+/// Quoted DOT node ID for a package, e.g. `"base64 0.5.1"`
+fn dot_id(package: &Package) -> String {
+    format!("\"{} {}\"", package.name, package.version)
+}
+
+/// Markdown link to an advisory, or just its ID if it has no URL
+/// Score and severity of the advisory's preferred CVSS vector, e.g. `9.8 (critical)`
+fn cvss_severity(
+    metadata: &rustsec::advisory::Metadata,
+    preference: CvssPreference,
+) -> Option<String> {
+    let score = metadata.cvss_score(preference)?;
+    let severity = metadata.severity(preference)?;
+    Some(format!("{} ({})", score, severity))
+}
+
+fn markdown_link(advisory: &rustsec::advisory::Metadata) -> String {
+    match advisory
+        .id
+        .url()
+        .or_else(|| advisory.url.as_ref().map(ToString::to_string))
+    {
+        Some(url) => format!("[{}]({})", advisory.id, url),
+        None => advisory.id.to_string(),
+    }
+}
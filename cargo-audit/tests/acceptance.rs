@@ -9,7 +9,11 @@
 
 use abscissa_core::testing::prelude::*;
 use once_cell::sync::Lazy;
-use std::{io::BufRead, path::PathBuf};
+use std::{
+    io::BufRead,
+    path::{Path, PathBuf},
+    process::Command,
+};
 use tempfile::TempDir;
 
 /// Directory containing the advisory database.
@@ -91,6 +95,20 @@ pub fn get_advisories_json(process: &mut Process) -> serde_json::Value {
     serde_json::from_str(&output).unwrap()
 }
 
+/// Read everything a process writes to stderr
+pub fn read_stderr(process: &mut Process) -> String {
+    let mut output = String::new();
+    while process.stderr().read_line(&mut output).unwrap() > 0 {}
+    output
+}
+
+/// Read everything a process writes to stdout
+pub fn read_stdout(process: &mut Process) -> String {
+    let mut output = String::new();
+    while process.stdout().read_line(&mut output).unwrap() > 0 {}
+    output
+}
+
 #[test]
 fn no_advisories_found_exit_success() {
     secure_cmd_runner().status().expect_success();
@@ -194,6 +212,950 @@ fn advisories_found_json() {
     assert_eq!(advisory_id, "RUSTSEC-2017-0004");
 }
 
+#[test]
+fn advisories_found_json_includes_description() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--json");
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_code(1);
+
+    let description = json
+        .pointer("/vulnerabilities/list/0/advisory/description")
+        .unwrap()
+        .as_str()
+        .unwrap();
+
+    assert!(!description.is_empty());
+}
+
+#[test]
+fn advisories_found_json_includes_patched_versions() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--json");
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_code(1);
+
+    let patched = json
+        .pointer("/vulnerabilities/list/0/patched_versions")
+        .unwrap()
+        .as_array()
+        .unwrap();
+    assert!(!patched.is_empty());
+
+    for req in patched {
+        let req: rustsec::VersionReq = req.as_str().unwrap().parse().unwrap();
+        assert!(!req.matches(&"0.5.1".parse().unwrap()));
+    }
+
+    assert!(json
+        .pointer("/vulnerabilities/list/0/unaffected_versions")
+        .unwrap()
+        .is_array());
+
+    // base64 0.5.1 is fixed in 0.5.2
+    assert_eq!(
+        json.pointer("/vulnerabilities/list/0/remediation_confidence")
+            .and_then(|level| level.as_str()),
+        Some("patch")
+    );
+}
+
+#[test]
+fn advisories_found_json_includes_database_commit() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--json");
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_code(1);
+
+    let commit = json
+        .pointer("/database/last-commit")
+        .unwrap()
+        .as_str()
+        .unwrap();
+    assert_eq!(commit.len(), 40);
+    assert!(commit.chars().all(|c| c.is_ascii_hexdigit()));
+
+    assert!(json.pointer("/database/last-updated").unwrap().is_string());
+    assert_eq!(
+        json.pointer("/database/path").unwrap().as_str().unwrap(),
+        ADVISORY_DB_DIR.path().to_str().unwrap()
+    );
+}
+
+#[test]
+fn collection_filter() {
+    let count_for = |collection: &str, code: i32| {
+        let mut runner = vulnerable_cmd_runner();
+        runner.arg("--json").arg("--collection").arg(collection);
+
+        let mut process = runner.run();
+        let json = get_advisories_json(&mut process);
+        process.wait().unwrap().expect_code(code);
+
+        json.pointer("/vulnerabilities/count")
+            .unwrap()
+            .as_u64()
+            .unwrap()
+    };
+
+    assert_eq!(count_for("crates", 1), 1);
+    assert_eq!(count_for("rust", 0), 0);
+}
+
+#[test]
+fn renamed_dependency_advisories_found_json() {
+    // `Cargo.lock` records the real crate name rather than the rename alias
+    let mut runner = new_cmd_runner("renamed_vuln");
+    runner.arg("--json");
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_code(1);
+
+    let advisory_id = json
+        .pointer("/vulnerabilities/list/0/advisory/id")
+        .unwrap()
+        .as_str()
+        .unwrap();
+
+    assert_eq!(advisory_id, "RUSTSEC-2017-0004");
+}
+
+#[test]
+fn advisories_found_json_schema_v1() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--json").arg("--json-schema-version").arg("1");
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_code(1);
+
+    assert!(json.get("schema-version").is_none());
+    assert_eq!(
+        json.pointer("/vulnerabilities/count")
+            .unwrap()
+            .as_u64()
+            .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn empty_database_is_not_a_clean_result() {
+    let empty_db = TempDir::new().unwrap();
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--db")
+        .arg(empty_db.path())
+        .arg("--no-fetch")
+        .arg("--json")
+        .arg("--file")
+        .arg(
+            [
+                env!("CARGO_MANIFEST_DIR"),
+                "tests",
+                "support",
+                "base64_vuln",
+                "Cargo.lock",
+            ]
+            .iter()
+            .collect::<PathBuf>(),
+        )
+        .capture_stdout()
+        .capture_stderr();
+
+    let mut process = runner.run();
+    let output = read_stdout(&mut process);
+    process.wait().unwrap().expect_code(2);
+
+    // No report claiming a clean result is printed
+    assert_eq!(output, "");
+}
+
+#[test]
+fn empty_database_is_not_a_clean_workspace_result() {
+    let empty_db = TempDir::new().unwrap();
+    let root: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "support",
+        "workspace_tree",
+    ]
+    .iter()
+    .collect();
+
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--db")
+        .arg(empty_db.path())
+        .arg("--no-fetch")
+        .arg("--workspace-root")
+        .arg(&root)
+        .capture_stdout()
+        .capture_stderr();
+
+    runner.status().expect_code(2);
+}
+
+#[test]
+fn database_missing_required_collection_fails() {
+    // A broken mirror with only the `rust` collection
+    let db = TempDir::new().unwrap();
+    std::fs::create_dir_all(db.path().join("rust/std")).unwrap();
+    std::fs::write(
+        db.path().join("rust/std/RUSTSEC-2001-2101.md"),
+        r#"```toml
+[advisory]
+id = "RUSTSEC-2001-2101"
+package = "std"
+date = "2001-02-03"
+
+[versions]
+patched = [">= 1.2.0"]
+```
+
+# Example toolchain advisory
+"#,
+    )
+    .unwrap();
+
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--db")
+        .arg(db.path())
+        .arg("--no-fetch")
+        .arg("--require-collection")
+        .arg("crates")
+        .arg("--file")
+        .arg(
+            [
+                env!("CARGO_MANIFEST_DIR"),
+                "tests",
+                "support",
+                "base64_vuln",
+                "Cargo.lock",
+            ]
+            .iter()
+            .collect::<PathBuf>(),
+        )
+        .capture_stdout()
+        .capture_stderr();
+
+    let mut process = runner.run();
+    let output = read_stderr(&mut process);
+
+    process.wait().unwrap().expect_code(1);
+    assert!(
+        output.contains("no advisories found in the `crates` collection"),
+        "unexpected output: {}",
+        output
+    );
+}
+
+#[test]
+fn database_path_reported() {
+    let runner = vulnerable_cmd_runner();
+    let mut process = runner.run();
+
+    let db_path = ADVISORY_DB_DIR.path().display().to_string();
+    let found = read_stderr(&mut process).contains(&db_path);
+
+    process.wait().unwrap().expect_code(1);
+    assert!(found, "advisory DB path not found in output");
+}
+
+#[test]
+fn locked_db_does_not_fetch() {
+    // Ensure the database has been fetched at least once
+    secure_cmd_runner().status().expect_success();
+
+    let mut runner = secure_cmd_runner();
+    runner.arg("--locked-db").arg("--stale");
+    let mut process = runner.run();
+
+    let output = read_stderr(&mut process);
+
+    process.wait().unwrap().expect_success();
+    assert!(!output.contains("Fetching"));
+    assert!(output.contains("advisory database at commit"));
+}
+
+/// Run `git` with the given arguments in `dir`, returning its output
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args([
+            "-c",
+            "user.name=rustsec",
+            "-c",
+            "user.email=rustsec@example.com",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git should be installed");
+    assert!(output.status.success(), "git {:?} failed", args);
+    String::from_utf8(output.stdout).unwrap().trim().to_owned()
+}
+
+#[test]
+fn db_commit_is_not_left_checked_out() {
+    let db = TempDir::new().unwrap();
+    git(db.path(), &["init", "--quiet"]);
+
+    let mut commits = vec![];
+    for id in ["RUSTSEC-2001-2101", "RUSTSEC-2001-2102"] {
+        write_base64_advisory(db.path(), id);
+        git(db.path(), &["add", "."]);
+        git(db.path(), &["commit", "--quiet", "-m", id]);
+        commits.push(git(db.path(), &["rev-parse", "HEAD"]));
+    }
+
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--db")
+        .arg(db.path())
+        .arg("--no-fetch")
+        .arg("--db-commit")
+        .arg(&commits[0])
+        .arg("--json")
+        .arg("--file")
+        .arg(
+            [
+                env!("CARGO_MANIFEST_DIR"),
+                "tests",
+                "support",
+                "base64_vuln",
+                "Cargo.lock",
+            ]
+            .iter()
+            .collect::<PathBuf>(),
+        )
+        .capture_stdout()
+        .capture_stderr();
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_code(1);
+
+    // Only the advisory in the pinned commit is found...
+    assert_eq!(json.pointer("/vulnerabilities/count").unwrap(), 1);
+
+    // ...but the checkout is back at the latest commit afterwards
+    assert_eq!(git(db.path(), &["rev-parse", "HEAD"]), commits[1]);
+    assert!(db
+        .path()
+        .join("crates/base64/RUSTSEC-2001-2102.md")
+        .is_file());
+}
+
+/// Number of packfiles in the advisory database checkout at `db`
+fn count_packs(db: &Path) -> usize {
+    std::fs::read_dir(db.join(".git/objects/pack"))
+        .unwrap()
+        .filter(|entry| {
+            let path = entry.as_ref().unwrap().path();
+            path.extension().and_then(|ext| ext.to_str()) == Some("pack")
+        })
+        .count()
+}
+
+#[test]
+fn prune_db_repacks_fetched_checkout() {
+    let tmp = TempDir::new().unwrap();
+    let source = tmp.path().join("source");
+    std::fs::create_dir_all(&source).unwrap();
+    git(&source, &["init", "--quiet"]);
+
+    // Don't look up yanked crates, so only the local advisory DB is fetched
+    let config = tmp.path().join("audit.toml");
+    std::fs::write(&config, "[yanked]\nenabled = false\n").unwrap();
+
+    let db = tmp.path().join("db");
+    let audit = |prune: bool, id: &str| {
+        write_base64_advisory(&source, id);
+        git(&source, &["add", "."]);
+        git(&source, &["commit", "--quiet", "-m", id]);
+
+        let mut runner = CmdRunner::default();
+        runner
+            .arg("audit")
+            .arg("--audit-config")
+            .arg(&config)
+            .arg("--db")
+            .arg(&db)
+            .arg("--url")
+            .arg(format!("file://{}", source.display()))
+            .arg("--file")
+            .arg(
+                [
+                    env!("CARGO_MANIFEST_DIR"),
+                    "tests",
+                    "support",
+                    "base64_vuln",
+                    "Cargo.lock",
+                ]
+                .iter()
+                .collect::<PathBuf>(),
+            )
+            .capture_stdout()
+            .capture_stderr();
+        if prune {
+            runner.arg("--prune-db");
+        }
+        runner.status().expect_code(1);
+    };
+
+    // Every fetch adds another pack...
+    audit(false, "RUSTSEC-2001-2101");
+    audit(false, "RUSTSEC-2001-2102");
+    assert!(count_packs(&db) > 1);
+
+    // ...until they're repacked into one
+    audit(true, "RUSTSEC-2001-2103");
+    assert_eq!(count_packs(&db), 1);
+}
+
+#[test]
+fn db_url_mismatch_policy_from_config() {
+    let tmp = TempDir::new().unwrap();
+    let source = |name: &str, id: &str| {
+        let dir = tmp.path().join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        git(&dir, &["init", "--quiet"]);
+        write_base64_advisory(&dir, id);
+        git(&dir, &["add", "."]);
+        git(&dir, &["commit", "--quiet", "-m", id]);
+        format!("file://{}", dir.display())
+    };
+    let original = source("original", "RUSTSEC-2001-2101");
+    let mirror = source("mirror", "RUSTSEC-2001-2102");
+
+    let db = tmp.path().join("db");
+    let audit = |url: &str, config: &str| {
+        let config_path = tmp.path().join("audit.toml");
+        std::fs::write(
+            &config_path,
+            format!("[yanked]\nenabled = false\n{}", config),
+        )
+        .unwrap();
+
+        let mut runner = CmdRunner::default();
+        runner
+            .arg("audit")
+            .arg("--audit-config")
+            .arg(&config_path)
+            .arg("--db")
+            .arg(&db)
+            .arg("--url")
+            .arg(url)
+            .arg("--json")
+            .arg("--file")
+            .arg(
+                [
+                    env!("CARGO_MANIFEST_DIR"),
+                    "tests",
+                    "support",
+                    "base64_vuln",
+                    "Cargo.lock",
+                ]
+                .iter()
+                .collect::<PathBuf>(),
+            )
+            .capture_stdout()
+            .capture_stderr();
+
+        let mut process = runner.run();
+        let stdout = read_stdout(&mut process);
+        let stderr = read_stderr(&mut process);
+        process.wait().unwrap().expect_code(1);
+        (stdout, stderr)
+    };
+
+    let (stdout, _) = audit(&original, "");
+    assert!(stdout.contains("RUSTSEC-2001-2101"));
+
+    // By default, a checkout from another URL is an error...
+    let (_, stderr) = audit(&mirror, "");
+    assert!(stderr.contains("couldn't fetch advisory database"));
+
+    // ...but it can be pointed at the new URL instead
+    let (stdout, _) = audit(
+        &mirror,
+        "[database]\nfetch = true\nstale = false\nurl_mismatch = \"update-remote\"\n",
+    );
+    assert!(stdout.contains("RUSTSEC-2001-2102"));
+}
+
+#[test]
+fn workspace_root_discovers_lockfiles() {
+    let root: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "support",
+        "workspace_tree",
+    ]
+    .iter()
+    .collect();
+
+    let mut runner = RUNNER.clone();
+    runner.arg("--workspace-root").arg(&root);
+    let mut process = runner.run();
+
+    let stderr = read_stderr(&mut process);
+    let scanned: Vec<_> = stderr
+        .lines()
+        .filter(|line| line.contains("Scanning"))
+        .collect();
+
+    process.wait().unwrap().expect_code(1);
+
+    let found = |dir: &str| {
+        let lockfile = root.join(dir).join("Cargo.lock").display().to_string();
+        scanned.iter().any(|line| line.contains(&lockfile))
+    };
+    assert_eq!(
+        scanned.len(),
+        2,
+        "unexpected lockfiles scanned: {:?}",
+        scanned
+    );
+    assert!(found("vulnerable"));
+    assert!(found("nested/secure"));
+}
+
+#[test]
+fn exclude_path_drops_findings() {
+    let root: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "support",
+        "workspace_tree",
+    ]
+    .iter()
+    .collect();
+
+    let mut runner = RUNNER.clone();
+    runner
+        .arg("--workspace-root")
+        .arg(&root)
+        .arg("--exclude-path")
+        .arg("*/workspace_tree/vulnerable");
+    let mut process = runner.run();
+
+    let stderr = read_stderr(&mut process);
+    let scanned = stderr
+        .lines()
+        .filter(|line| line.contains("Scanning"))
+        .count();
+    let excluded = stderr.lines().find(|line| line.contains("--exclude-path"));
+
+    // Both lockfiles are still scanned, but the only vulnerable one is excluded
+    process.wait().unwrap().expect_success();
+    assert_eq!(scanned, 2);
+    let excluded = excluded.expect("no exclusion summary");
+    assert!(excluded.contains("excluded "));
+    assert!(excluded.contains(&root.join("vulnerable").display().to_string()));
+}
+
+#[test]
+fn ignore_crate_suppresses_advisories() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--ignore-crate").arg("base64");
+    let mut process = runner.run();
+
+    let stderr = read_stderr(&mut process);
+    let notice = stderr.lines().find(|line| line.contains("--ignore-crate"));
+
+    process.wait().unwrap().expect_success();
+    assert!(
+        notice.expect("no suppression notice").contains("RUSTSEC-"),
+        "suppressed advisory ids not listed"
+    );
+}
+
+#[test]
+fn direct_only_skips_transitive_vulnerabilities() {
+    new_cmd_runner("transitive_vuln").status().expect_code(1);
+
+    let mut runner = new_cmd_runner("transitive_vuln");
+    runner.arg("--direct-only");
+    runner.status().expect_code(0);
+
+    let mut runner = new_cmd_runner("transitive_vuln");
+    runner
+        .arg("--direct-only")
+        .arg("--transitive-as-warnings")
+        .arg("--json");
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_code(0);
+
+    assert_eq!(
+        json.pointer("/warnings/transitive/0/advisory/id")
+            .and_then(|id| id.as_str()),
+        Some("RUSTSEC-2017-0004")
+    );
+}
+
+#[test]
+fn plain_format_is_undecorated_and_stable() {
+    let run = || {
+        let mut runner = vulnerable_cmd_runner();
+        runner.arg("--format").arg("plain");
+        let mut process = runner.run();
+
+        let output = read_stdout(&mut process);
+
+        process.wait().unwrap().expect_code(1);
+        output
+    };
+
+    let first = run();
+    assert!(!first.contains('\x1b'), "escape sequence in plain output");
+    assert!(
+        !first.contains(['│', '├', '└', '─']),
+        "box-drawing character in plain output"
+    );
+    assert!(first.lines().any(|line| line.starts_with(
+        "vulnerability\tRUSTSEC-2017-0004\tbase64\t0.5.1\t\
+         registry+https://github.com/rust-lang/crates.io-index\t"
+    )));
+    assert_eq!(first, run());
+}
+
+#[test]
+fn markdown_format_table() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--format").arg("markdown");
+    let mut process = runner.run();
+
+    let output = read_stdout(&mut process);
+
+    process.wait().unwrap().expect_code(1);
+    assert!(output.contains("| Crate | Version | Advisory | Severity | Title |"));
+    assert!(
+        output.contains("[RUSTSEC-2017-0004](https://rustsec.org/advisories/RUSTSEC-2017-0004)")
+    );
+}
+
+#[test]
+fn csv_format_rows() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--format").arg("csv");
+    let mut process = runner.run();
+
+    let lines: Vec<_> = read_stdout(&mut process)
+        .lines()
+        .map(|line| line.trim_end().to_owned())
+        .collect();
+
+    process.wait().unwrap().expect_code(1);
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        lines[0],
+        "crate,version,advisory_id,severity,title,patched_versions,dependency_path"
+    );
+    assert!(lines[1].starts_with("base64,0.5.1,RUSTSEC-2017-0004,"));
+    assert!(lines[1].ends_with(",>=0.5.2,base64_vuln 0.1.0 > base64 0.5.1"));
+}
+
+#[test]
+fn rustsec_ignore_file_suppresses_advisories() {
+    let dir = TempDir::new().unwrap();
+    let lockfile = dir.path().join("Cargo.lock");
+    std::fs::copy("tests/support/base64_vuln/Cargo.lock", &lockfile).unwrap();
+    std::fs::write(
+        dir.path().join(".rustsec-ignore"),
+        "# base64 isn't used for encoding untrusted data\n\nRUSTSEC-2017-0004 # see above\n",
+    )
+    .unwrap();
+
+    let mut runner = RUNNER.clone();
+    runner.arg("--file").arg(&lockfile);
+    runner.status().expect_success();
+}
+
+#[test]
+fn missing_checksums_fail_with_require_checksums() {
+    new_cmd_runner("missing_checksum").status().expect_success();
+
+    let mut runner = new_cmd_runner("missing_checksum");
+    runner.arg("--require-checksums").arg("--json");
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_code(1);
+
+    let missing = json["warnings"]["missing-checksum"].as_array().unwrap();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0]["package"]["name"], "cfg-if");
+}
+
+#[test]
+fn show_raw_prints_advisory_source() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--show-raw");
+    let mut process = runner.run();
+
+    let output = read_stdout(&mut process);
+
+    process.wait().unwrap().expect_code(1);
+    assert!(output.contains("RUSTSEC-2017-0004 (base64 0.5.1):\n```toml\n"));
+    assert!(output.contains("id = \"RUSTSEC-2017-0004\""));
+}
+
+#[test]
+fn show_raw_is_ignored_for_tree_format() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--show-raw").arg("--format").arg("tree");
+    let mut process = runner.run();
+
+    let output = read_stdout(&mut process);
+
+    process.wait().unwrap().expect_code(1);
+    assert!(output.contains("RUSTSEC-2017-0004"));
+    assert!(!output.contains("```toml"));
+}
+
+#[test]
+fn oneline_format() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--format").arg("oneline");
+    let mut process = runner.run();
+
+    let lines: Vec<_> = read_stdout(&mut process)
+        .lines()
+        .map(|line| line.trim_end().to_owned())
+        .collect();
+
+    process.wait().unwrap().expect_code(1);
+    assert_eq!(lines.len(), 1);
+
+    // `ID crate@version source severity title`
+    let fields: Vec<_> = lines[0].splitn(5, ' ').collect();
+    assert_eq!(fields.len(), 5);
+    assert_eq!(fields[0], "RUSTSEC-2017-0004");
+    assert_eq!(fields[1], "base64@0.5.1");
+    assert_eq!(
+        fields[2],
+        "registry+https://github.com/rust-lang/crates.io-index"
+    );
+    assert!(["-", "none", "low", "medium", "high", "critical"].contains(&fields[3]));
+    assert!(!fields[4].is_empty());
+}
+
+#[test]
+fn dot_format_highlights_vulnerable_crates() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--format").arg("dot");
+    let mut process = runner.run();
+
+    let output = read_stdout(&mut process);
+
+    process.wait().unwrap().expect_code(1);
+    assert!(output.starts_with("digraph dependencies {"));
+    assert!(output
+        .contains("\"base64 0.5.1\" [color=red, style=filled, fillcolor=red, fontcolor=white];"));
+    assert!(output.contains("\"base64_vuln 0.1.0\" [color=red];"));
+    assert!(output.contains("\"base64_vuln 0.1.0\" -> \"base64 0.5.1\" [color=red];"));
+}
+
+#[test]
+fn tree_format_annotates_vulnerable_crates() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--format").arg("tree");
+    let mut process = runner.run();
+
+    let output = read_stdout(&mut process);
+
+    process.wait().unwrap().expect_code(1);
+    assert!(output.starts_with("base64_vuln 0.1.0\n"));
+    assert!(output.contains("└── base64 0.5.1 [RUSTSEC-2017-0004"));
+    assert!(output.contains("\n    └── byteorder 1.3.1\n"));
+}
+
+#[test]
+fn json_report_rendered_as_markdown() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--format").arg("json");
+    let mut process = runner.run();
+
+    let json = read_stdout(&mut process);
+    process.wait().unwrap().expect_code(1);
+
+    let dir = TempDir::new().unwrap();
+    let report_file = dir.path().join("report.json");
+    std::fs::write(&report_file, json).unwrap();
+
+    let mut runner = RUNNER.clone();
+    runner
+        .arg("--from-report")
+        .arg(&report_file)
+        .arg("--format")
+        .arg("markdown");
+    let mut process = runner.run();
+
+    let output = read_stdout(&mut process);
+    process.wait().unwrap().expect_code(1);
+
+    assert!(output.contains("| Crate | Version | Advisory | Severity | Title |"));
+    assert!(output.contains(
+        "| base64 | 0.5.1 | [RUSTSEC-2017-0004](https://rustsec.org/advisories/RUSTSEC-2017-0004) |"
+    ));
+}
+
+/// Write a minimal advisory `id` for `base64` versions before 1.2.0 into
+/// the advisory DB at `db`
+fn write_base64_advisory(db: &Path, id: &str) {
+    let package_dir = db.join("crates/base64");
+    std::fs::create_dir_all(&package_dir).unwrap();
+    std::fs::write(
+        package_dir.join(format!("{}.md", id)),
+        format!(
+            "```toml\n[advisory]\nid = \"{}\"\npackage = \"base64\"\ndate = \"2001-02-03\"\n\n\
+             [versions]\npatched = [\">= 1.2.0\"]\n```\n\n# Example advisory\n",
+            id
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn json_schema_v1_report_rendered_offline() {
+    let db = TempDir::new().unwrap();
+    write_base64_advisory(db.path(), "RUSTSEC-2001-2101");
+    let lockfile: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "support",
+        "base64_vuln",
+        "Cargo.lock",
+    ]
+    .iter()
+    .collect();
+
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--db")
+        .arg(db.path())
+        .arg("--no-fetch")
+        .arg("--json")
+        .arg("--json-schema-version")
+        .arg("1")
+        .arg("--file")
+        .arg(&lockfile)
+        .capture_stdout()
+        .capture_stderr();
+    let mut process = runner.run();
+    let json = read_stdout(&mut process);
+    process.wait().unwrap().expect_code(1);
+    assert!(!json.contains("schema-version"));
+
+    let dir = TempDir::new().unwrap();
+    let report_file = dir.path().join("report.json");
+    std::fs::write(&report_file, json).unwrap();
+
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--from-report")
+        .arg(&report_file)
+        .arg("--format")
+        .arg("plain")
+        .capture_stdout()
+        .capture_stderr();
+    let mut process = runner.run();
+    let output = read_stdout(&mut process);
+    process.wait().unwrap().expect_code(1);
+    assert!(output.contains("RUSTSEC-2001-2101"));
+
+    // Reports from a newer schema than this version supports are rejected
+    std::fs::write(&report_file, r#"{"schema-version": 99}"#).unwrap();
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--from-report")
+        .arg(&report_file)
+        .capture_stdout()
+        .capture_stderr();
+    runner.status().expect_code(2);
+
+    // Options for a scan can't be combined with a saved report
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--from-report")
+        .arg(&report_file)
+        .arg("--ignore")
+        .arg("RUSTSEC-2001-2101")
+        .capture_stdout()
+        .capture_stderr();
+    runner.status().expect_code(2);
+}
+
+#[test]
+fn unreadable_reachability_hints_are_a_config_error() {
+    let db = TempDir::new().unwrap();
+    write_base64_advisory(db.path(), "RUSTSEC-2001-2101");
+    let lockfile: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "support",
+        "base64_vuln",
+        "Cargo.lock",
+    ]
+    .iter()
+    .collect();
+
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--db")
+        .arg(db.path())
+        .arg("--no-fetch")
+        .arg("--reachability")
+        .arg(db.path().join("missing.toml"))
+        .arg("--file")
+        .arg(&lockfile)
+        .capture_stdout()
+        .capture_stderr();
+    runner.status().expect_code(2);
+}
+
+#[test]
+fn new_advisories_records_database_commit() {
+    let state = TempDir::new().unwrap();
+    let state_file = state.path().join("rustsec-commit");
+
+    let mut runner = RUNNER.clone();
+    runner.arg("--new-advisories").arg(&state_file);
+    runner.run().wait().unwrap().expect_code(0);
+
+    let recorded = std::fs::read_to_string(&state_file).unwrap();
+    assert_eq!(recorded.trim().len(), 40);
+
+    // Nothing is new relative to the commit just recorded
+    let mut runner = RUNNER.clone();
+    runner.arg("--new-advisories").arg(&state_file);
+    let mut process = runner.run();
+
+    let output = read_stdout(&mut process);
+
+    process.wait().unwrap().expect_code(0);
+    assert!(output.is_empty());
+    assert_eq!(std::fs::read_to_string(&state_file).unwrap(), recorded);
+}
+
 #[test]
 fn version() {
     let mut runner = RUNNER.clone();
@@ -211,6 +1173,31 @@ fn version() {
     process.wait().unwrap().expect_success();
 }
 
+#[test]
+fn advisories_found_report_only_json() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--json").arg("--report-only");
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_success();
+
+    assert_eq!(
+        json.pointer("/vulnerabilities/count")
+            .unwrap()
+            .as_u64()
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        json.pointer("/vulnerabilities/list/0/advisory/id")
+            .unwrap()
+            .as_str()
+            .unwrap(),
+        "RUSTSEC-2017-0004"
+    );
+}
+
 #[test]
 fn advisories_found_but_ignored_json() {
     let mut runner = vulnerable_cmd_runner();
@@ -230,6 +1217,107 @@ fn advisories_found_but_ignored_json() {
     );
 }
 
+#[test]
+fn applied_ignores_json() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--json");
+    runner.arg("--ignore").arg("RUSTSEC-2017-0004");
+    runner.arg("--ignore").arg("RUSTSEC-2001-2101");
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_success();
+
+    // The ignore for an advisory which doesn't affect the project isn't listed
+    assert_eq!(
+        json.pointer("/applied_ignores").unwrap(),
+        &serde_json::json!(["RUSTSEC-2017-0004"])
+    );
+}
+
+#[test]
+fn stats_json() {
+    let mut runner = vulnerable_cmd_runner();
+    runner.arg("--json").arg("--stats");
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_code(1);
+
+    let stats = json.pointer("/stats").expect("stats block");
+    let candidates = stats["candidate-advisories"].as_u64().unwrap();
+    let advisory_count = json.pointer("/database/advisory-count").unwrap();
+    let dependency_count = json.pointer("/lockfile/dependency-count").unwrap();
+
+    // Each advisory is a candidate at most once for each dependency
+    assert!(candidates >= 1);
+    assert!(candidates <= advisory_count.as_u64().unwrap() * dependency_count.as_u64().unwrap());
+
+    let packages = stats["packages"].as_object().unwrap();
+    let total: u64 = packages
+        .values()
+        .map(|counts| counts["candidates"].as_u64().unwrap())
+        .sum();
+    assert_eq!(total, candidates);
+    assert!(stats["packages"]["base64"]["matched"].as_u64().unwrap() >= 1);
+}
+
+#[test]
+fn config_discovered_upward_from_lockfile() {
+    // `member/Cargo.lock` is vulnerable, but `.cargo/audit.toml` in the
+    // parent directory ignores the advisory
+    let mut runner = new_cmd_runner("config_discovery/member");
+    runner.arg("--json");
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_success();
+
+    assert_eq!(
+        json.pointer("/vulnerabilities/count")
+            .unwrap()
+            .as_u64()
+            .unwrap(),
+        0
+    );
+}
+
+#[test]
+fn explicit_audit_config_is_loaded() {
+    let config_dir: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "support",
+        "audit_config",
+    ]
+    .iter()
+    .collect();
+
+    let mut runner = vulnerable_cmd_runner();
+    runner
+        .arg("--audit-config")
+        .arg(config_dir.join("lenient.toml"))
+        .arg("--json");
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_success();
+
+    assert_eq!(
+        json.pointer("/vulnerabilities/count")
+            .unwrap()
+            .as_u64()
+            .unwrap(),
+        0
+    );
+
+    for config in ["missing.toml", "malformed.toml"] {
+        let mut runner = vulnerable_cmd_runner();
+        runner.arg("--audit-config").arg(config_dir.join(config));
+        runner.status().expect_code(2);
+    }
+}
+
 #[test]
 fn unmaintained_advisories_found_json() {
     let mut runner = unmaintained_cmd_runner();
@@ -256,6 +1344,84 @@ fn unmaintained_advisories_found_json() {
     assert_eq!(advisory_id, "RUSTSEC-2022-0061");
 }
 
+#[test]
+fn unmaintained_warnings_include_latest_release_age() {
+    let tmp = TempDir::new().unwrap();
+
+    let package_dir = tmp.path().join("db/crates/parity-wasm");
+    std::fs::create_dir_all(&package_dir).unwrap();
+    std::fs::write(
+        package_dir.join("RUSTSEC-2001-2102.md"),
+        "```toml\n[advisory]\nid = \"RUSTSEC-2001-2102\"\npackage = \"parity-wasm\"\n\
+         date = \"2001-02-03\"\ninformational = \"unmaintained\"\n\n\
+         [versions]\npatched = []\n```\n\n# Example advisory\n",
+    )
+    .unwrap();
+
+    // Index lookups are answered from the cache, so neither the crates.io
+    // index nor its API is queried
+    let cache_dir = tmp.path().join("index-cache");
+    std::fs::create_dir_all(&cache_dir).unwrap();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    std::fs::write(
+        cache_dir.join("parity-wasm.toml"),
+        format!(
+            "fetched_at = {}\n\n[versions.\"0.45.0\"]\nyanked = false\npublished = \"2022-10-01\"\n",
+            now
+        ),
+    )
+    .unwrap();
+
+    let config = tmp.path().join("audit.toml");
+    std::fs::write(
+        &config,
+        format!(
+            "[yanked]\nupdate_index = false\ncache_dir = {:?}\n",
+            cache_dir.display().to_string()
+        ),
+    )
+    .unwrap();
+
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--audit-config")
+        .arg(&config)
+        .arg("--db")
+        .arg(tmp.path().join("db"))
+        .arg("--no-fetch")
+        .arg("--json")
+        .arg("--file")
+        .arg(
+            [
+                env!("CARGO_MANIFEST_DIR"),
+                "tests",
+                "support",
+                "unmaintained",
+                "Cargo.lock",
+            ]
+            .iter()
+            .collect::<PathBuf>(),
+        )
+        .capture_stdout()
+        .capture_stderr();
+
+    let mut process = runner.run();
+    let json = get_advisories_json(&mut process);
+    process.wait().unwrap().expect_code(0);
+
+    let latest = json
+        .pointer("/warnings/unmaintained/0/latest_release")
+        .expect("latest release of the unmaintained crate");
+
+    assert_eq!(latest["version"], "0.45.0");
+    assert_eq!(latest["date"], "2022-10-01");
+    assert!(latest["age_days"].as_u64().unwrap() > 365);
+}
+
 #[test]
 fn unsound_advisories_found_json() {
     let mut runner = unsound_cmd_runner();
@@ -313,3 +1479,79 @@ fn branch_regression() {
     // Verifies that we don't crash on this Cargo.lock
     new_cmd_runner("branch-regression").status().expect_code(1);
 }
+
+#[test]
+fn explain_prints_advisory_details() {
+    let mut runner = RUNNER.clone();
+    runner.arg("--explain").arg("RUSTSEC-2017-0004");
+    let mut process = runner.run();
+
+    let output = read_stdout(&mut process);
+
+    process.wait().unwrap().expect_code(0);
+    assert!(output.starts_with("RUSTSEC-2017-0004: "));
+    assert!(output.contains("Crate:      base64"));
+    assert!(output.contains("Patched:    >=0.5.2"));
+    assert!(output.contains("`encode_config_buf`"));
+}
+
+#[test]
+fn explain_unknown_advisory() {
+    let mut runner = RUNNER.clone();
+    runner.arg("--explain").arg("RUSTSEC-2000-9999");
+    runner.status().expect_code(2);
+}
+
+#[test]
+fn crate_subcommand_finds_advisory() {
+    let mut runner = RUNNER.clone();
+    runner.arg("crate").arg("base64").arg("0.5.1");
+    let mut process = runner.run();
+
+    let output = read_stdout(&mut process);
+
+    process.wait().unwrap().expect_code(1);
+    assert!(output.contains("RUSTSEC-2017-0004\tbase64\t0.5.1\t"));
+}
+
+#[test]
+fn crate_subcommand_without_advisories() {
+    let mut runner = RUNNER.clone();
+    runner.arg("crate").arg("base64").arg("0.5.2");
+    let mut process = runner.run();
+
+    let output = read_stdout(&mut process);
+
+    process.wait().unwrap().expect_code(0);
+    assert!(output.is_empty());
+}
+
+#[test]
+fn crate_subcommand_with_empty_database_fails() {
+    let empty_db = TempDir::new().unwrap();
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--db")
+        .arg(empty_db.path())
+        .arg("--no-fetch")
+        .arg("crate")
+        .arg("base64")
+        .arg("0.5.2")
+        .capture_stdout()
+        .capture_stderr();
+
+    runner.status().expect_code(2);
+}
+
+#[cfg(feature = "binary-scanning")]
+#[test]
+fn self_scan_succeeds() {
+    let mut runner = RUNNER.clone();
+    runner.arg("--self");
+    let mut process = runner.run();
+
+    read_stdout(&mut process);
+
+    process.wait().unwrap().expect_code(0);
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "binary-scanning")]
 
-use std::path::PathBuf;
+use std::{io::Read, path::PathBuf};
 
 use abscissa_core::testing::prelude::*;
 use once_cell::sync::Lazy;
@@ -66,3 +66,116 @@ fn auditable_binary_with_vulnerabilities_fails() {
     binary_path.push("binary-with-vuln");
     assert_eq!(cmd_runner().arg(binary_path).status().code(), 1);
 }
+
+#[test]
+fn directory_of_binaries_is_scanned() {
+    // Includes `binary-with-vuln`, next to sources and scripts which are skipped
+    assert_eq!(cmd_runner().arg(binaries_dir()).status().code(), 1);
+}
+
+#[test]
+fn binaries_without_audit_info_fail_when_required() {
+    let dir = TempDir::new().unwrap();
+    let release = dir.path().join("release");
+    std::fs::create_dir_all(&release).unwrap();
+    for binary in ["binary-with-audit-info", "binary-without-audit-info"] {
+        std::fs::copy(binaries_dir().join(binary), release.join(binary)).unwrap();
+    }
+    std::fs::write(release.join("README.md"), "release notes\n").unwrap();
+
+    assert_eq!(cmd_runner().arg(dir.path()).status().code(), 0);
+    assert_eq!(
+        cmd_runner()
+            .arg("--require-audit-info")
+            .arg(dir.path())
+            .status()
+            .code(),
+        2
+    );
+}
+
+/// Create an advisory database with a single advisory for the crate in
+/// `binary-with-vuln`, with any `extra` TOML sections
+fn example_crate_db(extra: &str) -> TempDir {
+    let db = TempDir::new().unwrap();
+    let package_dir = db.path().join("crates/rustsec-example-crate");
+    std::fs::create_dir_all(&package_dir).unwrap();
+    std::fs::write(
+        package_dir.join("RUSTSEC-2001-2101.md"),
+        format!(
+            "```toml\n[advisory]\nid = \"RUSTSEC-2001-2101\"\npackage = \"rustsec-example-crate\"\n\
+             date = \"2001-02-03\"\n\n{}[versions]\npatched = [\">= 0.0.2\"]\n```\n\n\
+             # Example advisory\n",
+            extra
+        ),
+    )
+    .unwrap();
+    db
+}
+
+/// Run `cargo audit` against the given database, without fetching it
+fn offline_runner(db: &TempDir) -> CmdRunner {
+    let mut runner = CmdRunner::default();
+    runner
+        .arg("audit")
+        .arg("--db")
+        .arg(db.path())
+        .arg("--no-fetch");
+    runner
+}
+
+#[test]
+fn json_reports_are_keyed_by_binary_path() {
+    let db = example_crate_db("");
+
+    let dir = TempDir::new().unwrap();
+    for binary in ["binary-with-audit-info", "binary-with-vuln"] {
+        std::fs::copy(binaries_dir().join(binary), dir.path().join(binary)).unwrap();
+    }
+
+    let mut runner = offline_runner(&db);
+    runner
+        .arg("--json")
+        .arg("bin")
+        .arg(dir.path())
+        .capture_stdout()
+        .capture_stderr();
+    let mut process = runner.run();
+    let mut output = String::new();
+    process.stdout().read_to_string(&mut output).unwrap();
+    process.wait().unwrap().expect_code(1);
+
+    let reports: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&output).unwrap();
+    assert_eq!(reports.len(), 2);
+
+    let vulnerable = &reports[&dir.path().join("binary-with-vuln").display().to_string()];
+    assert_eq!(vulnerable["vulnerabilities"]["count"], 1);
+    let clean = &reports[&dir
+        .path()
+        .join("binary-with-audit-info")
+        .display()
+        .to_string()];
+    assert_eq!(clean["vulnerabilities"]["count"], 0);
+}
+
+#[test]
+fn binaries_are_audited_for_all_targets() {
+    // The binary may have been built for any target, so no advisory is
+    // skipped for not affecting the host. The fixture is an ELF binary, so
+    // the advisory is for another OS which uses ELF.
+    let foreign_os = if cfg!(target_os = "freebsd") {
+        "netbsd"
+    } else {
+        "freebsd"
+    };
+    let db = example_crate_db(&format!("[affected]\nos = [\"{}\"]\n\n", foreign_os));
+
+    let mut runner = offline_runner(&db);
+    runner
+        .arg("bin")
+        .arg(binaries_dir().join("binary-with-vuln"))
+        .capture_stdout()
+        .capture_stderr();
+    runner.status().expect_code(1);
+}
@@ -2,8 +2,12 @@
 
 use std::{fs, path::Path};
 
-use cargo_audit::config::AuditConfig;
-use rustsec::platforms::{Arch, OS};
+use cargo_audit::config::{AuditConfig, TargetConfig};
+use rustsec::{
+    platforms::{Arch, OS},
+    Database, Lockfile, Report,
+};
+use tempfile::TempDir;
 
 /// Ensure `audit.toml.example` parses as a valid config file
 #[test]
@@ -33,3 +37,58 @@ fn parser_audit_toml_example() {
     assert_eq!(config.target.arch(), vec![Arch::X86_64]);
     assert_eq!(config.target.os(), vec![OS::Linux]);
 }
+
+/// Without any targets configured, only the host's advisories are reported
+#[test]
+fn host_target_is_default() {
+    let host_os: OS = std::env::consts::OS.parse().unwrap();
+    let foreign_os = if host_os == OS::Windows {
+        OS::Linux
+    } else {
+        OS::Windows
+    };
+
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join("crates/base64")).unwrap();
+    fs::write(
+        dir.path().join("crates/base64/RUSTSEC-2017-0004.md"),
+        format!(
+            r#"```toml
+[advisory]
+id = "RUSTSEC-2017-0004"
+package = "base64"
+date = "2017-05-03"
+
+[affected]
+os = ["{}"]
+
+[versions]
+patched = [">= 0.5.2"]
+```
+
+# Integer overflow leads to heap-based buffer overflow in encode_config_buf
+"#,
+            foreign_os
+        ),
+    )
+    .unwrap();
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("tests/support/base64_vuln/Cargo.lock").unwrap();
+
+    let config = AuditConfig::default();
+    assert_eq!(config.target.os(), vec![host_os]);
+    let report = Report::generate(&db, &lockfile, &config.report_settings());
+    assert!(!report.vulnerabilities.found);
+
+    let config = AuditConfig {
+        target: TargetConfig {
+            all_targets: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert!(config.target.os().is_empty());
+    let report = Report::generate(&db, &lockfile, &config.report_settings());
+    assert_eq!(report.vulnerabilities.count, 1);
+}
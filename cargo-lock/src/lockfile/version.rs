@@ -97,6 +97,13 @@ impl TryFrom<u32> for ResolveVersion {
             2 => Ok(ResolveVersion::V2),
             3 => Ok(ResolveVersion::V3),
             4 => Ok(ResolveVersion::V4),
+            // Presumably written by a newer Cargo than this crate knows about
+            n if n > ResolveVersion::V4 as u32 => Err(Error::Parse(format!(
+                "unsupported Cargo.lock format version: `{}` (the newest supported is `{}`; \
+                 please upgrade to a release which supports it)",
+                n,
+                ResolveVersion::V4 as u32
+            ))),
             _ => Err(Error::Parse(format!(
                 "invalid Cargo.lock format version: `{}`",
                 num
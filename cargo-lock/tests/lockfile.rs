@@ -107,6 +107,20 @@ fn load_example_v4_lockfile() {
     );
 }
 
+/// Lockfile versions newer than V4 are rejected with a hint to upgrade
+#[test]
+fn unsupported_future_version() {
+    let lockfile = std::fs::read_to_string(V4_LOCKFILE_PATH)
+        .unwrap()
+        .replace("version = 4", "version = 5");
+
+    let err = lockfile.parse::<Lockfile>().unwrap_err().to_string();
+    assert!(err.contains("unsupported Cargo.lock format version: `5`"));
+    assert!(err.contains("please upgrade"));
+
+    assert!(ResolveVersion::try_from(0).is_err());
+}
+
 /// Ensure V4 lockfiles encode their version correctly.
 #[test]
 fn serialize_v4() {
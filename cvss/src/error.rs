@@ -1,5 +1,7 @@
 //! Error types
 
+#[cfg(feature = "v4")]
+use crate::v4;
 use crate::MetricType;
 use alloc::string::String;
 use core::fmt;
@@ -26,6 +28,37 @@ pub enum Error {
         value: String,
     },
 
+    #[cfg(feature = "v4")]
+    /// Invalid metric for CVSSv4.
+    InvalidMetricV4 {
+        /// The metric that was invalid.
+        metric_type: v4::MetricType,
+
+        /// The value that was provided which is invalid.
+        value: String,
+    },
+
+    #[cfg(feature = "v4")]
+    /// Missing metric for CVSSv4.
+    MissingMandatoryMetricV4 {
+        /// Metric which is missing.
+        metric_type: v4::MetricType,
+    },
+
+    #[cfg(feature = "v4")]
+    /// Metric is duplicated for CVSSv4.
+    DuplicateMetricV4 {
+        /// Metric which is duplicated.
+        metric_type: v4::MetricType,
+    },
+
+    #[cfg(feature = "v4")]
+    /// Invalid nomenclature for CVSSv4.
+    InvalidNomenclatureV4 {
+        /// Unknown CVSSv4 nomenclature.
+        nomenclature: String,
+    },
+
     /// Invalid CVSS string prefix.
     InvalidPrefix {
         /// Prefix which is invalid.
@@ -66,6 +99,38 @@ impl fmt::Display for Error {
                     value
                 )
             }
+            #[cfg(feature = "v4")]
+            Error::InvalidMetricV4 { metric_type, value } => {
+                write!(
+                    f,
+                    "invalid CVSSv4 {} ({}) metric: `{}`",
+                    metric_type.name(),
+                    metric_type.description(),
+                    value
+                )
+            }
+            #[cfg(feature = "v4")]
+            Error::DuplicateMetricV4 { metric_type } => {
+                write!(
+                    f,
+                    "duplicate CVSSv4 {} ({}) metric",
+                    metric_type.name(),
+                    metric_type.description(),
+                )
+            }
+            #[cfg(feature = "v4")]
+            Error::MissingMandatoryMetricV4 { metric_type } => {
+                write!(
+                    f,
+                    "missing mandatory CVSSv4 {} ({}) metric",
+                    metric_type.name(),
+                    metric_type.description(),
+                )
+            }
+            #[cfg(feature = "v4")]
+            Error::InvalidNomenclatureV4 { nomenclature } => {
+                write!(f, "invalid CVSSv4 nomenclature: `{}`", nomenclature)
+            }
             Error::InvalidPrefix { prefix } => {
                 write!(f, "invalid CVSS string prefix: `{}`", prefix)
             }
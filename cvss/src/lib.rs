@@ -12,11 +12,18 @@
 //! `CVSS:3.0` and `CVSS:3.1` Base Metric Group vector strings as described in
 //! the [CVSS v3.1 Specification].
 //!
+//! The [`v3::Vector`] type additionally parses and scores any Temporal and
+//! Environmental Metric Group metrics which follow the Base metrics.
+//!
+//! The [`v4::Vector`] type parses, serializes and scores `CVSS:4.0` vector
+//! strings as described in the [CVSS v4.0 Specification].
+//!
 //! Serde support is available through the optional `serde` Cargo feature.
 //!
 //! [CVSS v3.1 Specification]: https://www.first.org/cvss/specification-document
+//! [CVSS v4.0 Specification]: https://www.first.org/cvss/v4.0/specification-document
 
-// TODO(tarcieri): other CVSS versions, CVSS v3.1 Temporal and Environmental Groups
+// TODO(tarcieri): CVSS v2.0
 
 extern crate alloc;
 
@@ -25,6 +32,8 @@ extern crate std;
 
 #[cfg(feature = "v3")]
 pub mod v3;
+#[cfg(feature = "v4")]
+pub mod v4;
 
 mod error;
 mod metric;
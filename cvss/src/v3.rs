@@ -2,10 +2,73 @@
 //!
 //! <https://www.first.org/cvss/specification-document>
 
-// TODO(tarcieri): Environmental and Temporal Metrics
-
 pub mod base;
+pub mod environmental;
+pub mod temporal;
 
 mod score;
+mod vector;
+
+pub use self::{
+    base::Base, environmental::Environmental, score::Score, temporal::Temporal, vector::Vector,
+};
+
+use crate::{Error, MetricType, Result, PREFIX};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+/// Split a CVSS v3 vector string into its minor version and its metrics,
+/// with the metric values normalized to uppercase.
+fn parse_components(s: &str) -> Result<(usize, Vec<(MetricType, String)>)> {
+    let component_vec = s
+        .split('/')
+        .map(|component| {
+            let mut parts = component.split(':');
+
+            let id = parts.next().ok_or_else(|| Error::InvalidComponent {
+                component: component.to_owned(),
+            })?;
+
+            let value = parts.next().ok_or_else(|| Error::InvalidComponent {
+                component: component.to_owned(),
+            })?;
+
+            if parts.next().is_some() {
+                return Err(Error::InvalidComponent {
+                    component: component.to_owned(),
+                });
+            }
+
+            Ok((id, value))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut components = component_vec.iter();
+    let &(id, version_string) = components.next().ok_or(Error::InvalidPrefix {
+        prefix: s.to_owned(),
+    })?;
+
+    if id != PREFIX {
+        return Err(Error::InvalidPrefix {
+            prefix: id.to_owned(),
+        });
+    }
+
+    let minor_version = match version_string {
+        "3.0" => 0,
+        "3.1" => 1,
+        _ => {
+            return Err(Error::UnsupportedVersion {
+                version: version_string.to_owned(),
+            })
+        }
+    };
+
+    let metrics = components
+        .map(|&(id, value)| {
+            let metric_type = id.to_ascii_uppercase().parse::<MetricType>()?;
+            Ok((metric_type, value.to_ascii_uppercase()))
+        })
+        .collect::<Result<Vec<_>>>()?;
 
-pub use self::{base::Base, score::Score};
+    Ok((minor_version, metrics))
+}
@@ -16,7 +16,7 @@ pub use self::{
 
 use super::Score;
 use crate::{Error, Metric, MetricType, Result, PREFIX};
-use alloc::{borrow::ToOwned, vec::Vec};
+use alloc::borrow::ToOwned;
 use core::{fmt, str::FromStr};
 
 #[cfg(feature = "serde")]
@@ -199,59 +199,20 @@ impl fmt::Display for Base {
 impl FromStr for Base {
     type Err = Error;
 
+    /// Parse a vector containing only Base metrics.
+    ///
+    /// Use [`Vector`](super::Vector) to parse vectors which may also contain
+    /// Temporal or Environmental metrics.
     fn from_str(s: &str) -> Result<Self> {
-        let component_vec = s
-            .split('/')
-            .map(|component| {
-                let mut parts = component.split(':');
-
-                let id = parts.next().ok_or_else(|| Error::InvalidComponent {
-                    component: component.to_owned(),
-                })?;
-
-                let value = parts.next().ok_or_else(|| Error::InvalidComponent {
-                    component: component.to_owned(),
-                })?;
-
-                if parts.next().is_some() {
-                    return Err(Error::InvalidComponent {
-                        component: component.to_owned(),
-                    });
-                }
-
-                Ok((id, value))
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        let mut components = component_vec.iter();
-        let &(id, version_string) = components.next().ok_or(Error::InvalidPrefix {
-            prefix: s.to_owned(),
-        })?;
-
-        if id != PREFIX {
-            return Err(Error::InvalidPrefix {
-                prefix: id.to_owned(),
-            });
-        }
+        let (minor_version, components) = super::parse_components(s)?;
 
         let mut metrics = Self {
-            minor_version: match version_string {
-                "3.0" => 0,
-                "3.1" => 1,
-                _ => {
-                    return Err(Error::UnsupportedVersion {
-                        version: version_string.to_owned(),
-                    })
-                }
-            },
+            minor_version,
             ..Default::default()
         };
 
-        for &component in components {
-            let id = component.0.to_ascii_uppercase();
-            let value = component.1.to_ascii_uppercase();
-
-            match id.parse::<MetricType>()? {
+        for (metric_type, value) in components {
+            match metric_type {
                 MetricType::AV => metrics.av = Some(value.parse()?),
                 MetricType::AC => metrics.ac = Some(value.parse()?),
                 MetricType::PR => metrics.pr = Some(value.parse()?),
@@ -260,6 +221,11 @@ impl FromStr for Base {
                 MetricType::C => metrics.c = Some(value.parse()?),
                 MetricType::I => metrics.i = Some(value.parse()?),
                 MetricType::A => metrics.a = Some(value.parse()?),
+                other => {
+                    return Err(Error::UnknownMetric {
+                        name: other.name().to_owned(),
+                    })
+                }
             }
         }
 
@@ -0,0 +1,70 @@
+//! CVSS v3.1 Environmental Metric Group
+
+mod ar;
+mod cr;
+mod ir;
+
+pub use self::{
+    ar::AvailabilityRequirement, cr::ConfidentialityRequirement, ir::IntegrityRequirement,
+};
+
+use super::base::{
+    AttackComplexity, AttackVector, Availability, Confidentiality, Integrity, PrivilegesRequired,
+    Scope, UserInteraction,
+};
+
+/// CVSS v3.1 Environmental Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 4:
+/// <https://www.first.org/cvss/specification-document#t13>
+///
+/// > These metrics enable the analyst to customize the CVSS score depending
+/// > on the importance of the affected IT asset to a user's organization,
+/// > measured in terms of complementary/alternative security controls in
+/// > place, Confidentiality, Integrity, and Availability.
+///
+/// The Modified Base metrics (e.g. `MAV`) override the corresponding Base
+/// metric. They're `None` when absent or Not Defined (X), in which case the
+/// Base metric is used.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Environmental {
+    /// Confidentiality Requirement (CR)
+    pub cr: Option<ConfidentialityRequirement>,
+
+    /// Integrity Requirement (IR)
+    pub ir: Option<IntegrityRequirement>,
+
+    /// Availability Requirement (AR)
+    pub ar: Option<AvailabilityRequirement>,
+
+    /// Modified Attack Vector (MAV)
+    pub mav: Option<AttackVector>,
+
+    /// Modified Attack Complexity (MAC)
+    pub mac: Option<AttackComplexity>,
+
+    /// Modified Privileges Required (MPR)
+    pub mpr: Option<PrivilegesRequired>,
+
+    /// Modified User Interaction (MUI)
+    pub mui: Option<UserInteraction>,
+
+    /// Modified Scope (MS)
+    pub ms: Option<Scope>,
+
+    /// Modified Confidentiality Impact (MC)
+    pub mc: Option<Confidentiality>,
+
+    /// Modified Integrity Impact (MI)
+    pub mi: Option<Integrity>,
+
+    /// Modified Availability Impact (MA)
+    pub ma: Option<Availability>,
+}
+
+impl Environmental {
+    /// Are all of the Environmental metrics absent?
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
@@ -0,0 +1,50 @@
+//! CVSS v3.1 Temporal Metric Group
+
+mod e;
+mod rc;
+mod rl;
+
+pub use self::{e::ExploitCodeMaturity, rc::ReportConfidence, rl::RemediationLevel};
+
+#[cfg(feature = "std")]
+use crate::Metric;
+
+/// CVSS v3.1 Temporal Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 3:
+/// <https://www.first.org/cvss/specification-document#t9>
+///
+/// > The Temporal metrics measure the current state of exploit techniques or
+/// > code availability, the existence of any patches or workarounds, or the
+/// > confidence in the description of a vulnerability.
+///
+/// Metrics which are absent are treated as Not Defined (X), i.e. they don't
+/// change the score.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Temporal {
+    /// Exploit Code Maturity (E)
+    pub e: Option<ExploitCodeMaturity>,
+
+    /// Remediation Level (RL)
+    pub rl: Option<RemediationLevel>,
+
+    /// Report Confidence (RC)
+    pub rc: Option<ReportConfidence>,
+}
+
+impl Temporal {
+    /// Are all of the Temporal metrics absent?
+    pub fn is_empty(&self) -> bool {
+        self.e.is_none() && self.rl.is_none() && self.rc.is_none()
+    }
+
+    /// Product of the Temporal metric values, which the Base (or modified
+    /// Base) score is multiplied by to get the Temporal (or Environmental) score.
+    #[cfg(feature = "std")]
+    pub(crate) fn multiplier(&self) -> f64 {
+        let e_score = self.e.map(|e| e.score()).unwrap_or(1.0);
+        let rl_score = self.rl.map(|rl| rl.score()).unwrap_or(1.0);
+        let rc_score = self.rc.map(|rc| rc.score()).unwrap_or(1.0);
+        e_score * rl_score * rc_score
+    }
+}
@@ -0,0 +1,94 @@
+//! Report Confidence (RC)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Report Confidence (RC) - CVSS v3.1 Temporal Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 3.3:
+/// <https://www.first.org/cvss/specification-document#t9>
+///
+/// > This metric measures the degree of confidence in the existence of the
+/// > vulnerability and the credibility of the known technical details.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ReportConfidence {
+    /// Not Defined (X)
+    ///
+    /// > Assigning this value indicates there is insufficient information to
+    /// > choose one of the other values, and has no impact on the overall
+    /// > Temporal Score.
+    NotDefined,
+
+    /// Unknown (U)
+    ///
+    /// > There are reports of impacts that indicate a vulnerability is present.
+    /// > The reports indicate that the cause of the vulnerability is unknown.
+    Unknown,
+
+    /// Reasonable (R)
+    ///
+    /// > Significant details are published, but researchers either do not have
+    /// > full confidence in the root cause, or do not have access to source code
+    /// > to fully confirm all of the interactions that may lead to the result.
+    Reasonable,
+
+    /// Confirmed (C)
+    ///
+    /// > Detailed reports exist, or functional reproduction is possible.
+    /// > Source code is available to independently verify the assertions of the
+    /// > research, or the author or vendor of the affected code has confirmed
+    /// > the presence of the vulnerability.
+    Confirmed,
+}
+
+impl Default for ReportConfidence {
+    fn default() -> ReportConfidence {
+        ReportConfidence::NotDefined
+    }
+}
+
+impl Metric for ReportConfidence {
+    const TYPE: MetricType = MetricType::RC;
+
+    fn score(self) -> f64 {
+        match self {
+            ReportConfidence::NotDefined => 1.0,
+            ReportConfidence::Unknown => 0.92,
+            ReportConfidence::Reasonable => 0.96,
+            ReportConfidence::Confirmed => 1.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ReportConfidence::NotDefined => "X",
+            ReportConfidence::Unknown => "U",
+            ReportConfidence::Reasonable => "R",
+            ReportConfidence::Confirmed => "C",
+        }
+    }
+}
+
+impl fmt::Display for ReportConfidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for ReportConfidence {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(ReportConfidence::NotDefined),
+            "U" => Ok(ReportConfidence::Unknown),
+            "R" => Ok(ReportConfidence::Reasonable),
+            "C" => Ok(ReportConfidence::Confirmed),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Remediation Level (RL)
+
+use crate::{Error, Metric, MetricType, Result};
+use alloc::borrow::ToOwned;
+use core::{fmt, str::FromStr};
+
+/// Remediation Level (RL) - CVSS v3.1 Temporal Metric Group
+///
+/// Described in CVSS v3.1 Specification: Section 3.2:
+/// <https://www.first.org/cvss/specification-document#t9>
+///
+/// > The Remediation Level of a vulnerability is an important factor for
+/// > prioritization. The typical vulnerability is unpatched when initially
+/// > published. Workarounds or hotfixes may offer interim remediation until
+/// > an official patch or upgrade is issued.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum RemediationLevel {
+    /// Not Defined (X)
+    ///
+    /// > Assigning this value indicates there is insufficient information to
+    /// > choose one of the other values, and has no impact on the overall
+    /// > Temporal Score.
+    NotDefined,
+
+    /// Official Fix (O)
+    ///
+    /// > A complete vendor solution is available.
+    OfficialFix,
+
+    /// Temporary Fix (T)
+    ///
+    /// > There is an official but temporary fix available.
+    TemporaryFix,
+
+    /// Workaround (W)
+    ///
+    /// > There is an unofficial, non-vendor solution available.
+    Workaround,
+
+    /// Unavailable (U)
+    ///
+    /// > There is either no solution available or it is impossible to apply.
+    Unavailable,
+}
+
+impl Default for RemediationLevel {
+    fn default() -> RemediationLevel {
+        RemediationLevel::NotDefined
+    }
+}
+
+impl Metric for RemediationLevel {
+    const TYPE: MetricType = MetricType::RL;
+
+    fn score(self) -> f64 {
+        match self {
+            RemediationLevel::NotDefined => 1.0,
+            RemediationLevel::OfficialFix => 0.95,
+            RemediationLevel::TemporaryFix => 0.96,
+            RemediationLevel::Workaround => 0.97,
+            RemediationLevel::Unavailable => 1.0,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            RemediationLevel::NotDefined => "X",
+            RemediationLevel::OfficialFix => "O",
+            RemediationLevel::TemporaryFix => "T",
+            RemediationLevel::Workaround => "W",
+            RemediationLevel::Unavailable => "U",
+        }
+    }
+}
+
+impl fmt::Display for RemediationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", Self::name(), self.as_str())
+    }
+}
+
+impl FromStr for RemediationLevel {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(RemediationLevel::NotDefined),
+            "O" => Ok(RemediationLevel::OfficialFix),
+            "T" => Ok(RemediationLevel::TemporaryFix),
+            "W" => Ok(RemediationLevel::Workaround),
+            "U" => Ok(RemediationLevel::Unavailable),
+            _ => Err(Error::InvalidMetric {
+                metric_type: Self::TYPE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Complete CVSS v3.1 vectors
+
+use super::{Base, Environmental, Temporal};
+use crate::{Error, MetricType, Result};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use {
+    alloc::string::{String, ToString},
+    serde::{de, ser, Deserialize, Serialize},
+};
+
+#[cfg(feature = "std")]
+use {
+    super::Score,
+    crate::{Metric, Severity},
+};
+
+/// CVSS v3.1 vector containing the Base metrics along with any Temporal
+/// and Environmental metrics, e.g.
+/// `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C`.
+///
+/// Vectors with only Base metrics are also accepted, in which case the
+/// [`Temporal`] and [`Environmental`] groups are empty.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Vector {
+    /// Base Metric Group
+    pub base: Base,
+
+    /// Temporal Metric Group
+    pub temporal: Temporal,
+
+    /// Environmental Metric Group
+    pub environmental: Environmental,
+}
+
+impl Vector {
+    /// Calculate the Base score, which is what's generally referred to as
+    /// the "CVSS score". See [`Base::score`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn score(&self) -> Score {
+        self.base.score()
+    }
+
+    /// Calculate the Base `Severity`. See [`Base::severity`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn severity(&self) -> Severity {
+        self.base.severity()
+    }
+
+    /// Calculate the Temporal score, or `None` if the vector doesn't have
+    /// any Temporal metrics.
+    ///
+    /// Described in CVSS v3.1 Specification: Section 7.2:
+    /// <https://www.first.org/cvss/specification-document#t22>
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn temporal_score(&self) -> Option<Score> {
+        if self.temporal.is_empty() {
+            return None;
+        }
+
+        Some(Score::new(self.base.score().value() * self.temporal.multiplier()).roundup())
+    }
+
+    /// Calculate the Environmental score, or `None` if the vector doesn't
+    /// have any Environmental metrics.
+    ///
+    /// Modified Base metrics which aren't given fall back to the Base metrics,
+    /// and any Temporal metrics are applied as well.
+    ///
+    /// Described in CVSS v3.1 Specification: Section 7.3:
+    /// <https://www.first.org/cvss/specification-document#t23>
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn environmental_score(&self) -> Option<Score> {
+        if self.environmental.is_empty() {
+            return None;
+        }
+
+        let base = &self.base;
+        let env = &self.environmental;
+
+        let scope_changed = env.ms.or(base.s).map(|s| s.is_changed()).unwrap_or(false);
+
+        let av_score = env.mav.or(base.av).map(|av| av.score()).unwrap_or(0.0);
+        let ac_score = env.mac.or(base.ac).map(|ac| ac.score()).unwrap_or(0.0);
+        let ui_score = env.mui.or(base.ui).map(|ui| ui.score()).unwrap_or(0.0);
+        let pr_score = env
+            .mpr
+            .or(base.pr)
+            .map(|pr| pr.scoped_score(scope_changed))
+            .unwrap_or(0.0);
+        let exploitability = 8.22 * av_score * ac_score * pr_score * ui_score;
+
+        let c_score = env.mc.or(base.c).map(|c| c.score()).unwrap_or(0.0);
+        let i_score = env.mi.or(base.i).map(|i| i.score()).unwrap_or(0.0);
+        let a_score = env.ma.or(base.a).map(|a| a.score()).unwrap_or(0.0);
+        let cr_score = env.cr.map(|cr| cr.score()).unwrap_or(1.0);
+        let ir_score = env.ir.map(|ir| ir.score()).unwrap_or(1.0);
+        let ar_score = env.ar.map(|ar| ar.score()).unwrap_or(1.0);
+
+        let miss = (1.0
+            - ((1.0 - cr_score * c_score)
+                * (1.0 - ir_score * i_score)
+                * (1.0 - ar_score * a_score)))
+            .min(0.915);
+
+        let impact = if !scope_changed {
+            6.42 * miss
+        } else if base.minor_version == 0 {
+            7.52 * (miss - 0.029) - 3.25 * (miss - 0.02).powf(15.0)
+        } else {
+            7.52 * (miss - 0.029) - 3.25 * (miss * 0.9731 - 0.02).powf(13.0)
+        };
+
+        if impact <= 0.0 {
+            return Some(Score::new(0.0));
+        }
+
+        let modified = if !scope_changed {
+            (impact + exploitability).min(10.0)
+        } else {
+            (1.08 * (impact + exploitability)).min(10.0)
+        };
+
+        let modified = Score::new(modified).roundup().value();
+        Some(Score::new(modified * self.temporal.multiplier()).roundup())
+    }
+}
+
+impl From<Base> for Vector {
+    fn from(base: Base) -> Vector {
+        Vector {
+            base,
+            ..Default::default()
+        }
+    }
+}
+
+/// Keep only the Base Metrics, dropping any Temporal and Environmental ones
+impl From<Vector> for Base {
+    fn from(vector: Vector) -> Base {
+        vector.base
+    }
+}
+
+impl AsRef<Base> for Vector {
+    fn as_ref(&self) -> &Base {
+        &self.base
+    }
+}
+
+macro_rules! write_metrics {
+    ($f:expr, $prefix:expr, $($metric:expr),+) => {
+        $(
+            if let Some(metric) = $metric {
+                write!($f, "/{}{}", $prefix, metric)?;
+            }
+        )+
+    };
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let temporal = &self.temporal;
+        let env = &self.environmental;
+
+        write!(f, "{}", self.base)?;
+        write_metrics!(f, "", temporal.e, temporal.rl, temporal.rc);
+        write_metrics!(f, "", env.cr, env.ir, env.ar);
+        write_metrics!(f, "M", env.mav, env.mac, env.mpr, env.mui, env.ms, env.mc, env.mi, env.ma);
+        Ok(())
+    }
+}
+
+impl FromStr for Vector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (minor_version, components) = super::parse_components(s)?;
+
+        let mut vector = Self::default();
+        vector.base.minor_version = minor_version;
+
+        let base = &mut vector.base;
+        let temporal = &mut vector.temporal;
+        let env = &mut vector.environmental;
+
+        for (metric_type, value) in components {
+            // Not Defined (X) Modified Base metrics are the same as absent ones
+            let modified = value != "X";
+
+            match metric_type {
+                MetricType::AV => base.av = Some(value.parse()?),
+                MetricType::AC => base.ac = Some(value.parse()?),
+                MetricType::PR => base.pr = Some(value.parse()?),
+                MetricType::UI => base.ui = Some(value.parse()?),
+                MetricType::S => base.s = Some(value.parse()?),
+                MetricType::C => base.c = Some(value.parse()?),
+                MetricType::I => base.i = Some(value.parse()?),
+                MetricType::A => base.a = Some(value.parse()?),
+                MetricType::E => temporal.e = Some(value.parse()?),
+                MetricType::RL => temporal.rl = Some(value.parse()?),
+                MetricType::RC => temporal.rc = Some(value.parse()?),
+                MetricType::CR => env.cr = Some(value.parse()?),
+                MetricType::IR => env.ir = Some(value.parse()?),
+                MetricType::AR => env.ar = Some(value.parse()?),
+                MetricType::MAV => env.mav = modified.then(|| value.parse()).transpose()?,
+                MetricType::MAC => env.mac = modified.then(|| value.parse()).transpose()?,
+                MetricType::MPR => env.mpr = modified.then(|| value.parse()).transpose()?,
+                MetricType::MUI => env.mui = modified.then(|| value.parse()).transpose()?,
+                MetricType::MS => env.ms = modified.then(|| value.parse()).transpose()?,
+                MetricType::MC => env.mc = modified.then(|| value.parse()).transpose()?,
+                MetricType::MI => env.mi = modified.then(|| value.parse()).transpose()?,
+                MetricType::MA => env.ma = modified.then(|| value.parse()).transpose()?,
+            }
+        }
+
+        Ok(vector)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Vector {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Vector {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
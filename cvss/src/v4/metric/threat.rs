@@ -0,0 +1,7 @@
+//! CVSS v4.0 Threat Metric Group
+
+mod e;
+
+#[cfg(feature = "std")]
+pub(crate) use self::e::merge::MergedExploitMaturity;
+pub use self::e::ExploitMaturity;
@@ -0,0 +1,39 @@
+#![cfg(all(feature = "v4", feature = "std"))]
+/// CVSS v4.0 vector tests
+use core::str::FromStr;
+use cvss::{v4::Vector, Severity};
+
+#[test]
+fn base_critical() {
+    let cvss_string = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
+    let vector = Vector::from_str(cvss_string).unwrap();
+    assert_eq!(&vector.to_string(), cvss_string);
+    assert_eq!(vector.score().value(), 9.3);
+    assert_eq!(vector.score().severity(), Severity::Critical);
+}
+
+#[test]
+fn base_high() {
+    let cvss_string = "CVSS:4.0/AV:L/AC:L/AT:N/PR:L/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
+    let vector = Vector::from_str(cvss_string).unwrap();
+    assert_eq!(vector.score().value(), 8.5);
+    assert_eq!(vector.score().severity(), Severity::High);
+}
+
+#[test]
+fn no_impact() {
+    let cvss_string = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N";
+    let vector = Vector::from_str(cvss_string).unwrap();
+    assert_eq!(vector.score().value(), 0.0);
+    assert_eq!(vector.score().severity(), Severity::None);
+}
+
+#[test]
+fn threat_metrics_lower_the_score() {
+    let base = Vector::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N")
+        .unwrap();
+    let unreported =
+        Vector::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:U")
+            .unwrap();
+    assert!(unreported.score().value() < base.score().value());
+}
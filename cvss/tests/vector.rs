@@ -0,0 +1,84 @@
+#![cfg(all(feature = "v3", feature = "std"))]
+/// Full vector (Base, Temporal and Environmental Metrics) tests
+use core::str::FromStr;
+use cvss::v3::{
+    base::AttackVector,
+    environmental::ConfidentialityRequirement,
+    temporal::{ExploitCodeMaturity, RemediationLevel, ReportConfidence},
+    Vector,
+};
+
+/// Base metrics only
+#[test]
+fn base_only() {
+    let cvss_string = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H";
+    let vector = Vector::from_str(cvss_string).unwrap();
+    assert_eq!(&vector.to_string(), cvss_string);
+    assert_eq!(vector.base, cvss::v3::Base::from_str(cvss_string).unwrap());
+    assert!(vector.temporal.is_empty());
+    assert!(vector.environmental.is_empty());
+    assert_eq!(vector.score().value(), 9.8);
+    assert_eq!(vector.temporal_score(), None);
+    assert_eq!(vector.environmental_score(), None);
+}
+
+/// Converting between full vectors and their Base metrics
+#[test]
+fn base_conversions() {
+    let vector = Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P").unwrap();
+    let base = cvss::v3::Base::from(vector.clone());
+    assert_eq!(vector.as_ref(), &base);
+    assert_eq!(Vector::from(base.clone()).base, base);
+    assert!(Vector::from(base).temporal.is_empty());
+}
+
+/// Base and Temporal metrics
+#[test]
+fn temporal() {
+    let cvss_string = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C";
+    let vector = Vector::from_str(cvss_string).unwrap();
+    assert_eq!(&vector.to_string(), cvss_string);
+    assert_eq!(vector.temporal.e, Some(ExploitCodeMaturity::ProofOfConcept));
+    assert_eq!(vector.temporal.rl, Some(RemediationLevel::OfficialFix));
+    assert_eq!(vector.temporal.rc, Some(ReportConfidence::Confirmed));
+    assert_eq!(vector.score().value(), 9.8);
+    assert_eq!(vector.temporal_score().unwrap().value(), 8.8);
+    assert_eq!(vector.environmental_score(), None);
+
+    // Base vectors can't contain Temporal metrics
+    assert!(cvss::v3::Base::from_str(cvss_string).is_err());
+}
+
+/// Base, Temporal and Environmental metrics
+#[test]
+fn environmental() {
+    let cvss_string = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C/CR:L/IR:L/AR:L";
+    let vector = Vector::from_str(cvss_string).unwrap();
+    assert_eq!(&vector.to_string(), cvss_string);
+    assert_eq!(
+        vector.environmental.cr,
+        Some(ConfidentialityRequirement::Low)
+    );
+    assert_eq!(vector.environmental_score().unwrap().value(), 7.2);
+
+    let vector = Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MAV:L").unwrap();
+    assert_eq!(vector.environmental.mav, Some(AttackVector::Local));
+    assert_eq!(vector.temporal_score(), None);
+    assert_eq!(vector.environmental_score().unwrap().value(), 8.4);
+}
+
+/// Not Defined (X) metrics
+#[test]
+fn not_defined() {
+    let vector =
+        Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:X/MAV:X").unwrap();
+    assert_eq!(vector.temporal.e, Some(ExploitCodeMaturity::NotDefined));
+    assert_eq!(vector.environmental.mav, None);
+    assert_eq!(vector.temporal_score().unwrap().value(), 9.8);
+}
+
+/// Invalid Temporal metric value
+#[test]
+fn invalid_temporal_metric() {
+    assert!(Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:Z").is_err());
+}
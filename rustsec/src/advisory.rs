@@ -2,6 +2,7 @@
 
 pub mod affected;
 mod category;
+mod cvss_preference;
 mod date;
 mod id;
 mod informational;
@@ -15,6 +16,7 @@ pub(crate) mod versions;
 pub use self::{
     affected::Affected,
     category::Category,
+    cvss_preference::CvssPreference,
     date::Date,
     id::{Id, IdKind},
     informational::Informational,
@@ -28,12 +30,23 @@ pub use self::{
 pub use cvss::Severity;
 
 use crate::{
+    collection::Collection,
     error::{Error, ErrorKind},
     fs,
 };
+use semver::Version;
 use serde::{Deserialize, Serialize};
 use std::{path::Path, str::FromStr};
 
+/// Parse a single advisory from a string containing its Markdown source
+/// (TOML front matter followed by the title and description).
+///
+/// This performs the same validation as [`Advisory::load_file`], and is
+/// equivalent to calling [`str::parse`].
+pub fn parse(advisory_data: &str) -> Result<Advisory, Error> {
+    advisory_data.parse()
+}
+
 /// RustSec Security Advisories
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Advisory {
@@ -49,16 +62,40 @@ pub struct Advisory {
 }
 
 impl Advisory {
-    /// Load an advisory from a `RUSTSEC-20XX-NNNN.md` file
+    /// Load an advisory from a `RUSTSEC-20XX-NNNN.md` file.
+    ///
+    /// Files with a `.toml` extension are parsed as legacy TOML advisories,
+    /// where the title and description are fields of the `[advisory]` table.
     pub fn load_file(path: impl AsRef<Path>) -> Result<Self, Error> {
         let path = path.as_ref();
 
         let advisory_data = fs::read_to_string(path)
             .map_err(|e| format_err!(ErrorKind::Io, "couldn't open {}: {}", path.display(), e))?;
 
-        advisory_data
-            .parse()
-            .map_err(|e| format_err!(ErrorKind::Parse, "error parsing {}: {}", path.display(), e))
+        Self::parse_file_contents(path, &advisory_data)
+    }
+
+    /// Parse the contents of the advisory file at `path`, which need not
+    /// exist on disk: it only decides the format and is used in errors
+    pub(crate) fn parse_file_contents(path: &Path, advisory_data: &str) -> Result<Self, Error> {
+        let result = if is_toml_file(path) {
+            toml::from_str(advisory_data).map_err(Error::from_toml)
+        } else {
+            advisory_data.parse()
+        };
+
+        result.map_err(|e| format_err!(ErrorKind::Parse, "error parsing {}: {}", path.display(), e))
+    }
+
+    /// Get the TOML source from the contents of the advisory file at `path`:
+    /// the whole file for legacy TOML advisories, or the front matter
+    /// (without the surrounding code fence) for Markdown ones
+    pub(crate) fn toml_source(path: &Path, advisory_data: String) -> Result<String, Error> {
+        if is_toml_file(path) {
+            Ok(advisory_data)
+        } else {
+            Ok(Parts::parse(&advisory_data)?.front_matter.to_owned())
+        }
     }
 
     /// Get advisory ID
@@ -81,9 +118,48 @@ impl Advisory {
         &self.metadata.date
     }
 
-    /// Get the severity of this advisory if it has a CVSS v3 associated
+    /// Get the RustSec vulnerability categories of this advisory
+    pub fn categories(&self) -> &[Category] {
+        self.metadata.categories.as_slice()
+    }
+
+    /// Get the freeform keywords describing this advisory
+    pub fn keywords(&self) -> &[Keyword] {
+        self.metadata.keywords.as_slice()
+    }
+
+    /// Get the collection this advisory belongs to.
+    ///
+    /// This is inferred from the advisory's location when it is loaded from
+    /// a [`Database`](crate::Database), and is `None` for advisories parsed
+    /// on their own.
+    pub fn collection(&self) -> Option<Collection> {
+        self.metadata.collection
+    }
+
+    /// Get the severity of this advisory if it has a CVSS vector associated.
+    ///
+    /// If it has both a CVSS v3 and a CVSS v4 vector, the severity is taken
+    /// from the v4 one. Use [`Advisory::severity_with`] to prefer another
+    /// version.
     pub fn severity(&self) -> Option<Severity> {
-        self.metadata.cvss.as_ref().map(|cvss| cvss.severity())
+        self.severity_with(CvssPreference::default())
+    }
+
+    /// Get the severity of this advisory, taken from the vector for the
+    /// preferred CVSS version if it has one (see [`CvssPreference`])
+    pub fn severity_with(&self, preference: CvssPreference) -> Option<Severity> {
+        self.metadata.severity(preference)
+    }
+
+    /// Is the given version of the affected package vulnerable?
+    ///
+    /// A version is affected unless it matches one of the patched or
+    /// unaffected version requirements. If the advisory lists specific
+    /// affected releases, only those are affected. This is what database
+    /// queries use, so prefer it over checking the version ranges directly.
+    pub fn is_version_affected(&self, version: &Version) -> bool {
+        self.versions.is_vulnerable(version)
     }
 
     /// Whether the advisory has been withdrawn, i.e. soft-deleted
@@ -92,6 +168,11 @@ impl Advisory {
     }
 }
 
+/// Is the advisory file at `path` in the legacy TOML format?
+fn is_toml_file(path: &Path) -> bool {
+    path.extension().map_or(false, |ext| ext == "toml")
+}
+
 impl FromStr for Advisory {
     type Err = Error;
 
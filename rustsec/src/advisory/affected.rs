@@ -1,6 +1,10 @@
 //! The `[affected]` subsection of an advisory: metadata specifying the scope
 //! of impacted systems/functions/usages.
 
+mod cfg;
+
+pub use self::cfg::Cfg;
+
 use crate::{
     error::{Error, ErrorKind},
     Map,
@@ -27,6 +31,12 @@ pub struct Affected {
     #[serde(default)]
     pub os: Vec<OS>,
 
+    /// `cfg` expression the vulnerable code is conditionally compiled under
+    /// (e.g. `cfg(windows)`), for advisories which are only relevant on
+    /// targets `arch` and `os` can't describe on their own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cfg: Option<Cfg>,
+
     /// Paths to types and/or functions containing vulnerable code, enumerated
     /// as canonical Rust paths (i.e. starting with the crate name), sans any
     /// path parameters.
@@ -34,6 +44,51 @@ pub struct Affected {
     /// (e.g. `mycrate::path::to::VulnerableStruct::vulnerable_func`)
     #[serde(default)]
     pub functions: Map<FunctionPath, Vec<VersionReq>>,
+
+    /// Cargo features of the crate which enable the vulnerable code.
+    ///
+    /// If any are listed, the crate is only affected when at least one of
+    /// them is enabled.
+    #[serde(default)]
+    pub features: Vec<String>,
+}
+
+impl Affected {
+    /// Is the vulnerable code enabled by the given set of crate features?
+    ///
+    /// Always true if the advisory isn't specific to any features.
+    pub fn features_enabled<S: AsRef<str>>(&self, enabled: &[S]) -> bool {
+        self.features.is_empty()
+            || enabled
+                .iter()
+                .any(|feature| self.features.iter().any(|f| f == feature.as_ref()))
+    }
+
+    /// Could the vulnerable code be compiled for any of the given targets?
+    ///
+    /// Empty lists mean the architecture or OS is unknown, and always true
+    /// if the advisory doesn't have a `cfg` expression.
+    pub fn cfg_matches(&self, arch: &[Arch], os: &[OS]) -> bool {
+        let cfg = match &self.cfg {
+            Some(cfg) => cfg,
+            None => return true,
+        };
+
+        let arches: Vec<Option<Arch>> = if arch.is_empty() {
+            vec![None]
+        } else {
+            arch.iter().copied().map(Some).collect()
+        };
+        let oses: Vec<Option<OS>> = if os.is_empty() {
+            vec![None]
+        } else {
+            os.iter().copied().map(Some).collect()
+        };
+
+        arches
+            .iter()
+            .any(|arch| oses.iter().any(|os| cfg.matches(*arch, *os)))
+    }
 }
 
 /// Canonical Rust Paths (sans parameters) to vulnerable types and/or functions
@@ -0,0 +1,149 @@
+//! `cfg` expressions restricting an advisory to certain targets
+
+use crate::error::{Error, ErrorKind};
+use cfg_expr::{
+    targets::{Family, ALL_BUILTINS},
+    Expression, Predicate, TargetPredicate,
+};
+use platforms::target::{Arch, OS};
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+/// A `cfg` expression (e.g. `cfg(all(unix, target_arch = "x86_64"))`)
+/// which the vulnerable code is conditionally compiled under.
+///
+/// Only the target's OS and architecture are known when matching, so
+/// predicates on anything else (e.g. `target_env`) can't rule a target out.
+#[derive(Clone, Debug)]
+pub struct Cfg(Expression);
+
+impl Cfg {
+    /// Could this expression be true for the given target?
+    ///
+    /// `None` means the target's architecture or OS is unknown, in which case
+    /// predicates on it are assumed to hold.
+    pub fn matches(&self, arch: Option<Arch>, os: Option<OS>) -> bool {
+        self.eval(arch, os).unwrap_or(true)
+    }
+
+    /// Evaluate the expression, or `None` if the result can't be determined
+    fn eval(&self, arch: Option<Arch>, os: Option<OS>) -> Option<bool> {
+        self.0.eval(|predicate| match predicate {
+            Predicate::Target(TargetPredicate::Arch(value)) => {
+                arch.map(|arch| arch.as_str() == value.as_str())
+            }
+            Predicate::Target(TargetPredicate::Os(value)) => {
+                os.map(|os| os.as_str() == value.as_str())
+            }
+            Predicate::Target(TargetPredicate::Family(family)) => in_family(family, arch, os?),
+            _ => None,
+        })
+    }
+}
+
+/// Is every built-in target with the given OS (and architecture, if known)
+/// in `family`?
+///
+/// `None` if only some of them are, or rustc has no such target.
+fn in_family(family: &Family, arch: Option<Arch>, os: OS) -> Option<bool> {
+    let mut targets = ALL_BUILTINS
+        .iter()
+        .filter(|target| target.os.as_ref().map_or("none", |os| os.as_str()) == os.as_str())
+        .filter(|target| arch.map_or(true, |arch| target.arch.as_str() == arch.as_str()))
+        .map(|target| target.families.contains(family));
+
+    let first = targets.next()?;
+    targets.all(|result| result == first).then_some(first)
+}
+
+impl Display for Cfg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.original())
+    }
+}
+
+impl FromStr for Cfg {
+    type Err = Error;
+
+    /// Parse a `cfg` expression, with or without the surrounding `cfg(...)`
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Expression::parse(s.trim())
+            .map(Cfg)
+            .map_err(|e| format_err!(ErrorKind::Parse, "invalid cfg '{}': {}", s, e))
+    }
+}
+
+// Expressions are compared by their source, like `Expression` itself does
+impl PartialEq for Cfg {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.original() == other.0.original()
+    }
+}
+
+impl Eq for Cfg {}
+
+impl Hash for Cfg {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.original().hash(state)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cfg {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        string
+            .parse()
+            .map_err(|e| D::Error::custom(format!("{}", e)))
+    }
+}
+
+impl Serialize for Cfg {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("cfg({})", self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cfg;
+    use platforms::target::{Arch, OS};
+
+    #[test]
+    fn parse_and_display() {
+        let cfg: Cfg = r#"cfg(all(unix, not(target_arch = "x86")))"#.parse().unwrap();
+        assert_eq!(cfg.to_string(), r#"all(unix, not(target_arch = "x86"))"#);
+        assert_eq!(
+            "windows".parse::<Cfg>().unwrap(),
+            "cfg(windows)".parse::<Cfg>().unwrap()
+        );
+
+        assert!("cfg(unix".parse::<Cfg>().is_err());
+        assert!("cfg(unix, windows)".parse::<Cfg>().is_err());
+        assert!("not(unix, windows)".parse::<Cfg>().is_err());
+        assert!(r#"target_os = linux"#.parse::<Cfg>().is_err());
+        assert!("maybe(unix)".parse::<Cfg>().is_err());
+    }
+
+    #[test]
+    fn families_come_from_rustc_targets() {
+        let cfg: Cfg = r#"cfg(target_family = "unix")"#.parse().unwrap();
+        assert!(cfg.matches(Some(Arch::AArch64), Some(OS::iOS)));
+        assert!(cfg.matches(Some(Arch::X86_64), Some(OS::Linux)));
+        assert!(!cfg.matches(Some(Arch::X86_64), Some(OS::Windows)));
+
+        let cfg: Cfg = "cfg(windows)".parse().unwrap();
+        assert!(!cfg.matches(None, Some(OS::FreeBSD)));
+    }
+
+    #[test]
+    fn unknown_predicates_match() {
+        let cfg: Cfg = r#"cfg(all(windows, target_env = "msvc"))"#.parse().unwrap();
+        assert!(cfg.matches(Some(Arch::X86_64), Some(OS::Windows)));
+        assert!(!cfg.matches(Some(Arch::X86_64), Some(OS::Linux)));
+        assert!(cfg.matches(Some(Arch::X86_64), None));
+    }
+}
@@ -0,0 +1,60 @@
+//! Which CVSS version an advisory's severity is taken from
+
+use crate::error::{Error, ErrorKind};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Which CVSS version's vector an advisory's severity is taken from, when
+/// it has vectors for more than one.
+///
+/// Severities from different vectors are never combined: the preferred
+/// version's vector decides alone, even if another vector rates the
+/// advisory differently. If the advisory has no vector for the preferred
+/// version, the vector it does have is used instead.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CvssPreference {
+    /// Use the vector for the highest CVSS version available
+    #[default]
+    Latest,
+
+    /// Prefer the CVSS v3 vector
+    V3,
+
+    /// Prefer the CVSS v4 vector
+    V4,
+}
+
+impl CvssPreference {
+    /// Get a `str` representing this preference
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Latest => "latest",
+            Self::V3 => "v3",
+            Self::V4 => "v4",
+        }
+    }
+}
+
+impl fmt::Display for CvssPreference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CvssPreference {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "latest" => Ok(Self::Latest),
+            "v3" => Ok(Self::V3),
+            "v4" => Ok(Self::V4),
+            _ => Err(format_err!(
+                ErrorKind::Parse,
+                "invalid CVSS version preference: {}",
+                s
+            )),
+        }
+    }
+}
@@ -6,6 +6,7 @@ use std::{
     fmt::{self, Display},
     str::FromStr,
 };
+use time::{Month, OffsetDateTime};
 
 /// Minimum allowed year on advisory dates
 pub(crate) const YEAR_MIN: u32 = 2000;
@@ -18,6 +19,28 @@ pub(crate) const YEAR_MAX: u32 = YEAR_MIN + 100;
 pub struct Date(String);
 
 impl Date {
+    /// Get today's date (in UTC)
+    pub fn today() -> Self {
+        let today = OffsetDateTime::now_utc().date();
+        Date(format!(
+            "{:04}-{:02}-{:02}",
+            today.year(),
+            u8::from(today.month()),
+            today.day()
+        ))
+    }
+
+    /// Number of days from this date until today (negative if it's in the future)
+    pub fn days_ago(&self) -> i64 {
+        (OffsetDateTime::now_utc().date() - self.to_time()).whole_days()
+    }
+
+    /// Is this date after today, i.e. has the advisory not been published yet?
+    pub fn is_future(&self) -> bool {
+        // RFC 3339 dates sort chronologically as strings
+        self.as_str() > Self::today().as_str()
+    }
+
     /// Get the year for this date
     pub fn year(&self) -> u32 {
         self.component(0).expect("has year")
@@ -38,6 +61,22 @@ impl Date {
         self.0.as_ref()
     }
 
+    /// Convert this date into a [`time::Date`]
+    fn to_time(&self) -> time::Date {
+        let month = u8::try_from(self.month())
+            .ok()
+            .and_then(|month| Month::try_from(month).ok())
+            .expect("valid month");
+
+        // Days past the end of the month aren't rejected when parsing, so
+        // clamp them to the last day of the month
+        let day = u8::try_from(self.day())
+            .expect("valid day")
+            .min(time::util::days_in_year_month(self.year() as i32, month));
+
+        time::Date::from_calendar_date(self.year() as i32, month, day).expect("valid date")
+    }
+
     /// Get a specific component of the date by numerical offset
     fn component(&self, index: usize) -> Option<u32> {
         self.0
@@ -135,6 +174,21 @@ mod tests {
         assert!(Date::from_str("2017-01-01-01").is_err());
     }
 
+    #[test]
+    fn days_ago_test() {
+        assert_eq!(Date::today().days_ago(), 0);
+        assert!(Date::from_str("2000-01-01").unwrap().days_ago() > 0);
+        assert!(Date::from_str("2099-12-31").unwrap().days_ago() < 0);
+        assert!(Date::from_str("2023-02-31").unwrap().days_ago() > 0);
+    }
+
+    #[test]
+    fn future_date_test() {
+        assert!(Date::from_str("2099-12-31").unwrap().is_future());
+        assert!(!Date::from_str("2000-01-01").unwrap().is_future());
+        assert!(!Date::today().is_future());
+    }
+
     #[test]
     fn date_components_test() {
         let date = Date::from_str("2000-01-02").unwrap();
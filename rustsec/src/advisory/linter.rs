@@ -52,8 +52,15 @@ impl Linter {
 
         // Get advisory "front matter" (TOML formatted)
         let advisory_parts = parts::Parts::parse(s)?;
-        let front_matter = advisory_parts
-            .front_matter
+
+        // V4 advisories omit the leading `[advisory]` TOML table
+        let front_matter = if advisory_parts.front_matter.starts_with("[advisory]") {
+            advisory_parts.front_matter.to_owned()
+        } else {
+            String::from("[advisory]\n") + advisory_parts.front_matter
+        };
+
+        let front_matter = front_matter
             .parse::<toml::Value>()
             .map_err(crate::Error::from_toml)?;
 
@@ -216,8 +223,8 @@ impl Linter {
                             }
                         }
                     }
-                    "aliases" | "cvss" | "keywords" | "package" | "references" | "related"
-                    | "title" | "withdrawn" | "description" => (),
+                    "aliases" | "cvss" | "cvss_v4" | "keywords" | "package" | "references"
+                    | "related" | "title" | "withdrawn" | "description" | "context" => (),
                     _ => self.errors.push(Error {
                         kind: ErrorKind::key(key),
                         section: Some("advisory"),
@@ -239,7 +246,7 @@ impl Linter {
         if let Some(table) = versions.as_table() {
             for (key, _) in table {
                 match key.as_str() {
-                    "patched" | "unaffected" => (),
+                    "patched" | "unaffected" | "affected" | "affected_ranges" => (),
                     _ => self.errors.push(Error {
                         kind: ErrorKind::key(key),
                         section: Some("versions"),
@@ -270,7 +277,7 @@ impl Linter {
                             }
                         }
                     }
-                    "arch" | "os" => (),
+                    "arch" | "os" | "cfg" | "features" => (),
                     _ => self.errors.push(Error {
                         kind: ErrorKind::key(key),
                         section: Some("affected"),
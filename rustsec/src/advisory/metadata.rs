@@ -1,7 +1,8 @@
 //! Advisory information (i.e. the `[advisory]` section)
 
 use super::{
-    category::Category, date::Date, id::Id, informational::Informational, keyword::Keyword,
+    category::Category, cvss_preference::CvssPreference, date::Date, id::Id,
+    informational::Informational, keyword::Keyword, Severity,
 };
 use crate::advisory::license::License;
 use crate::{collection::Collection, package, SourceId};
@@ -21,10 +22,18 @@ pub struct Metadata {
     #[serde(default)]
     pub title: String,
 
-    /// Extended description of a vulnerability
+    /// Extended description of a vulnerability (Markdown).
+    ///
+    /// This is the body of the advisory and is included in serialized reports.
     #[serde(default)]
     pub description: String,
 
+    /// Short note explaining when the affected versions are actually
+    /// vulnerable (e.g. "only affected when the `tls` feature is enabled"),
+    /// to help users decide whether an advisory is relevant to them
+    #[serde(default)]
+    pub context: Option<String>,
+
     /// Date the underlying issue was reported
     pub date: Date,
 
@@ -51,14 +60,30 @@ pub struct Metadata {
     #[serde(default)]
     pub keywords: Vec<Keyword>,
 
-    /// CVSS v3.1 Base Metrics vector string containing severity information.
+    /// CVSS v3.1 vector string containing severity information.
+    ///
+    /// This always has the Base Metrics, and may also have Temporal and
+    /// Environmental Metrics (e.g. `E:P/RL:O/RC:C`). Before those were
+    /// parsed this was a [`cvss::v3::Base`], which [`Metadata::cvss_base`]
+    /// still returns.
     ///
     /// Example:
     ///
     /// ```text
     /// CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N
     /// ```
-    pub cvss: Option<cvss::v3::Base>,
+    pub cvss: Option<cvss::v3::Vector>,
+
+    /// CVSS v4.0 vector string containing severity information, which an
+    /// advisory may have alongside or instead of [`Metadata::cvss`].
+    ///
+    /// Example:
+    ///
+    /// ```text
+    /// CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cvss_v4: Option<cvss::v4::Vector>,
 
     /// Informational advisories can be used to warn users about issues
     /// affecting a particular crate without failing the build.
@@ -87,3 +112,48 @@ pub struct Metadata {
     #[serde(default)]
     pub license: License,
 }
+
+impl Metadata {
+    /// Get the severity of this advisory from the vector for the preferred
+    /// CVSS version, falling back to any other vector it has.
+    ///
+    /// `None` if the advisory has no CVSS vector at all.
+    pub fn severity(&self, preference: CvssPreference) -> Option<Severity> {
+        match self.preferred_cvss(preference)? {
+            Cvss::V3(cvss) => Some(cvss.severity()),
+            Cvss::V4(cvss) => Some(cvss.score().severity()),
+        }
+    }
+
+    /// Get the numerical score of the vector the severity is taken from
+    /// (see [`Metadata::severity`])
+    pub fn cvss_score(&self, preference: CvssPreference) -> Option<f64> {
+        match self.preferred_cvss(preference)? {
+            Cvss::V3(cvss) => Some(cvss.score().value()),
+            Cvss::V4(cvss) => Some(cvss.score().value()),
+        }
+    }
+
+    /// Get the Base Metrics of the CVSS v3 vector, if there is one
+    pub fn cvss_base(&self) -> Option<&cvss::v3::Base> {
+        self.cvss.as_ref().map(|cvss| &cvss.base)
+    }
+
+    /// Get the vector for the preferred CVSS version, or the other one if
+    /// there isn't one
+    fn preferred_cvss(&self, preference: CvssPreference) -> Option<Cvss<'_>> {
+        let v3 = || self.cvss.as_ref().map(Cvss::V3);
+        let v4 = || self.cvss_v4.as_ref().map(Cvss::V4);
+
+        match preference {
+            CvssPreference::Latest | CvssPreference::V4 => v4().or_else(v3),
+            CvssPreference::V3 => v3().or_else(v4),
+        }
+    }
+}
+
+/// CVSS vector of either version
+enum Cvss<'a> {
+    V3(&'a cvss::v3::Vector),
+    V4(&'a cvss::v4::Vector),
+}
@@ -1,7 +1,7 @@
 //! The `[versions]` subsection of an advisory.
 
-use crate::{osv, Error};
-use semver::{Version, VersionReq};
+use crate::{osv, Error, ErrorKind};
+use semver::{BuildMetadata, Version, VersionReq};
 use serde::{Deserialize, Serialize};
 
 /// The `[versions]` subsection of an advisory: future home to information
@@ -15,11 +15,39 @@ pub struct Versions {
     /// Versions which were never affected in the first place
     #[serde(default)]
     unaffected: Vec<VersionReq>,
+
+    /// Explicit list of affected releases.
+    ///
+    /// When present, only these exact versions are vulnerable. This allows
+    /// advisories to enumerate non-contiguous bad releases.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    affected: Vec<Version>,
+
+    /// Ranges of affected versions.
+    ///
+    /// When present, versions outside all of these ranges aren't vulnerable,
+    /// even if they're neither patched nor unaffected. This allows advisories
+    /// to describe several separate bands of vulnerable versions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    affected_ranges: Vec<VersionReq>,
 }
 
 impl Versions {
     /// Is the given version of a package vulnerable?
+    ///
+    /// Build metadata (e.g. the `+build` in `1.2.3+build`) is ignored, as it
+    /// is when Cargo matches versions against requirements: `1.2.3+build` is
+    /// vulnerable exactly when `1.2.3` is.
     pub fn is_vulnerable(&self, version: &Version) -> bool {
+        let version = &without_build_metadata(version);
+
+        if !self.affected.is_empty() {
+            return self
+                .affected
+                .iter()
+                .any(|affected| without_build_metadata(affected) == *version);
+        }
+
         for range in osv::ranges_for_advisory(self).iter() {
             if range.affects(version) {
                 return true;
@@ -28,12 +56,58 @@ impl Versions {
         false
     }
 
+    /// Could any version matching the given requirement be vulnerable?
+    ///
+    /// This intersects `req` with the affected versions, i.e. everything which
+    /// isn't patched or unaffected (or only the explicitly listed `affected`
+    /// releases, if any). Requirements which can't be expressed as a single
+    /// range, such as wildcards, are conservatively treated as overlapping.
+    pub fn overlaps(&self, req: &VersionReq) -> bool {
+        if !self.affected.is_empty() {
+            return self.affected.iter().any(|version| req.matches(version));
+        }
+
+        osv::ranges_for_advisory(self)
+            .iter()
+            .any(|range| range.overlaps(req).unwrap_or(true))
+    }
+
     /// Creates a new `[versions]` entry.
     /// Checks consistency of the passed version requirements.
     pub fn new(patched: Vec<VersionReq>, unaffected: Vec<VersionReq>) -> Result<Self, Error> {
+        Self::new_with_affected(patched, unaffected, vec![])
+    }
+
+    /// Creates a new `[versions]` entry with an explicit list of affected releases.
+    /// Checks that none of the affected releases are patched or unaffected.
+    pub fn new_with_affected(
+        patched: Vec<VersionReq>,
+        unaffected: Vec<VersionReq>,
+        affected: Vec<Version>,
+    ) -> Result<Self, Error> {
+        RawVersions {
+            patched,
+            unaffected,
+            affected,
+            affected_ranges: vec![],
+        }
+        .try_into()
+    }
+
+    /// Creates a new `[versions]` entry with ranges of affected versions,
+    /// e.g. `^1` and `^3` for an advisory which doesn't affect 2.x releases.
+    /// Checks that the ranges don't overlap, and that some version in them
+    /// is neither patched nor unaffected.
+    pub fn new_with_affected_ranges(
+        patched: Vec<VersionReq>,
+        unaffected: Vec<VersionReq>,
+        affected_ranges: Vec<VersionReq>,
+    ) -> Result<Self, Error> {
         RawVersions {
             patched,
             unaffected,
+            affected: vec![],
+            affected_ranges,
         }
         .try_into()
     }
@@ -47,6 +121,16 @@ impl Versions {
     pub fn unaffected(&self) -> &[VersionReq] {
         self.unaffected.as_slice()
     }
+
+    /// Explicitly listed affected releases (empty if not specified)
+    pub fn affected(&self) -> &[Version] {
+        self.affected.as_slice()
+    }
+
+    /// Ranges of affected versions (empty if not specified)
+    pub fn affected_ranges(&self) -> &[VersionReq] {
+        self.affected_ranges.as_slice()
+    }
 }
 
 impl TryFrom<RawVersions> for Versions {
@@ -57,10 +141,23 @@ impl TryFrom<RawVersions> for Versions {
         Ok(Versions {
             patched: raw.patched,
             unaffected: raw.unaffected,
+            affected: raw.affected,
+            affected_ranges: raw.affected_ranges,
         })
     }
 }
 
+/// Copy of `version` without its build metadata.
+///
+/// Build metadata takes part in `Version`'s ordering and equality, so it has
+/// to be removed before comparing against the bounds of affected ranges.
+fn without_build_metadata(version: &Version) -> Version {
+    Version {
+        build: BuildMetadata::EMPTY,
+        ..version.clone()
+    }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 /// Raw deserialized data that didn't pass validation yet
 pub(crate) struct RawVersions {
@@ -68,9 +165,201 @@ pub(crate) struct RawVersions {
 
     #[serde(default)]
     pub unaffected: Vec<VersionReq>,
+
+    #[serde(default)]
+    pub affected: Vec<Version>,
+
+    #[serde(default)]
+    pub affected_ranges: Vec<VersionReq>,
 }
 
 fn validate_ranges(versions: &RawVersions) -> Result<(), Error> {
-    let _ = osv::ranges_for_unvalidated_advisory(versions)?;
+    let ranges = osv::ranges_for_unvalidated_advisory(versions)?;
+
+    if !versions.affected_ranges.is_empty() && ranges.is_empty() {
+        fail!(
+            ErrorKind::BadParam,
+            "affected version ranges are all patched or unaffected"
+        );
+    }
+
+    for version in &versions.affected {
+        let stripped = without_build_metadata(version);
+        if !ranges.iter().any(|range| range.affects(&stripped)) {
+            fail!(
+                ErrorKind::BadParam,
+                "affected version {} is also patched or unaffected",
+                version
+            );
+        }
+    }
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Versions;
+    use semver::{Version, VersionReq};
+
+    fn version(v: &str) -> Version {
+        Version::parse(v).unwrap()
+    }
+
+    #[test]
+    fn explicit_affected_list() {
+        let versions = Versions::new_with_affected(
+            vec![VersionReq::parse(">= 1.0.4").unwrap()],
+            vec![],
+            vec![version("1.0.1"), version("1.0.3")],
+        )
+        .unwrap();
+
+        assert!(versions.is_vulnerable(&version("1.0.1")));
+        assert!(versions.is_vulnerable(&version("1.0.3")));
+        assert!(!versions.is_vulnerable(&version("1.0.2")));
+        assert!(!versions.is_vulnerable(&version("1.0.4")));
+    }
+
+    #[test]
+    fn build_metadata_is_ignored() {
+        let versions = Versions::new(vec![req(">= 1.2.3")], vec![req("< 1.0.0")]).unwrap();
+        assert!(versions.is_vulnerable(&version("1.2.2+build")));
+        assert!(versions.is_vulnerable(&version("1.0.0+build.5")));
+        assert!(!versions.is_vulnerable(&version("1.2.3+build")));
+        assert!(!versions.is_vulnerable(&version("0.9.9+build")));
+
+        let versions = Versions::new_with_affected(
+            vec![req(">= 1.0.4")],
+            vec![],
+            vec![version("1.0.1"), version("1.0.3+upstream")],
+        )
+        .unwrap();
+        assert!(versions.is_vulnerable(&version("1.0.1+build")));
+        assert!(versions.is_vulnerable(&version("1.0.3")));
+        assert!(!versions.is_vulnerable(&version("1.0.2+build")));
+    }
+
+    fn req(r: &str) -> VersionReq {
+        VersionReq::parse(r).unwrap()
+    }
+
+    #[test]
+    fn exclusive_upper_bound() {
+        // Affected: `>= 1.0, < 1.4`
+        let versions = Versions::new(vec![req(">= 1.4")], vec![req("< 1.0")]).unwrap();
+        assert!(versions.is_vulnerable(&version("1.0.0")));
+        assert!(versions.is_vulnerable(&version("1.3.9")));
+        assert!(versions.is_vulnerable(&version("1.4.0-rc.1")));
+        assert!(!versions.is_vulnerable(&version("1.4.0")));
+        assert!(!versions.is_vulnerable(&version("0.9.9")));
+
+        // Unaffected up to and including every 1.2.x release
+        let versions = Versions::new(vec![req(">= 2.0.0")], vec![req("<= 1.2")]).unwrap();
+        assert!(!versions.is_vulnerable(&version("1.2.7")));
+        assert!(versions.is_vulnerable(&version("1.3.0")));
+    }
+
+    #[test]
+    fn overlapping_ranges() {
+        let versions = Versions::new(vec![req(">= 1.2.3")], vec![req("< 1.0.0")]).unwrap();
+        assert!(versions.overlaps(&req("^1.2")));
+        assert!(versions.overlaps(&req(">= 1.1.0, < 1.1.5")));
+        assert!(versions.overlaps(&req("<= 1.0.0")));
+    }
+
+    #[test]
+    fn adjacent_ranges() {
+        let versions = Versions::new(vec![req(">= 1.2.3")], vec![req("< 1.0.0")]).unwrap();
+        assert!(!versions.overlaps(&req(">= 1.2.3")));
+        assert!(!versions.overlaps(&req("< 1.0.0")));
+        assert!(versions.overlaps(&req("<= 1.2.2")));
+    }
+
+    #[test]
+    fn disjoint_ranges() {
+        let versions = Versions::new(vec![req(">= 1.2.3")], vec![req("< 1.0.0")]).unwrap();
+        assert!(!versions.overlaps(&req("^0.9")));
+        assert!(!versions.overlaps(&req("^2")));
+        assert!(!versions.overlaps(&req("= 1.3.0")));
+    }
+
+    #[test]
+    fn open_ended_ranges() {
+        // No patched versions: everything which isn't unaffected is vulnerable
+        let versions = Versions::new(vec![], vec![req("< 1.0.0")]).unwrap();
+        assert!(versions.overlaps(&req(">= 5.0.0")));
+        assert!(!versions.overlaps(&req("^0.5")));
+
+        // Neither patched nor unaffected: every version is vulnerable
+        let versions = Versions::new(vec![], vec![]).unwrap();
+        assert!(versions.overlaps(&req("*")));
+        assert!(versions.overlaps(&req("= 0.0.1")));
+    }
+
+    #[test]
+    fn explicit_affected_list_overlaps() {
+        let versions = Versions::new_with_affected(
+            vec![req(">= 1.0.4")],
+            vec![],
+            vec![version("1.0.1"), version("1.0.3")],
+        )
+        .unwrap();
+        assert!(versions.overlaps(&req("= 1.0.3")));
+        assert!(!versions.overlaps(&req("= 1.0.2")));
+    }
+
+    #[test]
+    fn affected_list_conflicting_with_patched() {
+        assert!(Versions::new_with_affected(
+            vec![VersionReq::parse(">= 1.0.4").unwrap()],
+            vec![],
+            vec![version("1.0.5")],
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn non_contiguous_affected_ranges() {
+        // 1.x and 3.x are affected, but 2.x never was
+        let versions =
+            Versions::new_with_affected_ranges(vec![], vec![], vec![req("^1"), req("^3")]).unwrap();
+        assert!(versions.is_vulnerable(&version("1.0.0")));
+        assert!(versions.is_vulnerable(&version("1.9.3")));
+        assert!(!versions.is_vulnerable(&version("2.4.0")));
+        assert!(!versions.is_vulnerable(&version("3.0.0-rc.1")));
+        assert!(versions.is_vulnerable(&version("3.2.1")));
+        assert!(!versions.is_vulnerable(&version("0.9.0")));
+        assert!(!versions.is_vulnerable(&version("4.0.0")));
+
+        assert!(versions.overlaps(&req("^3.1")));
+        assert!(!versions.overlaps(&req("^2")));
+
+        // Patched releases within a band are still excluded
+        let versions = Versions::new_with_affected_ranges(
+            vec![req(">= 1.5.0, < 2.0.0"), req(">= 3.1.0")],
+            vec![],
+            vec![req("^1"), req("^3")],
+        )
+        .unwrap();
+        assert!(versions.is_vulnerable(&version("1.4.9")));
+        assert!(!versions.is_vulnerable(&version("1.5.0")));
+        assert!(versions.is_vulnerable(&version("3.0.5")));
+        assert!(!versions.is_vulnerable(&version("3.1.0")));
+    }
+
+    #[test]
+    fn invalid_affected_ranges() {
+        // Overlapping bands
+        assert!(
+            Versions::new_with_affected_ranges(vec![], vec![], vec![req("^1"), req(">= 1.5")])
+                .is_err()
+        );
+
+        // Nothing in the band is vulnerable
+        assert!(
+            Versions::new_with_affected_ranges(vec![req(">= 1.0.0")], vec![], vec![req("^1")])
+                .is_err()
+        );
+    }
+}
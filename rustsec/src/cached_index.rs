@@ -1,57 +1,327 @@
 //! An efficient way to check whether a given package has been yanked
 use std::{
     collections::{BTreeSet, HashMap},
-    time::Duration,
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use crate::{
+    advisory,
     error::{Error, ErrorKind},
+    fs,
     package::{self, Package},
+    warning::LatestRelease,
+    Version,
 };
+use serde::{Deserialize, Serialize};
 
 pub use tame_index::external::reqwest::ClientBuilder;
-use tame_index::utils::flock::{FileLock, LockOptions};
+use tame_index::{
+    external::tokio::runtime::Runtime,
+    utils::flock::{FileLock, LockOptions},
+};
+
+/// Versions of a crate in the index, and what the index says about each.
+///
+/// This is logically HashMap<Version, VersionInfo>
+/// but we don't parse semver because crates.io registry contains invalid semver:
+/// <https://github.com/rustsec/rustsec/issues/759>
+type CrateVersions = HashMap<String, VersionInfo>;
+
+/// What the index says about a single version of a crate
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct VersionInfo {
+    /// Has this version been yanked?
+    yanked: bool,
+
+    /// When this version was published, if the index records it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    published: Option<advisory::Date>,
+}
 
 enum Index {
     Git(tame_index::index::RemoteGitIndex),
     SparseCached(tame_index::index::SparseIndex),
     SparseRemote(tame_index::index::AsyncRemoteSparseIndex),
+    /// Fixed crate data which counts how often it's queried
+    #[cfg(test)]
+    Fixed {
+        krates: HashMap<String, CrateVersions>,
+        queries: std::sync::atomic::AtomicUsize,
+    },
 }
 
 impl Index {
     #[inline]
-    fn krate(
-        &self,
-        name: &package::Name,
-        lock: &FileLock,
-    ) -> Result<Option<tame_index::IndexKrate>, Error> {
+    fn krate(&self, name: &package::Name, lock: &FileLock) -> Result<Option<CrateVersions>, Error> {
+        #[cfg(test)]
+        if let Self::Fixed { krates, queries } = self {
+            queries.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            return Ok(krates.get(name.as_str()).cloned());
+        }
+
         let name = name.as_str().try_into().map_err(Error::from_tame)?;
         let res = match self {
             Self::Git(gi) => gi.krate(name, true, lock),
             Self::SparseCached(si) => si.cached_krate(name, lock),
             Self::SparseRemote(rsi) => rsi.cached_krate(name, lock),
+            #[cfg(test)]
+            Self::Fixed { .. } => unreachable!(),
         }
         .map_err(Error::from_tame)?;
 
-        Ok(res)
+        Ok(res.map(crate_versions))
+    }
+
+    /// Revision of the index contents, if the index has one.
+    ///
+    /// Only git indexes have a revision (their `HEAD` commit). Sparse index
+    /// entries are fetched individually, so there's no single revision.
+    fn revision(&self) -> Option<String> {
+        match self {
+            Self::Git(gi) => gi.local().head_commit().map(str::to_owned),
+            _ => None,
+        }
+    }
+}
+
+/// Versions listed in an index entry
+fn crate_versions(krate: tame_index::IndexKrate) -> CrateVersions {
+    krate
+        .versions
+        .into_iter()
+        .map(|v| {
+            let info = VersionInfo {
+                yanked: v.is_yanked(),
+                // The index doesn't record when versions were published, so
+                // dates come from the crates.io API (see `CratesIoApi`)
+                published: None,
+            };
+            (v.version.to_string(), info)
+        })
+        .collect()
+}
+
+/// Base URL of the crates.io web API
+const CRATES_IO_API: &str = "https://crates.io/api/v1/crates";
+
+/// User agent for crates.io API requests.
+///
+/// The crates.io crawler policy asks for a way to contact whoever is
+/// making the requests.
+const API_USER_AGENT: &str = concat!(
+    "rustsec/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/rustsec/rustsec)"
+);
+
+/// How long a single crates.io API request may take
+const API_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Minimum time between crates.io API requests, which the crawler policy
+/// limits to one per second
+const API_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Client for the crates.io web API, which records when each version of a
+/// crate was published
+struct CratesIoApi {
+    client: tame_index::external::reqwest::Client,
+    /// When the last request was sent, for rate limiting
+    last_request: Option<Instant>,
+}
+
+/// Response to `GET /api/v1/crates/{name}/{version}`, reduced to what we use
+#[derive(Deserialize)]
+struct ApiVersionResponse {
+    version: ApiVersion,
+}
+
+#[derive(Deserialize)]
+struct ApiVersion {
+    /// RFC 3339 timestamp of when the version was published
+    created_at: String,
+}
+
+impl CratesIoApi {
+    fn new(client: Option<ClientBuilder>) -> Result<Self, Error> {
+        // crates.io rejects API requests without a user agent
+        let client = client
+            .unwrap_or_default()
+            .user_agent(API_USER_AGENT)
+            .timeout(API_TIMEOUT)
+            .build()
+            .map_err(|err| {
+                format_err!(
+                    ErrorKind::Registry,
+                    "unable to build crates.io API client: {}",
+                    err
+                )
+            })?;
+
+        Ok(Self {
+            client,
+            last_request: None,
+        })
+    }
+
+    /// Get the date the given version of a crate was published
+    fn published(
+        &mut self,
+        runtime: &Runtime,
+        name: &package::Name,
+        version: &str,
+    ) -> Result<advisory::Date, Error> {
+        let url = format!("{}/{}/{}", CRATES_IO_API, name, version);
+        let error = |err: &dyn std::fmt::Display| {
+            format_err!(
+                ErrorKind::Registry,
+                "couldn't get {} {} from the crates.io API: {}",
+                name,
+                version,
+                err
+            )
+        };
+
+        if let Some(elapsed) = self.last_request.map(|last| last.elapsed()) {
+            if elapsed < API_REQUEST_INTERVAL {
+                thread::sleep(API_REQUEST_INTERVAL - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+
+        let body = runtime
+            .block_on(async {
+                self.client
+                    .get(&url)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .text()
+                    .await
+            })
+            .map_err(|err| error(&err))?;
+
+        publication_date(&body).map_err(|err| error(&err))
+    }
+}
+
+/// Date a version was published, from a crates.io API response for it
+fn publication_date(body: &str) -> Result<advisory::Date, Error> {
+    let response: ApiVersionResponse = serde_json::from_str(body)
+        .map_err(|err| format_err!(ErrorKind::Parse, "invalid response: {}", err))?;
+    let created_at = response.version.created_at;
+
+    // The date is the part of the timestamp before the time, e.g. `2017-05-03`
+    created_at
+        .split_once('T')
+        .map_or(created_at.as_str(), |(date, _)| date)
+        .parse()
+}
+
+/// On-disk cache of index lookups, so repeated scans can reuse them
+struct DiskCache {
+    /// Directory containing one file per crate
+    dir: PathBuf,
+
+    /// How long entries stay valid if the index has no revision
+    max_age: Duration,
+}
+
+/// A crate's index entry as stored in the [`DiskCache`]
+#[derive(Deserialize, Serialize)]
+struct DiskEntry {
+    /// Revision of the index the entry was read from
+    revision: Option<String>,
+
+    /// When the entry was read from the index, in seconds since the Unix epoch
+    fetched_at: u64,
+
+    /// Versions of the crate (`None` if it isn't in the index)
+    versions: Option<CrateVersions>,
+}
+
+impl DiskCache {
+    /// Path of the entry for the given crate
+    fn path(&self, name: &package::Name) -> PathBuf {
+        // crates.io treats names case-insensitively
+        self.dir
+            .join(format!("{}.toml", name.as_str().to_ascii_lowercase()))
+    }
+
+    /// Load the entry for the given crate, if it's still fresh.
+    ///
+    /// An entry is fresh if it was read at the current index revision, or,
+    /// for indexes without a revision, if it's no older than `max_age`.
+    fn load(&self, name: &package::Name, revision: Option<&str>) -> Option<Option<CrateVersions>> {
+        let contents = fs::read_to_string(self.path(name)).ok()?;
+        let entry: DiskEntry = toml::from_str(&contents).ok()?;
+
+        let fresh = match revision {
+            Some(revision) => entry.revision.as_deref() == Some(revision),
+            None => {
+                entry.revision.is_none()
+                    && now().saturating_sub(entry.fetched_at) <= self.max_age.as_secs()
+            }
+        };
+
+        fresh.then_some(entry.versions)
+    }
+
+    /// Store the entry for the given crate.
+    ///
+    /// The cache is only an optimization, so failures to write it are ignored.
+    fn store(
+        &self,
+        name: &package::Name,
+        revision: Option<&str>,
+        versions: &Option<CrateVersions>,
+    ) {
+        let entry = DiskEntry {
+            revision: revision.map(str::to_owned),
+            fetched_at: now(),
+            versions: versions.clone(),
+        };
+
+        if let Ok(contents) = toml::to_string(&entry) {
+            let _ =
+                fs::create_dir_all(&self.dir).and_then(|_| fs::write(self.path(name), contents));
+        }
     }
 }
 
+/// Current time in seconds since the Unix epoch
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
 /// Provides an efficient way to check if the given package has been yanked.
 ///
 /// Operations on crates.io index are rather slow.
 /// Instead of peforming an index lookup for every version of every crate,
 /// this implementation looks up each crate only once and caches the result in memory.
+/// Lookups can also be cached on disk with [`CachedIndex::disk_cache`],
+/// so that repeated scans reuse them.
 ///
 /// Please note that this struct will hold a global Cargo package lock while it exists.
 /// Cargo operations that download crates (e.g. `cargo update` or even `cargo build`)
 /// will not be possible while this lock is held.
 pub struct CachedIndex {
     index: Index,
-    /// The inner hash map is logically HashMap<Version, IsYanked>
-    /// but we don't parse semver because crates.io registry contains invalid semver:
-    /// <https://github.com/rustsec/rustsec/issues/759>
-    cache: HashMap<package::Name, Result<Option<HashMap<String, bool>>, Error>>,
+    /// Index lookups made so far, by crate name
+    cache: HashMap<package::Name, Result<Option<CrateVersions>, Error>>,
+    /// Optional cache of lookups shared with other scans
+    disk_cache: Option<DiskCache>,
+    /// Optional source of the publication dates the index doesn't have
+    api: Option<CratesIoApi>,
+    /// Runtime for requests to the sparse index and the crates.io API,
+    /// started when it's first needed
+    runtime: Option<Runtime>,
+    /// Was the index fetched, rather than only read from the local cache?
+    /// Crates missing from a local cache may still exist.
+    fetched: bool,
     /// The lock we hold on the Cargo cache directory
     lock: FileLock,
 }
@@ -107,6 +377,10 @@ impl CachedIndex {
         Ok(CachedIndex {
             index,
             cache: Default::default(),
+            disk_cache: None,
+            api: None,
+            runtime: None,
+            fetched: true,
             lock,
         })
     }
@@ -148,30 +422,51 @@ impl CachedIndex {
         Ok(CachedIndex {
             index,
             cache: Default::default(),
+            disk_cache: None,
+            api: None,
+            runtime: None,
+            fetched: false,
             lock,
         })
     }
 
+    /// Also cache index lookups on disk in `dir`, reusing any cached there
+    /// by earlier scans.
+    ///
+    /// Cached lookups from a git index are reused until the index is updated
+    /// to a new commit. Sparse index entries don't have a revision, so they're
+    /// reused for up to `max_age` after they were fetched.
+    pub fn disk_cache(mut self, dir: impl Into<PathBuf>, max_age: Duration) -> Self {
+        self.disk_cache = Some(DiskCache {
+            dir: dir.into(),
+            max_age,
+        });
+        self
+    }
+
+    /// Look up when releases were published with the crates.io web API, so
+    /// [`CachedIndex::latest_release`] can report their dates and ages.
+    ///
+    /// The crates.io index doesn't record publication times, so without this
+    /// they're left out. Dates are cached along with the index entries, and
+    /// requests are limited to one per second as the crates.io crawler
+    /// policy asks.
+    pub fn release_dates_from_api(&mut self, client: Option<ClientBuilder>) -> Result<(), Error> {
+        self.api = Some(CratesIoApi::new(client)?);
+        Ok(())
+    }
+
     /// Populates the cache entries for all of the specified crates.
     fn populate_cache(&mut self, mut packages: BTreeSet<&package::Name>) -> Result<(), Error> {
         // only look up info on packages that aren't yet cached
         packages.retain(|pkg| !self.cache.contains_key(pkg));
+
+        // ...and that can't be reused from the disk cache
+        packages.retain(|pkg| !self.load_from_disk(pkg));
+
         match &self.index {
-            Index::Git(_) | Index::SparseCached(_) => {
-                for pkg in packages {
-                    self.insert(pkg.to_owned(), self.index.krate(pkg, &self.lock));
-                }
-            }
             Index::SparseRemote(rsi) => {
-                // Ensure we have a runtime
-                let rt = tame_index::external::tokio::runtime::Runtime::new().map_err(|err| {
-                    format_err!(
-                        ErrorKind::Registry,
-                        "unable to start a tokio runtime: {}",
-                        err
-                    )
-                })?;
-                let _rt = rt.enter();
+                let _rt = runtime(&mut self.runtime)?.enter();
 
                 /// This is the timeout per individual crate. If a crate fails to be
                 /// requested for a retriable reason then it will be retried until
@@ -199,45 +494,64 @@ impl CachedIndex {
                 for (name, res) in results {
                     self.insert(
                         name.parse().expect("this was a package name before"),
-                        res.map_err(Error::from_tame),
+                        res.map(|ik| ik.map(crate_versions))
+                            .map_err(Error::from_tame),
                     );
                 }
             }
+            _ => {
+                for pkg in packages {
+                    self.insert(pkg.to_owned(), self.index.krate(pkg, &self.lock));
+                }
+            }
         }
 
         Ok(())
     }
 
+    /// Populate the cache entry for a crate from the disk cache, if it has a
+    /// fresh entry for it. Returns whether it did.
+    fn load_from_disk(&mut self, package: &package::Name) -> bool {
+        let Some(disk_cache) = &self.disk_cache else {
+            return false;
+        };
+
+        match disk_cache.load(package, self.index.revision().as_deref()) {
+            Some(versions) => {
+                self.cache.insert(package.to_owned(), Ok(versions));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Record the result of looking up a crate in the index
     #[inline]
-    fn insert(
-        &mut self,
-        package: package::Name,
-        krate_res: Result<Option<tame_index::IndexKrate>, Error>,
-    ) {
-        let krate_res = krate_res.map(|ik| {
-            ik.map(|ik| {
-                ik.versions
-                    .into_iter()
-                    .map(|v| (v.version.to_string(), v.is_yanked()))
-                    .collect()
-            })
-        });
+    fn insert(&mut self, package: package::Name, krate_res: Result<Option<CrateVersions>, Error>) {
+        if let (Some(disk_cache), Ok(versions)) = (&self.disk_cache, &krate_res) {
+            disk_cache.store(&package, self.index.revision().as_deref(), versions);
+        }
 
         self.cache.insert(package, krate_res);
     }
 
-    /// Is the given package yanked?
-    fn is_yanked(&mut self, package: &Package) -> Result<bool, Error> {
-        if !self.cache.contains_key(&package.name) {
+    /// Look up the index entry for the given package's crate
+    fn lookup(&mut self, package: &Package) -> &Result<Option<CrateVersions>, Error> {
+        if !self.cache.contains_key(&package.name) && !self.load_from_disk(&package.name) {
             self.insert(
                 package.name.to_owned(),
                 self.index.krate(&package.name, &self.lock),
             );
         }
 
-        match &self.cache[&package.name] {
+        &self.cache[&package.name]
+    }
+
+    /// Is the given package yanked?
+    fn is_yanked(&mut self, package: &Package) -> Result<bool, Error> {
+        match self.lookup(package) {
             Ok(Some(ik)) => match ik.get(&package.version.to_string()) {
-                Some(is_yanked) => Ok(*is_yanked),
+                Some(info) => Ok(info.yanked),
                 None => Err(format_err!(
                     ErrorKind::NotFound,
                     "No such version in crates.io index: {} {}",
@@ -294,6 +608,147 @@ impl CachedIndex {
 
         yanked
     }
+
+    /// Iterate over the provided packages, returning a vector of the
+    /// packages whose version was never published to the index.
+    ///
+    /// No advisory can match a version which doesn't exist, so lockfiles
+    /// pinning one (through a typo or tampering) would otherwise look clean.
+    /// Like [`CachedIndex::find_yanked`], this should be called with many
+    /// packages at once.
+    ///
+    /// If the index was only [opened](CachedIndex::open) rather than fetched,
+    /// crates missing from the local cache may still exist, so they aren't
+    /// reported.
+    pub fn find_unpublished<'a, I>(&mut self, packages: I) -> Vec<Result<&'a Package, Error>>
+    where
+        I: IntoIterator<Item = &'a Package>,
+    {
+        let mut unpublished = Vec::new();
+
+        let dedup_packages: BTreeSet<&Package> = packages.into_iter().collect();
+        let package_names: BTreeSet<&package::Name> =
+            dedup_packages.iter().map(|p| &p.name).collect();
+        if let Err(e) = self.populate_cache(package_names) {
+            unpublished.push(Err(Error::with_source(
+                ErrorKind::Registry,
+                "Failed to download crates.io index. \
+                    Data may be missing or stale when checking for unpublished packages."
+                    .to_owned(),
+                e,
+            )));
+        }
+
+        let fetched = self.fetched;
+        for package in dedup_packages {
+            match self.lookup(package) {
+                Ok(Some(versions)) if versions.contains_key(&package.version.to_string()) => {}
+                Ok(None) if !fetched => {} // unknown, it may just not be cached
+                Ok(_) => unpublished.push(Ok(package)),
+                Err(err) => unpublished.push(Err(format_err!(
+                    ErrorKind::Registry,
+                    "Failed to retrieve {} from crates.io index: {}",
+                    &package.name,
+                    err,
+                ))),
+            }
+        }
+
+        unpublished
+    }
+
+    /// Find the latest release of the given package's crate which hasn't been
+    /// yanked, along with when it was published if that's known.
+    ///
+    /// Publication dates are looked up with the crates.io API if enabled with
+    /// [`CachedIndex::release_dates_from_api`]. If a request to the API fails,
+    /// its error is returned and the API isn't used again, so later releases
+    /// are returned without a date instead of waiting on it.
+    ///
+    /// Returns `None` if the crate isn't in the index or has no releases
+    /// with a valid version.
+    pub fn latest_release(&mut self, package: &Package) -> Result<Option<LatestRelease>, Error> {
+        let versions = match self.lookup(package) {
+            Ok(Some(versions)) => versions,
+            Ok(None) => return Ok(None),
+            Err(err) => {
+                return Err(format_err!(
+                    ErrorKind::Registry,
+                    "Failed to retrieve {} from crates.io index: {}",
+                    &package.name,
+                    err,
+                ))
+            }
+        };
+
+        let latest = versions
+            .iter()
+            .filter(|(_, info)| !info.yanked)
+            .filter_map(|(version, info)| Some((version.parse::<Version>().ok()?, version, info)))
+            .max_by(|(a, _, _), (b, _, _)| a.cmp(b))
+            .map(|(version, key, info)| (version, key.clone(), info.published.clone()));
+
+        let Some((version, key, mut published)) = latest else {
+            return Ok(None);
+        };
+
+        if published.is_none() {
+            if let Some(api) = &mut self.api {
+                let result = runtime(&mut self.runtime)
+                    .and_then(|runtime| api.published(runtime, &package.name, &key));
+
+                match result {
+                    Ok(date) => {
+                        published = Some(date);
+                        self.record_published(&package.name, &key, published.clone());
+                    }
+                    Err(err) => {
+                        self.api = None;
+                        return Err(err);
+                    }
+                }
+            }
+        }
+
+        Ok(Some(LatestRelease::new(version, published)))
+    }
+
+    /// Record when a version was published in the cached index entry for its
+    /// crate, so it doesn't have to be looked up again
+    fn record_published(
+        &mut self,
+        name: &package::Name,
+        version: &str,
+        published: Option<advisory::Date>,
+    ) {
+        let Some(Ok(Some(versions))) = self.cache.get_mut(name) else {
+            return;
+        };
+        let Some(info) = versions.get_mut(version) else {
+            return;
+        };
+        info.published = published;
+
+        if let Some(disk_cache) = &self.disk_cache {
+            let versions = Some(versions.clone());
+            disk_cache.store(name, self.index.revision().as_deref(), &versions);
+        }
+    }
+}
+
+/// Get the runtime for async requests, starting it if it isn't running yet
+fn runtime(runtime: &mut Option<Runtime>) -> Result<&Runtime, Error> {
+    if runtime.is_none() {
+        *runtime = Some(Runtime::new().map_err(|err| {
+            format_err!(
+                ErrorKind::Registry,
+                "unable to start a tokio runtime: {}",
+                err
+            )
+        })?);
+    }
+
+    Ok(runtime.as_ref().expect("runtime was just started"))
 }
 
 // We cannot expose these publicly because that would leak the `tame_index` SemVer into the public API
@@ -312,3 +767,158 @@ fn acquire_lock(
         lock_opts.lock(|_| Some(lock_timeout))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{publication_date, CachedIndex, Index, VersionInfo};
+    use crate::{advisory::Date, package::Package};
+    use std::{collections::HashMap, sync::atomic::Ordering, time::Duration};
+    use tame_index::utils::flock::FileLock;
+
+    /// Index containing `base` (with a yanked 1.1.0 release) and `other`
+    fn fixed_index() -> CachedIndex {
+        let krates = HashMap::from([
+            (
+                "base".to_owned(),
+                HashMap::from([
+                    ("1.0.0".to_owned(), version(false, Some("2020-01-01"))),
+                    ("1.1.0".to_owned(), version(true, Some("2021-01-01"))),
+                ]),
+            ),
+            (
+                "other".to_owned(),
+                HashMap::from([("0.1.0".to_owned(), version(false, None))]),
+            ),
+        ]);
+
+        CachedIndex {
+            index: Index::Fixed {
+                krates,
+                queries: Default::default(),
+            },
+            cache: Default::default(),
+            disk_cache: None,
+            api: None,
+            runtime: None,
+            fetched: true,
+            lock: FileLock::unlocked(),
+        }
+    }
+
+    fn version(yanked: bool, published: Option<&str>) -> VersionInfo {
+        VersionInfo {
+            yanked,
+            published: published.map(|date| date.parse().unwrap()),
+        }
+    }
+
+    /// Number of times the index has been queried
+    fn queries(index: &CachedIndex) -> usize {
+        match &index.index {
+            Index::Fixed { queries, .. } => queries.load(Ordering::SeqCst),
+            _ => unreachable!(),
+        }
+    }
+
+    fn packages() -> Vec<Package> {
+        [("base", "1.0.0"), ("base", "1.1.0"), ("other", "0.1.0")]
+            .iter()
+            .map(|(name, version)| Package {
+                name: name.parse().unwrap(),
+                version: version.parse().unwrap(),
+                source: None,
+                checksum: None,
+                dependencies: vec![],
+                replace: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn index_is_queried_once_per_crate() {
+        let packages = packages();
+        let mut index = fixed_index();
+
+        let yanked = index.find_yanked(&packages);
+        assert_eq!(yanked.len(), 1);
+        assert_eq!(yanked[0].as_ref().unwrap().version.to_string(), "1.1.0");
+        assert_eq!(queries(&index), 2);
+
+        index.find_yanked(&packages);
+        assert_eq!(queries(&index), 2);
+    }
+
+    #[test]
+    fn unpublished_versions_are_found() {
+        let mut packages = packages();
+        packages[0].version = "1.0.1".parse().unwrap();
+        packages[2].name = "missing".parse().unwrap();
+
+        let mut index = fixed_index();
+        let mut unpublished: Vec<_> = index
+            .find_unpublished(&packages)
+            .into_iter()
+            .map(|package| {
+                let package = package.unwrap();
+                format!("{} {}", package.name, package.version)
+            })
+            .collect();
+        unpublished.sort();
+
+        assert_eq!(unpublished, ["base 1.0.1", "missing 0.1.0"]);
+
+        // Without fetching, a crate missing from the local cache is unknown
+        let mut index = fixed_index();
+        index.fetched = false;
+        let unpublished = index.find_unpublished(&packages);
+        assert_eq!(unpublished.len(), 1);
+        assert_eq!(unpublished[0].as_ref().unwrap().name.as_str(), "base");
+    }
+
+    #[test]
+    fn disk_cache_is_reused_between_scans() {
+        let dir = tempfile::tempdir().unwrap();
+        let packages = packages();
+
+        let mut first = fixed_index().disk_cache(dir.path(), Duration::from_secs(60));
+        assert_eq!(first.find_yanked(&packages).len(), 1);
+        assert_eq!(queries(&first), 2);
+        drop(first);
+
+        let mut second = fixed_index().disk_cache(dir.path(), Duration::from_secs(60));
+        let yanked = second.find_yanked(&packages);
+        assert_eq!(yanked.len(), 1);
+        assert_eq!(yanked[0].as_ref().unwrap().version.to_string(), "1.1.0");
+        assert_eq!(queries(&second), 0);
+    }
+
+    #[test]
+    fn latest_release_age() {
+        let packages = packages();
+        let mut index = fixed_index();
+
+        // 1.1.0 is yanked, so 1.0.0 is the latest release
+        let latest = index.latest_release(&packages[0]).unwrap().unwrap();
+        assert_eq!(latest.version.to_string(), "1.0.0");
+        assert_eq!(latest.date, Some("2020-01-01".parse::<Date>().unwrap()));
+        let age = latest.age_days.unwrap();
+        assert_eq!(age as i64, latest.date.unwrap().days_ago());
+        assert!(age > 365);
+
+        // Without a publication date the age is omitted
+        let latest = index.latest_release(&packages[2]).unwrap().unwrap();
+        assert_eq!(latest.version.to_string(), "0.1.0");
+        assert_eq!(latest.age_days, None);
+    }
+
+    #[test]
+    fn publication_date_from_api_response() {
+        let body = r#"{"version":{"num":"0.45.0","created_at":"2022-08-22T15:03:12.970011+00:00","yanked":false}}"#;
+        assert_eq!(
+            publication_date(body).unwrap(),
+            "2022-08-22".parse::<Date>().unwrap()
+        );
+
+        assert!(publication_date(r#"{"errors":[{"detail":"Not Found"}]}"#).is_err());
+    }
+}
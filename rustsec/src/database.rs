@@ -1,21 +1,49 @@
 //! Database containing `RustSec` security advisories
 
+#[cfg(feature = "archive")]
+mod archive;
 mod entries;
 mod index;
+mod load_report;
+#[cfg(feature = "manifest-signature")]
+mod manifest;
+mod open_options;
 mod query;
+mod schema;
+mod validation;
 
-pub use self::query::Query;
+pub use self::{
+    load_report::LoadReport,
+    open_options::OpenOptions,
+    query::Query,
+    schema::{SCHEMA_FILE, SUPPORTED_SCHEMA_VERSION},
+    validation::ValidationIssue,
+};
+
+#[cfg(feature = "archive")]
+pub use self::archive::ArchiveFormat;
 
-use self::{entries::Entries, index::Index};
+#[cfg(feature = "manifest-signature")]
+pub use self::manifest::{ManifestKey, MANIFEST_FILE, MANIFEST_SIGNATURE_FILE};
+
+use self::{
+    entries::{Entries, Parsed},
+    index::Index,
+};
 use crate::{
     advisory::{self, Advisory},
     collection::Collection,
-    error::Error,
+    error::{Error, ErrorKind},
     fs,
+    package::Package,
     vulnerability::Vulnerability,
-    Lockfile,
+    Lockfile, Map,
+};
+use semver::Version;
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
 };
-use std::path::Path;
 
 #[cfg(feature = "git")]
 use crate::repository::git;
@@ -35,52 +63,327 @@ pub struct Database {
     /// Index of third party crates
     crate_index: Index,
 
+    /// Local path the database was loaded from
+    path: PathBuf,
+
+    /// Files the advisories were loaded from, in the same order as `advisories`
+    advisory_paths: Vec<PathBuf>,
+
+    /// Advisory files which were skipped because they failed to load
+    load_report: LoadReport,
+
+    /// TOML sources of the advisories, if [`OpenOptions::retain_raw_source`] is set
+    raw_sources: Map<advisory::Id, String>,
+
     /// Information about the last git commit to the database
     #[cfg(feature = "git")]
     latest_commit: Option<git::Commit>,
+
+    /// Advisory files in the checkout which aren't in its `HEAD` commit
+    #[cfg(feature = "git")]
+    orphaned_advisories: Vec<PathBuf>,
 }
 
 impl Database {
     /// Open [`Database`] located at the given local path
     pub fn open(path: &Path) -> Result<Self, Error> {
+        Self::open_with_options(path, &OpenOptions::default())
+    }
+
+    /// Open [`Database`] located at the given local path using the given [`OpenOptions`]
+    pub fn open_with_options(path: &Path, options: &OpenOptions) -> Result<Self, Error> {
+        trace_span!("db.open", path = %path.display());
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        if options.check_schema_version {
+            schema::check(path)?;
+        }
+
         let mut advisory_paths = vec![];
+        let mut skipped_symlinks = vec![];
 
-        for collection in Collection::all() {
-            let collection_path = path.join(collection.as_str());
+        if options.flat_layout {
+            let mut visited = HashSet::from([fs::canonicalize(path)?]);
+            find_flat_advisories(
+                path,
+                path,
+                options.follow_symlinks,
+                &mut visited,
+                &mut advisory_paths,
+                &mut skipped_symlinks,
+            )?;
+        } else {
+            for collection in Collection::all() {
+                let collection_path = path.join(collection.as_str());
 
-            if let Ok(collection_entry) = fs::read_dir(&collection_path) {
-                for dir_entry in collection_entry {
-                    let dir_entry = dir_entry?;
-                    if !dir_entry.file_type()?.is_dir() {
-                        continue;
-                    }
-                    for advisory_entry in fs::read_dir(dir_entry.path())? {
-                        let advisory_path = advisory_entry?.path();
-                        let file_name = advisory_path.file_name().and_then(|f| f.to_str());
-                        // skip dotfiles like .DS_Store
-                        if file_name.map_or(false, |f| f.starts_with('.')) {
-                            continue;
+                if let Ok(collection_entry) = fs::read_dir(&collection_path) {
+                    for dir_entry in collection_entry {
+                        let dir_entry = dir_entry?;
+                        match file_type(&dir_entry, options.follow_symlinks)? {
+                            Some(file_type) if file_type.is_dir() => (),
+                            Some(_) => continue,
+                            None => {
+                                skipped_symlinks.push(dir_entry.path());
+                                continue;
+                            }
+                        }
+                        for advisory_entry in fs::read_dir(dir_entry.path())? {
+                            let advisory_entry = advisory_entry?;
+                            let advisory_path = advisory_entry.path();
+                            let file_name = advisory_path.file_name().and_then(|f| f.to_str());
+                            // skip dotfiles like .DS_Store
+                            if file_name.map_or(false, |f| f.starts_with('.')) {
+                                continue;
+                            }
+                            if file_type(&advisory_entry, options.follow_symlinks)?.is_none() {
+                                skipped_symlinks.push(advisory_path);
+                                continue;
+                            }
+                            advisory_paths.push(advisory_path);
                         }
-                        advisory_paths.push(advisory_path);
                     }
                 }
             }
         }
 
+        // Directory listings come back in arbitrary order, so sort them to
+        // make which of several conflicting advisories wins deterministic
+        advisory_paths.sort();
+
+        // Advisories checked against a signed manifest are parsed from the
+        // contents which were hashed, so the files can't be swapped out
+        // between checking and loading them
+        #[cfg(feature = "manifest-signature")]
+        let verified = match &options.manifest_key {
+            Some(key) => Some(manifest::verify(path, key, &advisory_paths)?),
+            None => None,
+        };
+        #[cfg(not(feature = "manifest-signature"))]
+        let verified: Option<Vec<Vec<u8>>> = None;
+
+        let parse_file = if options.flat_layout {
+            Entries::parse_flat_file
+        } else {
+            Entries::parse_file
+        };
+        let parse = |(index, path): (usize, &PathBuf)| match &verified {
+            Some(verified) => {
+                let contents = String::from_utf8(verified[index].clone()).map_err(|e| {
+                    format_err!(ErrorKind::Io, "couldn't open {}: {}", path.display(), e)
+                })?;
+                Entries::parse_contents(
+                    path,
+                    contents,
+                    options.retain_raw_source,
+                    options.flat_layout,
+                )
+            }
+            None => parse_file(path, options.retain_raw_source),
+        };
+
+        #[cfg(feature = "rayon")]
+        let parsed: Vec<_> = if options.parallel {
+            use rayon::prelude::*;
+            advisory_paths.par_iter().enumerate().map(parse).collect()
+        } else {
+            advisory_paths.iter().enumerate().map(parse).collect()
+        };
+
+        #[cfg(not(feature = "rayon"))]
+        let parsed: Vec<_> = advisory_paths.iter().enumerate().map(parse).collect();
+
+        let mut db = Self::from_parsed(path, advisory_paths.into_iter().zip(parsed), options)?;
+        skipped_symlinks.sort();
+        db.load_report.skipped_symlinks = skipped_symlinks;
+
+        trace_event!(
+            name: "db.load",
+            count = db.advisories.iter().count(),
+            duration_ms = started.elapsed().as_millis() as u64,
+            "loaded advisory database"
+        );
+
+        db.check_required_advisories(path, options)?;
+        Ok(db)
+    }
+
+    /// Load a [`Database`] from an archive of one in memory, e.g. a snapshot
+    /// embedded in a binary with `include_bytes!`, without touching the
+    /// filesystem or network.
+    ///
+    /// The archive has the same layout as a database on disk, optionally
+    /// inside a single top-level directory like GitHub tarballs have.
+    #[cfg(feature = "archive")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "archive")))]
+    pub fn from_archive_bytes(bytes: &[u8], format: ArchiveFormat) -> Result<Self, Error> {
+        Self::from_archive_bytes_with_options(bytes, format, &OpenOptions::default())
+    }
+
+    /// Load a [`Database`] from an archive in memory like
+    /// [`Database::from_archive_bytes`], using the given [`OpenOptions`].
+    ///
+    /// Links in the archive are always skipped. Signed manifests can't be
+    /// checked for archives, so loading fails if one is to be verified.
+    #[cfg(feature = "archive")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "archive")))]
+    pub fn from_archive_bytes_with_options(
+        bytes: &[u8],
+        format: ArchiveFormat,
+        options: &OpenOptions,
+    ) -> Result<Self, Error> {
+        trace_span!("db.open_archive", format = ?format);
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        #[cfg(feature = "manifest-signature")]
+        if options.manifest_key.is_some() {
+            fail!(
+                ErrorKind::BadParam,
+                "signed manifests can't be verified for advisory database archives"
+            );
+        }
+
+        // Databases from archives don't have a path, so errors name the archive
+        let location = Path::new("<archive>");
+        let files = archive::files(bytes, format)?;
+
+        if options.check_schema_version {
+            let schema = files
+                .iter()
+                .find(|(path, _)| path == Path::new(SCHEMA_FILE));
+            if let Some((_, contents)) = schema {
+                schema::check_contents(
+                    std::str::from_utf8(contents)?,
+                    &location.join(SCHEMA_FILE),
+                    location,
+                )?;
+            }
+        }
+
+        let mut files: Vec<_> = files
+            .into_iter()
+            .filter(|(path, _)| archive::is_advisory(path, options.flat_layout))
+            .collect();
+        files.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let parsed = files.into_iter().map(|(path, contents)| {
+            let result = String::from_utf8(contents)
+                .map_err(|err| {
+                    format_err!(
+                        ErrorKind::Parse,
+                        "invalid UTF-8 in {}: {}",
+                        path.display(),
+                        err
+                    )
+                })
+                .and_then(|contents| {
+                    Entries::parse_contents(
+                        &path,
+                        contents,
+                        options.retain_raw_source,
+                        options.flat_layout,
+                    )
+                });
+            (path, result)
+        });
+
+        let db = Self::from_parsed(Path::new(""), parsed, options)?;
+
+        trace_event!(
+            name: "db.load",
+            count = db.advisories.iter().count(),
+            duration_ms = started.elapsed().as_millis() as u64,
+            "loaded advisory database"
+        );
+
+        db.check_required_advisories(location, options)?;
+        Ok(db)
+    }
+
+    /// Check the database loaded from `location` has the advisories required
+    /// by [`OpenOptions::require_advisories`] and [`OpenOptions::require_collections`]
+    fn check_required_advisories(
+        &self,
+        location: &Path,
+        options: &OpenOptions,
+    ) -> Result<(), Error> {
+        if options.require_advisories && self.advisories.is_empty() {
+            fail!(
+                ErrorKind::EmptyDatabase,
+                "no advisories found in {}",
+                location.display()
+            );
+        }
+
+        for collection in &options.required_collections {
+            if !self
+                .iter()
+                .any(|advisory| advisory.collection() == Some(*collection))
+            {
+                fail!(
+                    ErrorKind::EmptyDatabase,
+                    "no advisories found in the `{}` collection of {}; \
+                     is the advisory database complete?",
+                    collection,
+                    location.display()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build a [`Database`] from the result of parsing each advisory file,
+    /// inserting them in the order given
+    fn from_parsed(
+        path: &Path,
+        parsed: impl IntoIterator<Item = (PathBuf, Result<Option<Parsed>, Error>)>,
+        options: &OpenOptions,
+    ) -> Result<Self, Error> {
         let mut advisories = Entries::new();
         let mut rust_index = Index::new();
         let mut crate_index = Index::new();
+        let mut advisory_paths = vec![];
+        let mut load_report = LoadReport::default();
+        let mut raw_sources = Map::new();
 
-        for path in &advisory_paths {
-            if let Some(slot) = advisories.load_file(path)? {
-                let advisory = advisories.get(slot).unwrap();
-                match advisory.metadata.collection.unwrap() {
-                    Collection::Crates => {
-                        crate_index.insert(&advisory.metadata.package, slot);
-                    }
-                    Collection::Rust => {
-                        rust_index.insert(&advisory.metadata.package, slot);
+        for (path, result) in parsed {
+            let inserted = result.and_then(|parsed| {
+                parsed
+                    .map(|(advisory, raw_source)| {
+                        let slot = advisories.insert(advisory, options.allow_duplicate_ids)?;
+                        Ok((slot, raw_source))
+                    })
+                    .transpose()
+            });
+
+            let slot = match inserted {
+                Ok(Some((slot, raw_source))) => {
+                    if let Some(raw_source) = raw_source {
+                        let id = advisories.get(slot).unwrap().id().clone();
+                        // Keep the first of duplicate advisories, like `Database::get`
+                        raw_sources.entry(id).or_insert(raw_source);
                     }
+                    slot
+                }
+                Ok(None) => continue,
+                Err(err) if options.skip_invalid_advisories => {
+                    load_report.errors.push((path, err));
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            advisory_paths.push(path);
+
+            let advisory = advisories.get(slot).unwrap();
+            match advisory.collection().unwrap() {
+                Collection::Crates => {
+                    crate_index.insert(&advisory.metadata.package, slot);
+                }
+                Collection::Rust => {
+                    rust_index.insert(&advisory.metadata.package, slot);
                 }
             }
         }
@@ -89,16 +392,32 @@ impl Database {
             advisories,
             crate_index,
             rust_index,
+            path: path.to_owned(),
+            advisory_paths,
+            load_report,
+            raw_sources,
             #[cfg(feature = "git")]
             latest_commit: None,
+            #[cfg(feature = "git")]
+            orphaned_advisories: vec![],
         })
     }
 
     /// Load [`Database`] from the given [`git::Repository`]
     #[cfg(feature = "git")]
     pub fn load_from_repo(repo: &git::Repository) -> Result<Self, Error> {
-        let mut db = Self::open(repo.path())?;
+        Self::load_from_repo_with_options(repo, &OpenOptions::default())
+    }
+
+    /// Load [`Database`] from the given [`git::Repository`] with the given [`OpenOptions`]
+    #[cfg(feature = "git")]
+    pub fn load_from_repo_with_options(
+        repo: &git::Repository,
+        options: &OpenOptions,
+    ) -> Result<Self, Error> {
+        let mut db = Self::open_with_options(repo.path(), options)?;
         db.latest_commit = Some(repo.latest_commit()?);
+        db.orphaned_advisories = repo.orphaned_advisories()?;
         Ok(db)
     }
 
@@ -108,11 +427,43 @@ impl Database {
         git::Repository::fetch_default_repo().and_then(|repo| Self::load_from_repo(&repo))
     }
 
-    /// Look up an advisory by an advisory ID (e.g. "RUSTSEC-YYYY-XXXX")
+    /// Look up an advisory by an advisory ID (e.g. "RUSTSEC-YYYY-XXXX").
+    ///
+    /// IDs from other databases (e.g. GHSA or CVE IDs) are resolved to the
+    /// advisory which lists them in its `aliases`.
+    ///
+    /// If the database was opened with [`OpenOptions::allow_duplicate_ids`]
+    /// and the ID is used more than once, this returns the first one loaded.
     pub fn get(&self, id: &advisory::Id) -> Option<&Advisory> {
         self.advisories.find_by_id(id)
     }
 
+    /// Look up every advisory with the given advisory ID.
+    ///
+    /// This contains more than one advisory only if the database was opened
+    /// with [`OpenOptions::allow_duplicate_ids`] and the ID is used more than once.
+    /// Aliases are resolved as in [`Database::get`].
+    pub fn get_all(&self, id: &advisory::Id) -> Vec<&Advisory> {
+        self.advisories.find_all_by_id(id)
+    }
+
+    /// Get the TOML source of an advisory exactly as it was written, e.g. to
+    /// check it by hand when auditing a finding.
+    ///
+    /// For Markdown advisories this is the front matter, without the
+    /// surrounding code fence. Sources are only retained if the database was
+    /// opened with [`OpenOptions::retain_raw_source`], and this is `None` otherwise.
+    /// Aliases and duplicate IDs are resolved as in [`Database::get`].
+    pub fn raw_source(&self, id: &advisory::Id) -> Option<&str> {
+        let id = self.canonical_id(id)?;
+        self.raw_sources.get(id).map(String::as_str)
+    }
+
+    /// Resolve an advisory ID or alias to the ID of the advisory in this database
+    pub fn canonical_id(&self, id: &advisory::Id) -> Option<&advisory::Id> {
+        self.get(id).map(|advisory| &advisory.metadata.id)
+    }
+
     /// Query the database according to the given query object
     pub fn query(&self, query: &Query) -> Vec<&Advisory> {
         // Use indexes if we know a package name and collection
@@ -138,37 +489,203 @@ impl Database {
     }
 
     /// Find vulnerabilities in the provided `Lockfile` which match a given query.
+    ///
+    /// Packages are matched by the crate name recorded in the lockfile, which
+    /// is always the real crate name even if a dependency is renamed in `Cargo.toml`.
+    ///
+    /// Each distinct (name, version, source) in the lockfile is reported
+    /// separately. When an advisory matches a package from the advisory's
+    /// source, copies of the same crate from git or path sources (e.g. a git
+    /// fork alongside the crates.io release) are also reported if their
+    /// version is affected, since they usually carry the same vulnerable code.
+    ///
+    /// Crates from other registries are never matched, since a crate of the
+    /// same name in another registry is an unrelated crate.
     pub fn query_vulnerabilities(&self, lockfile: &Lockfile, query: &Query) -> Vec<Vulnerability> {
         let mut vulns = vec![];
 
         for package in &lockfile.packages {
             let advisories = self.query(&query.clone().package(package));
 
-            vulns.extend(
-                advisories
-                    .iter()
-                    .map(|advisory| Vulnerability::new(advisory, package)),
-            );
+            for advisory in advisories {
+                vulns.push(Vulnerability::new(advisory, package));
+
+                let shadowed = lockfile.packages.iter().filter(|other| {
+                    other.name == package.name
+                        && other
+                            .source
+                            .as_ref()
+                            .is_some_and(|source| !source.is_registry())
+                        && other.source != package.source
+                        && advisory.is_version_affected(&other.version)
+                });
+
+                for other in shadowed {
+                    let vuln = Vulnerability::new(advisory, other);
+
+                    if !vulns.contains(&vuln) {
+                        vulns.push(vuln);
+                    }
+                }
+            }
         }
 
         vulns
     }
 
+    /// Find vulnerabilities in the Rust toolchain (e.g. `std` or `rustdoc`)
+    /// for the given `rustc` version which match a given query.
+    ///
+    /// Advisories in the [`Collection::Rust`] collection are matched against
+    /// `rust_version`, since every toolchain component shares its version.
+    /// Each vulnerability's package is the affected component at that version.
+    pub fn toolchain_vulnerabilities(
+        &self,
+        rust_version: &Version,
+        query: &Query,
+    ) -> Vec<Vulnerability> {
+        let query = query
+            .clone()
+            .collection(Collection::Rust)
+            .package_version(rust_version.clone());
+
+        self.query(&query)
+            .into_iter()
+            .map(|advisory| {
+                let package = Package {
+                    name: advisory.metadata.package.clone(),
+                    version: rust_version.clone(),
+                    source: None,
+                    checksum: None,
+                    dependencies: vec![],
+                    replace: None,
+                };
+
+                Vulnerability::new(advisory, &package)
+            })
+            .collect()
+    }
+
     /// Scan for vulnerabilities in the provided `Lockfile`.
     pub fn vulnerabilities(&self, lockfile: &Lockfile) -> Vec<Vulnerability> {
         self.query_vulnerabilities(lockfile, &Query::crate_scope())
     }
 
+    /// Check every advisory in the database for structural problems, such as
+    /// missing required fields or keys and values the linter doesn't accept.
+    ///
+    /// Loading only checks that each advisory parses, so this also reports
+    /// advisories which parse but are incomplete. It's intended for database
+    /// maintainers, e.g. to lint contributions before they are merged.
+    ///
+    /// Opening a database fails on the first advisory which doesn't load, so
+    /// open it with [`OpenOptions::skip_invalid_advisories`] to have those
+    /// files reported here too. Issues are sorted by the file they're in.
+    /// Advisory files are only linted for databases loaded from disk.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        validation::validate(self)
+    }
+
     /// Iterate over all of the advisories in the database
     pub fn iter(&self) -> Iter<'_> {
         self.advisories.iter()
     }
 
+    /// Local path the database was loaded from, which is empty for
+    /// databases loaded from an archive in memory
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Advisory files which were skipped because they failed to load.
+    ///
+    /// Always empty unless the database was opened with
+    /// [`OpenOptions::skip_invalid_advisories`].
+    pub fn load_report(&self) -> &LoadReport {
+        &self.load_report
+    }
+
     /// Get information about the latest commit to the repo
     #[cfg(feature = "git")]
     pub fn latest_commit(&self) -> Option<&git::Commit> {
         self.latest_commit.as_ref()
     }
+
+    /// Advisory files in the repository's checkout which aren't in its `HEAD`
+    /// commit, relative to [`Database::path`].
+    ///
+    /// These were loaded like any other advisory, but may have been removed
+    /// or withdrawn upstream, so they're a sign of a stale checkout. Always
+    /// empty unless the database was loaded with [`Database::load_from_repo`].
+    #[cfg(feature = "git")]
+    pub fn orphaned_advisories(&self) -> &[PathBuf] {
+        &self.orphaned_advisories
+    }
+}
+
+/// Type of a directory entry, with symlinks resolved if `follow_symlinks`
+/// is set, or `None` for a symlink which shouldn't be followed
+fn file_type(
+    entry: &fs::DirEntry,
+    follow_symlinks: bool,
+) -> Result<Option<std::fs::FileType>, Error> {
+    let file_type = entry.file_type()?;
+
+    if !file_type.is_symlink() {
+        Ok(Some(file_type))
+    } else if follow_symlinks {
+        Ok(Some(fs::metadata(entry.path())?.file_type()))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Find advisory files anywhere under `dir` for a flat database rooted at `root`.
+///
+/// `visited` holds the canonical paths of directories already walked, and
+/// symlinks which aren't followed are added to `skipped`.
+fn find_flat_advisories(
+    root: &Path,
+    dir: &Path,
+    follow_symlinks: bool,
+    visited: &mut HashSet<PathBuf>,
+    paths: &mut Vec<PathBuf>,
+    skipped: &mut Vec<PathBuf>,
+) -> Result<(), Error> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let entry_path = entry.path();
+        let file_name = entry.file_name();
+
+        // skip dotfiles like .DS_Store, and the .git directory
+        if file_name.to_string_lossy().starts_with('.') {
+            continue;
+        }
+
+        let Some(file_type) = file_type(&entry, follow_symlinks)? else {
+            skipped.push(entry_path);
+            continue;
+        };
+
+        if file_type.is_dir() {
+            // A followed symlink may lead back to a directory already walked
+            if visited.insert(fs::canonicalize(&entry_path)?) {
+                find_flat_advisories(root, &entry_path, follow_symlinks, visited, paths, skipped)?;
+            }
+            continue;
+        }
+
+        if dir == root && file_name == SCHEMA_FILE {
+            continue;
+        }
+
+        let extension = entry_path.extension().and_then(|ext| ext.to_str());
+        if matches!(extension, Some("md") | Some("toml")) {
+            paths.push(entry_path);
+        }
+    }
+
+    Ok(())
 }
 
 impl IntoIterator for Database {
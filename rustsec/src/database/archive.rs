@@ -0,0 +1,132 @@
+//! Advisory databases loaded from archives in memory
+
+use super::SCHEMA_FILE;
+use crate::{
+    collection::Collection,
+    error::{Error, ErrorKind},
+};
+use std::{
+    ffi::OsStr,
+    io::{self, Read},
+    path::{Component, Path, PathBuf},
+};
+
+/// Format of an advisory database archive passed to
+/// [`Database::from_archive_bytes`](super::Database::from_archive_bytes)
+#[cfg_attr(docsrs, doc(cfg(feature = "archive")))]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ArchiveFormat {
+    /// Uncompressed tarball (`.tar`)
+    Tar,
+
+    /// Gzip-compressed tarball (`.tar.gz` or `.tgz`), e.g. as downloaded
+    /// from GitHub
+    TarGz,
+}
+
+impl ArchiveFormat {
+    /// Description of this format for error messages
+    fn description(self) -> &'static str {
+        match self {
+            Self::Tar => "tar",
+            Self::TarGz => "gzip-compressed tar",
+        }
+    }
+}
+
+/// Regular files in an archive, with paths relative to the root of the
+/// database in it.
+///
+/// Tarballs whose entries are all inside one directory (like the
+/// `advisory-db-main/` of GitHub tarballs) have it as their root.
+pub(super) fn files(bytes: &[u8], format: ArchiveFormat) -> Result<Vec<(PathBuf, Vec<u8>)>, Error> {
+    let files = match format {
+        ArchiveFormat::Tar => tar_files(bytes),
+        ArchiveFormat::TarGz => tar_files(flate2::read::MultiGzDecoder::new(bytes)),
+    };
+    let mut files = files.map_err(|err| {
+        Error::with_source(
+            ErrorKind::Parse,
+            format!("invalid {} archive", format.description()),
+            err,
+        )
+    })?;
+
+    let first_dir = |path: &Path| {
+        let mut components = path.components();
+        let first = components.next()?;
+        components.next().map(|_| first.as_os_str().to_owned())
+    };
+
+    if let Some(root) = files.first().and_then(|(path, _)| first_dir(path)) {
+        let is_collection = Collection::all()
+            .iter()
+            .any(|collection| root == collection.as_str());
+        if !is_collection
+            && files
+                .iter()
+                .all(|(path, _)| first_dir(path).as_ref() == Some(&root))
+        {
+            for (path, _) in &mut files {
+                *path = path.strip_prefix(&root).unwrap().to_owned();
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Is the file at `path` in an archive an advisory to load?
+///
+/// These are the files [`Database::open_with_options`](super::Database::open_with_options)
+/// would load from the same layout on disk.
+pub(super) fn is_advisory(path: &Path, flat_layout: bool) -> bool {
+    // skip dotfiles like .DS_Store, and anything in dot directories like .git
+    if path
+        .iter()
+        .any(|component| component.to_string_lossy().starts_with('.'))
+    {
+        return false;
+    }
+
+    if flat_layout {
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        path != Path::new(SCHEMA_FILE) && matches!(extension, Some("md") | Some("toml"))
+    } else {
+        let collection = path.iter().next();
+        path.iter().count() == 3
+            && Collection::all()
+                .iter()
+                .any(|c| collection == Some(OsStr::new(c.as_str())))
+    }
+}
+
+/// Regular files in a tarball, by their path in it
+fn tar_files(reader: impl Read) -> io::Result<Vec<(PathBuf, Vec<u8>)>> {
+    let mut files = vec![];
+
+    // Entries take their names from GNU long name and pax extended headers
+    for entry in tar::Archive::new(reader).entries()? {
+        let mut entry = entry?;
+
+        // Directories, links and anything else don't contain advisories
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        let path = relative_path(&entry.path()?);
+        let mut contents = vec![];
+        entry.read_to_end(&mut contents)?;
+        files.push((path, contents));
+    }
+
+    Ok(files)
+}
+
+/// Path of an entry without any leading `/` or `./`
+fn relative_path(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| matches!(component, Component::Normal(_)))
+        .collect()
+}
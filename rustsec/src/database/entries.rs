@@ -5,13 +5,17 @@ use crate::{
     advisory::{self, Advisory},
     collection::Collection,
     error::{Error, ErrorKind},
-    map, Map,
+    fs, map, Map,
 };
 use std::{
     ffi::{OsStr, OsString},
     path::Path,
 };
 
+/// An advisory parsed from a file, along with its TOML source if it was
+/// retained (see [`OpenOptions::retain_raw_source`](super::OpenOptions::retain_raw_source))
+pub(crate) type Parsed = (Advisory, Option<String>);
+
 /// "Slots" identify the location in the entries table where a particular
 /// advisory is located.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -20,8 +24,11 @@ pub(crate) struct Slot(usize);
 /// Entries in the advisory database
 #[derive(Debug, Default)]
 pub(crate) struct Entries {
-    /// Index of advisory IDs to their slots
-    index: Map<advisory::Id, Slot>,
+    /// Index of advisory IDs to their slots (more than one if duplicates are allowed)
+    index: Map<advisory::Id, Vec<Slot>>,
+
+    /// Index of advisory aliases (e.g. GHSA or CVE IDs) to the advisory they alias
+    aliases: Map<advisory::Id, Slot>,
 
     /// Advisory collection
     advisories: Vec<Advisory>,
@@ -33,11 +40,39 @@ impl Entries {
         Self::default()
     }
 
-    /// Load an advisory from a file and insert it into the database entry table
+    /// Load an advisory from a file, checking it's in the right place in the database.
+    ///
+    /// Returns `None` for placeholder advisories, which are parsed but not
+    /// inserted into the database. This doesn't touch the entry table, so
+    /// files can be parsed concurrently before being inserted in order.
     // TODO(tarcieri): factor more of this into `advisory.rs`?
-    pub fn load_file(&mut self, path: &Path) -> Result<Option<Slot>, Error> {
-        let mut advisory = Advisory::load_file(path)?;
+    pub fn parse_file(path: &Path, retain_raw_source: bool) -> Result<Option<Parsed>, Error> {
+        let (advisory, raw_source) = load_file(path, retain_raw_source)?;
+        Ok(Self::locate(path, advisory)?.map(|advisory| (advisory, raw_source)))
+    }
 
+    /// Load an advisory from the contents of the file at `path`, e.g. in an
+    /// archive, checking its location like [`Entries::parse_file`] (or
+    /// [`Entries::parse_flat_file`] if `flat_layout` is set).
+    pub fn parse_contents(
+        path: &Path,
+        contents: String,
+        retain_raw_source: bool,
+        flat_layout: bool,
+    ) -> Result<Option<Parsed>, Error> {
+        let (advisory, raw_source) = parse(path, contents, retain_raw_source)?;
+
+        let advisory = if flat_layout {
+            Self::locate_flat(advisory)
+        } else {
+            Self::locate(path, advisory)?
+        };
+        Ok(advisory.map(|advisory| (advisory, raw_source)))
+    }
+
+    /// Check an advisory loaded from `path` is in the right place in the
+    /// database, and set its collection from that place
+    fn locate(path: &Path, mut advisory: Advisory) -> Result<Option<Advisory>, Error> {
         // TODO(tarcieri): deprecate and remove legacy TOML-based advisory format
         let expected_filename = match path.extension().and_then(|ext| ext.to_str()) {
             Some("md") => OsString::from(format!("{}.md", advisory.metadata.id)),
@@ -121,25 +156,77 @@ impl Entries {
             return Ok(None);
         }
 
+        Ok(Some(advisory))
+    }
+
+    /// Load an advisory from a file in a flat database, where advisories
+    /// aren't sorted into collection and package directories.
+    ///
+    /// The package comes from the advisory itself, and the collection
+    /// defaults to [`Collection::Crates`] if the advisory doesn't set one.
+    pub fn parse_flat_file(path: &Path, retain_raw_source: bool) -> Result<Option<Parsed>, Error> {
+        let (advisory, raw_source) = load_file(path, retain_raw_source)?;
+        Ok(Self::locate_flat(advisory).map(|advisory| (advisory, raw_source)))
+    }
+
+    /// Set the collection of an advisory from a flat database
+    fn locate_flat(mut advisory: Advisory) -> Option<Advisory> {
+        if advisory.metadata.collection.is_none() {
+            advisory.metadata.collection = Some(Collection::Crates);
+        }
+
+        if advisory.metadata.id.is_placeholder() {
+            return None;
+        }
+
+        Some(advisory)
+    }
+
+    /// Insert an advisory into the database entry table.
+    ///
+    /// Fails if an advisory with the same ID was already loaded, unless `allow_duplicates` is set.
+    pub fn insert(&mut self, advisory: Advisory, allow_duplicates: bool) -> Result<Slot, Error> {
         let id = advisory.metadata.id.clone();
         let slot = Slot(self.advisories.len());
-        self.advisories.push(advisory);
 
         match self.index.entry(id) {
             map::Entry::Vacant(entry) => {
-                entry.insert(slot);
+                entry.insert(vec![slot]);
             }
-            map::Entry::Occupied(entry) => {
-                fail!(ErrorKind::Parse, "duplicate advisory ID: {}", entry.key())
+            map::Entry::Occupied(mut entry) => {
+                if !allow_duplicates {
+                    fail!(ErrorKind::Parse, "duplicate advisory ID: {}", entry.key())
+                }
+                entry.get_mut().push(slot);
             }
         }
 
-        Ok(Some(slot))
+        for alias in &advisory.metadata.aliases {
+            self.aliases.entry(alias.clone()).or_insert(slot);
+        }
+
+        self.advisories.push(advisory);
+        Ok(slot)
     }
 
-    /// Find an advisory by its `advisory::Id`
+    /// Find an advisory by its `advisory::Id` or one of its aliases
     pub fn find_by_id(&self, id: &advisory::Id) -> Option<&Advisory> {
-        self.index.get(id).and_then(|slot| self.get(*slot))
+        self.find_all_by_id(id).into_iter().next()
+    }
+
+    /// Find every advisory with the given `advisory::Id`.
+    ///
+    /// If no advisory has this ID, the advisory it's an alias of is returned.
+    pub fn find_all_by_id(&self, id: &advisory::Id) -> Vec<&Advisory> {
+        match self.index.get(id) {
+            Some(slots) => slots.iter().filter_map(|slot| self.get(*slot)).collect(),
+            None => self
+                .aliases
+                .get(id)
+                .and_then(|slot| self.get(*slot))
+                .into_iter()
+                .collect(),
+        }
     }
 
     /// Get an advisory from the database by its [`Slot`]
@@ -147,12 +234,36 @@ impl Entries {
         self.advisories.get(slot.0)
     }
 
+    /// Does the database have no entries?
+    pub fn is_empty(&self) -> bool {
+        self.advisories.is_empty()
+    }
+
     /// Iterate over all of the entries in the database
     pub fn iter(&self) -> Iter<'_> {
         self.advisories.iter()
     }
 }
 
+/// Load an advisory file, keeping its TOML source if `retain_raw_source`
+fn load_file(path: &Path, retain_raw_source: bool) -> Result<Parsed, Error> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format_err!(ErrorKind::Io, "couldn't open {}: {}", path.display(), e))?;
+
+    parse(path, contents, retain_raw_source)
+}
+
+/// Parse the contents of an advisory file, keeping its TOML source if `retain_raw_source`
+fn parse(path: &Path, contents: String, retain_raw_source: bool) -> Result<Parsed, Error> {
+    let advisory = Advisory::parse_file_contents(path, &contents)?;
+    let raw_source = if retain_raw_source {
+        Some(Advisory::toml_source(path, contents)?)
+    } else {
+        None
+    };
+    Ok((advisory, raw_source))
+}
+
 impl IntoIterator for Entries {
     type Item = Advisory;
 
@@ -6,8 +6,11 @@ use super::entries::Slot;
 use crate::{map, package, Map, Set};
 
 /// Database index which maps package names to a set of advisory IDs
+///
+/// Names are stored normalized, so lookups for `foo-bar` and `foo_bar`
+/// find the same advisories.
 #[derive(Debug, Default)]
-pub(crate) struct Index(Map<package::Name, Set<Slot>>);
+pub(crate) struct Index(Map<String, Set<Slot>>);
 
 impl Index {
     /// Create a new index
@@ -17,7 +20,7 @@ impl Index {
 
     /// Insert an entry into the index
     pub fn insert(&mut self, key: &package::Name, slot: Slot) -> bool {
-        let values = match self.0.entry(key.clone()) {
+        let values = match self.0.entry(normalize(key)) {
             map::Entry::Vacant(entry) => entry.insert(Set::new()),
             map::Entry::Occupied(entry) => entry.into_mut(),
         };
@@ -27,6 +30,22 @@ impl Index {
 
     /// Get an iterator over advisory IDs for a given package name
     pub fn get(&self, key: &package::Name) -> Option<Iter<'_, Slot>> {
-        self.0.get(key).map(|set| set.iter())
+        self.0.get(&normalize(key)).map(|set| set.iter())
     }
 }
+
+/// Do two package names refer to the same crate?
+///
+/// Like Cargo and crates.io, this treats `-` and `_` as equivalent.
+pub(crate) fn names_match(a: &package::Name, b: &package::Name) -> bool {
+    let (a, b) = (a.as_str().as_bytes(), b.as_str().as_bytes());
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|(x, y)| x == y || (matches!(x, b'-' | b'_') && matches!(y, b'-' | b'_')))
+}
+
+/// Normalize a package name for use as an index key
+fn normalize(name: &package::Name) -> String {
+    name.as_str().replace('-', "_")
+}
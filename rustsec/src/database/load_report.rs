@@ -0,0 +1,26 @@
+//! Advisories which couldn't be loaded into a database
+
+use crate::error::Error;
+use std::path::PathBuf;
+
+/// Advisory files skipped while loading a [`Database`](super::Database).
+///
+/// Errors are listed in the same (sorted) order as the files they came from.
+#[derive(Debug, Default)]
+pub struct LoadReport {
+    /// Files which failed to load, along with the reason why, for databases
+    /// opened with [`OpenOptions::skip_invalid_advisories`](super::OpenOptions::skip_invalid_advisories)
+    pub errors: Vec<(PathBuf, Error)>,
+
+    /// Symlinked advisory files and package directories which weren't
+    /// loaded, since [`OpenOptions::follow_symlinks`](super::OpenOptions::follow_symlinks)
+    /// isn't set
+    pub skipped_symlinks: Vec<PathBuf>,
+}
+
+impl LoadReport {
+    /// Were all advisory files loaded successfully?
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
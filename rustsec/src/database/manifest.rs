@@ -0,0 +1,237 @@
+//! Signed manifests of advisory file hashes
+
+use crate::{
+    error::{Error, ErrorKind},
+    fs, Map,
+};
+use ring::{
+    digest,
+    signature::{UnparsedPublicKey, ED25519},
+};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt,
+    path::{Component, Path, PathBuf},
+    str::{self, FromStr},
+};
+
+/// Name of the file at the root of an advisory database listing the SHA-256
+/// hash of each of its files, in the format written by `sha256sum`
+pub const MANIFEST_FILE: &str = "advisories.sha256";
+
+/// Name of the file next to the [`MANIFEST_FILE`] holding its hex-encoded
+/// Ed25519 signature
+pub const MANIFEST_SIGNATURE_FILE: &str = "advisories.sha256.sig";
+
+/// Ed25519 public key which an advisory database's manifest is signed with.
+///
+/// Parsed from and displayed as 64 hex digits.
+#[cfg_attr(docsrs, doc(cfg(feature = "manifest-signature")))]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ManifestKey([u8; 32]);
+
+impl ManifestKey {
+    /// Create a key from its raw bytes
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Get the raw bytes of this key
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl FromStr for ManifestKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        decode_hex(s)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(Self)
+            .ok_or_else(|| {
+                format_err!(
+                    ErrorKind::Parse,
+                    "invalid manifest key {:?}: expected 64 hex digits",
+                    s
+                )
+            })
+    }
+}
+
+impl fmt::Display for ManifestKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for ManifestKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for ManifestKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Check the manifest of the database at `path` is signed by `key`, and
+/// that the files it lists, including every one of `advisory_paths`, have
+/// the hashes it records.
+///
+/// Anything short of that fails, including a missing manifest or signature,
+/// since an attacker able to tamper with the files could also remove them.
+///
+/// Returns the contents of each of `advisory_paths` as they were hashed, so
+/// they can be parsed without reading the files again after checking them.
+pub(super) fn verify(
+    path: &Path,
+    key: &ManifestKey,
+    advisory_paths: &[PathBuf],
+) -> Result<Vec<Vec<u8>>, Error> {
+    let manifest_path = path.join(MANIFEST_FILE);
+    let signature_path = path.join(MANIFEST_SIGNATURE_FILE);
+
+    let manifest = read(&manifest_path)?;
+    let signature = read(&signature_path)?;
+    let signature = str::from_utf8(&signature)
+        .ok()
+        .and_then(|signature| decode_hex(signature.trim()))
+        .ok_or_else(|| {
+            format_err!(
+                ErrorKind::Verification,
+                "malformed signature in {}",
+                signature_path.display()
+            )
+        })?;
+
+    UnparsedPublicKey::new(&ED25519, key.as_bytes())
+        .verify(&manifest, &signature)
+        .map_err(|_| {
+            format_err!(
+                ErrorKind::Verification,
+                "{} isn't signed by manifest key {}",
+                manifest_path.display(),
+                key
+            )
+        })?;
+
+    let hashes = str::from_utf8(&manifest)
+        .ok()
+        .and_then(parse)
+        .ok_or_else(|| {
+            format_err!(
+                ErrorKind::Verification,
+                "malformed manifest {}",
+                manifest_path.display()
+            )
+        })?;
+
+    for advisory_path in advisory_paths {
+        if !hashes.contains_key(&relative_name(path, advisory_path)) {
+            fail!(
+                ErrorKind::Verification,
+                "{} isn't listed in {}",
+                advisory_path.display(),
+                manifest_path.display()
+            );
+        }
+    }
+
+    let mut contents = Map::new();
+    for (name, hash) in &hashes {
+        let file_path = path.join(name);
+        let data = read(&file_path)?;
+        if digest::digest(&digest::SHA256, &data).as_ref() != hash.as_slice() {
+            fail!(
+                ErrorKind::Verification,
+                "{} doesn't match its hash in {}",
+                file_path.display(),
+                manifest_path.display()
+            );
+        }
+        contents.insert(name.as_str(), data);
+    }
+
+    Ok(advisory_paths
+        .iter()
+        .map(|advisory_path| {
+            contents
+                .remove(relative_name(path, advisory_path).as_str())
+                .expect("advisory files were checked to be listed")
+        })
+        .collect())
+}
+
+/// Read a file the manifest covers, which must exist
+fn read(path: &Path) -> Result<Vec<u8>, Error> {
+    fs::read(path).map_err(|err| {
+        Error::with_source(
+            ErrorKind::Verification,
+            format!("couldn't read {}", path.display()),
+            err,
+        )
+    })
+}
+
+/// Parse `sha256sum` output into hashes by file name, or `None` if it's
+/// malformed.
+///
+/// File names must stay inside the database, so names with `..` or other
+/// components besides plain file and directory names are malformed.
+fn parse(manifest: &str) -> Option<Map<String, Vec<u8>>> {
+    let mut hashes = Map::new();
+
+    for line in manifest.lines().filter(|line| !line.trim().is_empty()) {
+        let (hash, name) = line.split_once(' ')?;
+        // `sha256sum --binary` marks file names with a `*`
+        let name = name.strip_prefix([' ', '*'])?;
+        let name = name.strip_prefix("./").unwrap_or(name);
+        if !Path::new(name)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return None;
+        }
+        let hash = decode_hex(hash).filter(|hash| hash.len() == digest::SHA256_OUTPUT_LEN)?;
+        hashes.insert(name.to_owned(), hash);
+    }
+
+    Some(hashes)
+}
+
+/// Name of a file in the database as listed in its manifest, i.e. relative
+/// to the database root with `/` separators
+fn relative_name(root: &Path, path: &Path) -> String {
+    let mut name = String::new();
+
+    for component in path.strip_prefix(root).unwrap_or(path).components() {
+        if let Component::Normal(component) = component {
+            if !name.is_empty() {
+                name.push('/');
+            }
+            name.push_str(&component.to_string_lossy());
+        }
+    }
+
+    name
+}
+
+/// Decode a string of hex digits, or `None` if it isn't one
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
@@ -0,0 +1,185 @@
+//! Options controlling how an advisory database is loaded from disk
+
+use crate::collection::Collection;
+
+/// Options for [`Database::open_with_options`](super::Database::open_with_options).
+///
+/// The defaults match [`Database::open`](super::Database::open).
+#[derive(Clone, Debug)]
+pub struct OpenOptions {
+    /// Fail if no advisories were loaded
+    pub(super) require_advisories: bool,
+
+    /// Collections which must each contain at least one advisory
+    pub(super) required_collections: Vec<Collection>,
+
+    /// Load advisories whose IDs are already in the database instead of failing
+    pub(super) allow_duplicate_ids: bool,
+
+    /// Fail if the database declares a newer schema than is supported
+    pub(super) check_schema_version: bool,
+
+    /// Record advisories which fail to load in the [`LoadReport`](super::LoadReport) instead of failing
+    pub(super) skip_invalid_advisories: bool,
+
+    /// Load every advisory file under the database path, ignoring directory layout
+    pub(super) flat_layout: bool,
+
+    /// Follow symlinked advisory files and directories
+    pub(super) follow_symlinks: bool,
+
+    /// Keep the TOML source of each advisory
+    pub(super) retain_raw_source: bool,
+
+    /// Parse advisory files on the rayon thread pool
+    #[cfg(feature = "rayon")]
+    pub(super) parallel: bool,
+
+    /// Key the database's manifest must be signed with
+    #[cfg(feature = "manifest-signature")]
+    pub(super) manifest_key: Option<super::ManifestKey>,
+}
+
+impl OpenOptions {
+    /// Create the default set of options
+    pub fn new() -> Self {
+        Self {
+            require_advisories: false,
+            required_collections: vec![],
+            allow_duplicate_ids: false,
+            check_schema_version: true,
+            skip_invalid_advisories: false,
+            flat_layout: false,
+            follow_symlinks: false,
+            retain_raw_source: false,
+            #[cfg(feature = "rayon")]
+            parallel: true,
+            #[cfg(feature = "manifest-signature")]
+            manifest_key: None,
+        }
+    }
+
+    /// Fail with [`ErrorKind::EmptyDatabase`](crate::ErrorKind::EmptyDatabase)
+    /// if the database doesn't contain any advisories.
+    ///
+    /// A database path which doesn't contain any advisories is almost always
+    /// misconfigured, and would otherwise cause every scan to come up clean.
+    /// This is opt-in so intentionally empty custom databases keep working.
+    pub fn require_advisories(mut self, setting: bool) -> Self {
+        self.require_advisories = setting;
+        self
+    }
+
+    /// Fail with [`ErrorKind::EmptyDatabase`](crate::ErrorKind::EmptyDatabase)
+    /// if any of the given collections doesn't contain any advisories.
+    ///
+    /// A database missing e.g. the `crates` collection entirely, like a
+    /// broken mirror, would otherwise report every crate as clean even if
+    /// [`OpenOptions::require_advisories`] is satisfied by other collections.
+    pub fn require_collections(
+        mut self,
+        collections: impl IntoIterator<Item = Collection>,
+    ) -> Self {
+        self.required_collections.extend(collections);
+        self
+    }
+
+    /// Load advisories whose ID is already used by another advisory, rather
+    /// than failing with a "duplicate advisory ID" error.
+    ///
+    /// This is useful for databases merged from several sources, where
+    /// conflicts can be found with [`Database::get_all`](super::Database::get_all).
+    pub fn allow_duplicate_ids(mut self, setting: bool) -> Self {
+        self.allow_duplicate_ids = setting;
+        self
+    }
+
+    /// Fail with [`ErrorKind::UnsupportedSchema`](crate::ErrorKind::UnsupportedSchema)
+    /// if the database's [`SCHEMA_FILE`](super::SCHEMA_FILE) declares a newer
+    /// version than [`SUPPORTED_SCHEMA_VERSION`](super::SUPPORTED_SCHEMA_VERSION)
+    /// (default: true).
+    ///
+    /// Newer schemas may add fields this version doesn't know about, so
+    /// reading them could silently miss vulnerabilities. Databases without
+    /// a schema file are always accepted.
+    pub fn check_schema_version(mut self, setting: bool) -> Self {
+        self.check_schema_version = setting;
+        self
+    }
+
+    /// Skip advisory files which fail to load, recording why in the
+    /// database's [`LoadReport`](super::LoadReport), rather than failing
+    /// on the first one (default: false).
+    ///
+    /// This keeps a database with a few malformed entries usable, at the
+    /// cost of missing whatever vulnerabilities those entries describe.
+    pub fn skip_invalid_advisories(mut self, setting: bool) -> Self {
+        self.skip_invalid_advisories = setting;
+        self
+    }
+
+    /// Load every `*.md` and `*.toml` advisory file found anywhere under the
+    /// database path, rather than expecting the `crates/<name>/` layout of
+    /// the upstream advisory database (default: false).
+    ///
+    /// This supports databases which are just a directory of advisories. The
+    /// package name is taken from each advisory's contents, and advisories
+    /// which don't set a `collection` are treated as crate advisories.
+    /// The [`SCHEMA_FILE`](super::SCHEMA_FILE) and dotfiles are skipped.
+    pub fn flat_layout(mut self, setting: bool) -> Self {
+        self.flat_layout = setting;
+        self
+    }
+
+    /// Load advisory files and package directories which are symlinks,
+    /// rather than skipping them (default: false).
+    ///
+    /// Directories are only walked once, so symlinks which point back into
+    /// the database can't cause the load to loop.
+    pub fn follow_symlinks(mut self, setting: bool) -> Self {
+        self.follow_symlinks = setting;
+        self
+    }
+
+    /// Keep the TOML source of each advisory, which is available from
+    /// [`Database::raw_source`](crate::Database::raw_source) (default: false).
+    ///
+    /// This is for auditing the advisories behind findings, and is opt-in
+    /// since it keeps a copy of every advisory's source in memory.
+    pub fn retain_raw_source(mut self, setting: bool) -> Self {
+        self.retain_raw_source = setting;
+        self
+    }
+
+    /// Parse advisory files in parallel on the rayon thread pool (default: true).
+    ///
+    /// The resulting database is identical to one loaded serially: files are
+    /// always inserted in sorted path order once they've been parsed.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn parallel(mut self, setting: bool) -> Self {
+        self.parallel = setting;
+        self
+    }
+
+    /// Before loading any advisories, check the database's
+    /// [`MANIFEST_FILE`](super::MANIFEST_FILE) is signed by `key` and lists
+    /// every advisory file with its SHA-256 hash.
+    ///
+    /// Loading fails with [`ErrorKind::Verification`](crate::ErrorKind::Verification)
+    /// if the manifest or its signature is missing, the signature doesn't
+    /// match, or any file differs from the manifest, so a tampered snapshot
+    /// of the database is never used.
+    #[cfg(feature = "manifest-signature")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "manifest-signature")))]
+    pub fn verify_manifest(mut self, key: super::ManifestKey) -> Self {
+        self.manifest_key = Some(key);
+        self
+    }
+}
+
+impl Default for OpenOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
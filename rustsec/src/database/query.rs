@@ -1,7 +1,8 @@
 //! Queries against the RustSec database
 //!
+use super::index::names_match;
 use crate::{
-    advisory::{Advisory, Severity},
+    advisory::{Advisory, CvssPreference, Date, Severity},
     collection::Collection,
     package::{self, Package},
     SourceId,
@@ -27,6 +28,9 @@ pub struct Query {
     /// Severity threshold (i.e. minimum severity)
     severity: Option<Severity>,
 
+    /// CVSS version the severity is taken from
+    cvss_preference: CvssPreference,
+
     /// Target architecture
     target_arch: Vec<Arch>,
 
@@ -43,6 +47,12 @@ pub struct Query {
 
     /// Query for informational advisories
     informational: Option<bool>,
+
+    /// Cargo features enabled for the package (if known)
+    enabled_features: Option<Vec<String>>,
+
+    /// Only match advisories as they stood on this date
+    as_of: Option<Date>,
 }
 
 impl Query {
@@ -62,11 +72,14 @@ impl Query {
             package_version: None,
             package_source: None,
             severity: None,
+            cvss_preference: CvssPreference::default(),
             target_arch: Default::default(),
             target_os: Default::default(),
             year: None,
             withdrawn: None,
             informational: None,
+            enabled_features: None,
+            as_of: None,
         }
     }
 
@@ -125,6 +138,13 @@ impl Query {
         self
     }
 
+    /// Set which CVSS version's vector the severity is taken from, for
+    /// advisories with more than one (default: the highest version)
+    pub fn cvss_preference(mut self, preference: CvssPreference) -> Self {
+        self.cvss_preference = preference;
+        self
+    }
+
     /// Set target architectures
     pub fn target_arch(mut self, arch: Vec<Arch>) -> Self {
         self.target_arch = arch;
@@ -158,22 +178,48 @@ impl Query {
         self
     }
 
+    /// Set the cargo features enabled for the package.
+    ///
+    /// Advisories which only affect the crate when certain features are
+    /// enabled won't match unless one of them is in this list. If this isn't
+    /// set, the enabled features are unknown and such advisories always match.
+    pub fn enabled_features(mut self, features: Vec<String>) -> Self {
+        self.enabled_features = Some(features);
+        self
+    }
+
+    /// Query the database as it stood on the given date, e.g. to find what
+    /// an audit would have reported on the day of a release.
+    ///
+    /// Advisories dated after this date don't match, and advisories
+    /// withdrawn after it are treated as not (yet) withdrawn.
+    ///
+    /// An advisory's date is when the issue was reported, not when the
+    /// advisory was added to the database, which is often days or weeks
+    /// later. So this can match advisories which weren't in the database yet
+    /// on the given date. To see exactly what the database held on a date,
+    /// check out a commit from that date (e.g. with `Repository::checkout_commit`).
+    pub fn as_of(mut self, date: Date) -> Self {
+        self.as_of = Some(date);
+        self
+    }
+
     /// Does this query match a given advisory?
     pub fn matches(&self, advisory: &Advisory) -> bool {
         if let Some(collection) = self.collection {
-            if Some(collection) != advisory.metadata.collection {
+            if Some(collection) != advisory.collection() {
                 return false;
             }
         }
 
         if let Some(package_name) = &self.package_name {
-            if package_name != &advisory.metadata.package {
+            if !names_match(package_name, &advisory.metadata.package) {
                 return false;
             }
         }
 
         if let Some(package_version) = &self.package_version {
-            if !advisory.versions.is_vulnerable(package_version) {
+            if !advisory.is_version_affected(package_version) {
                 return false;
             }
         }
@@ -186,16 +232,22 @@ impl Query {
                 .cloned()
                 .unwrap_or_default();
 
+            // crates.io is the same registry whether its index is read
+            // through git or the sparse protocol
+            let both_crates_io =
+                advisory_source.is_default_registry() && package_source.is_default_registry();
+
             // TODO(tarcieri): better source comparison?
-            if advisory_source.kind() != package_source.kind()
-                || advisory_source.url() != package_source.url()
+            if !both_crates_io
+                && (advisory_source.kind() != package_source.kind()
+                    || advisory_source.url() != package_source.url())
             {
                 return false;
             }
         }
 
         if let Some(severity_threshold) = self.severity {
-            if let Some(advisory_severity) = advisory.severity() {
+            if let Some(advisory_severity) = advisory.severity_with(self.cvss_preference) {
                 if advisory_severity < severity_threshold {
                     return false;
                 }
@@ -222,6 +274,16 @@ impl Query {
             {
                 return false;
             }
+
+            if !affected.cfg_matches(&self.target_arch, &self.target_os) {
+                return false;
+            }
+
+            if let Some(enabled_features) = &self.enabled_features {
+                if !affected.features_enabled(enabled_features) {
+                    return false;
+                }
+            }
         }
 
         if let Some(query_year) = self.year {
@@ -232,8 +294,19 @@ impl Query {
             }
         }
 
+        if let Some(as_of) = &self.as_of {
+            if &advisory.metadata.date > as_of {
+                return false;
+            }
+        }
+
         if let Some(withdrawn) = self.withdrawn {
-            if withdrawn != advisory.metadata.withdrawn.is_some() {
+            let was_withdrawn = match (&advisory.metadata.withdrawn, &self.as_of) {
+                (Some(date), Some(as_of)) => date <= as_of,
+                (date, _) => date.is_some(),
+            };
+
+            if withdrawn != was_withdrawn {
                 return false;
             }
         }
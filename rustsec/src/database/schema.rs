@@ -0,0 +1,59 @@
+//! Advisory database schema version marker
+
+use crate::{
+    error::{Error, ErrorKind},
+    fs,
+};
+use serde::Deserialize;
+use std::path::Path;
+
+/// Name of the file at the root of an advisory database declaring its schema
+pub const SCHEMA_FILE: &str = "schema.toml";
+
+/// Newest advisory database schema version this crate can read
+pub const SUPPORTED_SCHEMA_VERSION: u32 = 1;
+
+/// Contents of the schema file
+#[derive(Debug, Deserialize)]
+struct Schema {
+    /// Version of the database schema
+    version: u32,
+}
+
+/// Fail if the database at `path` declares a newer schema than is supported.
+///
+/// Databases without a schema file predate the marker and are always accepted.
+pub(super) fn check(path: &Path) -> Result<(), Error> {
+    let schema_path = path.join(SCHEMA_FILE);
+    if !schema_path.exists() {
+        return Ok(());
+    }
+
+    check_contents(&fs::read_to_string(&schema_path)?, &schema_path, path)
+}
+
+/// Fail if `contents` of the schema file at `schema_path`, in the database
+/// at `path`, declare a newer schema than is supported
+pub(super) fn check_contents(contents: &str, schema_path: &Path, path: &Path) -> Result<(), Error> {
+    let schema: Schema = toml::from_str(contents).map_err(|err| {
+        format_err!(
+            ErrorKind::Parse,
+            "invalid schema file {}: {}",
+            schema_path.display(),
+            err
+        )
+    })?;
+
+    if schema.version > SUPPORTED_SCHEMA_VERSION {
+        fail!(
+            ErrorKind::UnsupportedSchema,
+            "advisory database at {} uses schema version {}, but only versions up to {} \
+             are supported; please upgrade to a newer release",
+            path.display(),
+            schema.version,
+            SUPPORTED_SCHEMA_VERSION
+        );
+    }
+
+    Ok(())
+}
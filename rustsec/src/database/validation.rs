@@ -0,0 +1,136 @@
+//! Structural validation of the advisories in a database
+
+use super::Database;
+use crate::{
+    advisory::{self, linter::Linter, Advisory},
+    collection::Collection,
+};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// Longest crate name crates.io accepts
+const MAX_CRATE_NAME_LEN: usize = 64;
+
+/// Problem found with an advisory by [`Database::validate`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationIssue {
+    /// File the advisory was loaded from.
+    ///
+    /// For databases loaded from an archive, this is the path in the archive.
+    pub path: PathBuf,
+
+    /// ID of the advisory with the problem, or `None` if the file couldn't
+    /// be loaded at all
+    pub id: Option<advisory::Id>,
+
+    /// Description of the problem
+    pub message: String,
+}
+
+impl ValidationIssue {
+    /// Create a new issue for the given advisory
+    fn new(path: &Path, advisory: &Advisory, message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_owned(),
+            id: Some(advisory.id().clone()),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.id {
+            Some(id) => write!(f, "{}: {}", id, self.message),
+            None => write!(f, "{}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+/// Check every advisory file `db` was loaded from
+pub(super) fn validate(db: &Database) -> Vec<ValidationIssue> {
+    let mut issues: Vec<_> = db
+        .load_report
+        .errors
+        .iter()
+        .map(|(path, err)| ValidationIssue {
+            path: path.clone(),
+            id: None,
+            message: err.to_string(),
+        })
+        .collect();
+
+    // Databases loaded from an archive have no files on disk to lint
+    let on_disk = !db.path().as_os_str().is_empty();
+
+    for (advisory, path) in db.iter().zip(&db.advisory_paths) {
+        validate_advisory(path, advisory, on_disk, &mut issues);
+    }
+
+    issues.sort_by(|a, b| a.path.cmp(&b.path));
+    issues
+}
+
+/// Check a single advisory loaded from `path`, appending any problems found to `issues`
+fn validate_advisory(
+    path: &Path,
+    advisory: &Advisory,
+    lint: bool,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if advisory.title().is_empty() {
+        issues.push(ValidationIssue::new(
+            path,
+            advisory,
+            "missing required field `title`",
+        ));
+    }
+
+    if advisory.description().is_empty() {
+        issues.push(ValidationIssue::new(
+            path,
+            advisory,
+            "missing required field `description`",
+        ));
+    }
+
+    let package = advisory.metadata.package.as_str();
+    if package.trim().is_empty() {
+        issues.push(ValidationIssue::new(
+            path,
+            advisory,
+            "missing required field `package`",
+        ));
+    } else if advisory.collection() == Some(Collection::Crates) && !is_valid_crate_name(package) {
+        issues.push(ValidationIssue::new(
+            path,
+            advisory,
+            format!("invalid crate name `{}`", package),
+        ));
+    }
+
+    // Version requirements and CVSS vectors were already parsed when the
+    // advisory was loaded, so lint the source for anything else
+    if lint {
+        match Linter::lint_file(path) {
+            Ok(linter) => issues.extend(
+                linter
+                    .errors()
+                    .iter()
+                    .map(|err| ValidationIssue::new(path, advisory, err.to_string())),
+            ),
+            Err(err) => issues.push(ValidationIssue::new(path, advisory, err.to_string())),
+        }
+    }
+}
+
+/// Is `name` a crate name crates.io would accept?
+fn is_valid_crate_name(name: &str) -> bool {
+    name.len() <= MAX_CRATE_NAME_LEN
+        && name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
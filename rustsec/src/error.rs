@@ -85,6 +85,11 @@ impl Error {
     }
 
     /// Obtain the inner `ErrorKind` for this error
+    ///
+    /// Match on this to handle particular failures, rather than on the
+    /// message. For example, a [`Repository::fetch`](crate::repository::git::Repository::fetch)
+    /// which fails with [`ErrorKind::LockTimeout`] because another process
+    /// is updating the database can simply be retried.
     pub fn kind(&self) -> ErrorKind {
         self.kind
     }
@@ -144,6 +149,19 @@ pub enum ErrorKind {
     /// Errors related to versions
     #[error("bad version")]
     Version,
+
+    /// The advisory database doesn't contain any advisories, or any in a
+    /// required collection
+    #[error("advisory database is empty")]
+    EmptyDatabase,
+
+    /// The advisory database uses a newer schema than is supported
+    #[error("unsupported advisory database schema")]
+    UnsupportedSchema,
+
+    /// The advisory database doesn't match its signed manifest
+    #[error("advisory database verification failed")]
+    Verification,
 }
 
 impl From<Utf8Error> for Error {
@@ -2,11 +2,19 @@
 //!
 //! This module is **experimental**, and its behavior may change in the future.
 
-use crate::vulnerability::Vulnerability;
+use crate::{
+    error::{Error, ErrorKind},
+    fs, package,
+    vulnerability::Vulnerability,
+};
 use cargo_lock::{Lockfile, Package};
+use semver::{Comparator, Op, Version, VersionReq};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+/// Tables of a `Cargo.toml` which list dependencies
+const DEPENDENCY_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
 /// Auto-fixer for vulnerable dependencies
 #[cfg_attr(docsrs, doc(cfg(feature = "fix")))]
 pub struct Fixer {
@@ -42,8 +50,116 @@ impl Fixer {
     /// the vulnerability was actually fixed!
     /// It may remain if no semver-compatible fix was available.
     pub fn get_fix_command(&self, vulnerability: &Vulnerability, dry_run: bool) -> Command {
+        let mut command = self.update_command(dry_run);
+        // there can be more than one version of a given package in the lockfile, so we need to iterate over all of them
+        for pkg in self.vulnerable_packages(vulnerability) {
+            let pkgid = pkgid(pkg);
+            command.arg(&pkgid);
+        }
+
+        command
+    }
+
+    /// Returns every package in the lockfile affected by this vulnerability
+    pub fn vulnerable_packages<'a>(&'a self, vulnerability: &'a Vulnerability) -> Vec<&'a Package> {
+        self.lockfile
+            .packages
+            .iter()
+            .filter(|pkg| {
+                pkg.name == vulnerability.package.name
+                    && vulnerability.versions.is_vulnerable(&pkg.version)
+            })
+            .collect()
+    }
+
+    /// Returns lockfile-only fixes for the vulnerable packages which are
+    /// transitive dependencies, i.e. not depended on directly by a workspace member.
+    ///
+    /// The version to update to is the earliest release named in the
+    /// advisory's patched versions which is newer than the vulnerable one.
+    pub fn transitive_fixes<'a>(
+        &'a self,
+        vulnerability: &'a Vulnerability,
+    ) -> Vec<TransitiveFix<'a>> {
+        self.vulnerable_packages(vulnerability)
+            .into_iter()
+            .filter(|pkg| !self.is_direct_dependency(pkg))
+            .filter_map(|pkg| {
+                patched_version(vulnerability, &pkg.version).map(|version| TransitiveFix {
+                    package: pkg,
+                    version,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the newest patched version of the vulnerable package which
+    /// `cargo update` can reach without changing any version requirements.
+    ///
+    /// The requirements on the package are read from the `Cargo.toml` given
+    /// to [`Fixer::new`], keeping those which the locked version meets. If
+    /// there's no manifest, or it doesn't name the package (e.g. because it's
+    /// a transitive dependency), the semver-compatible range of the locked
+    /// version is used instead, i.e. what a requirement like `"1.2"` allows.
+    ///
+    /// Returns [`CompatibleFix::RequiresBreakingChange`] if every patched
+    /// version is outside of the requirements, and `None` if nothing is patched.
+    pub fn compatible_fix(
+        &self,
+        vulnerability: &Vulnerability,
+    ) -> Result<Option<CompatibleFix>, Error> {
+        let package = &vulnerability.package;
+        let mut candidates = patched_versions(vulnerability, &package.version).peekable();
+        if candidates.peek().is_none() {
+            return Ok(None);
+        }
+
+        let mut requirements = match &self.manifest_path {
+            Some(path) => manifest_requirements(path, &package.name)?,
+            None => vec![],
+        };
+        requirements.retain(|req| req.matches(&package.version));
+        if requirements.is_empty() {
+            requirements.push(caret_requirement(&package.version));
+        }
+
+        Ok(Some(
+            match candidates
+                .filter(|version| requirements.iter().all(|req| req.matches(version)))
+                .max()
+            {
+                Some(version) => CompatibleFix::Update(version),
+                None => CompatibleFix::RequiresBreakingChange,
+            },
+        ))
+    }
+
+    /// Returns a command that calls `cargo update --precise` to bump just the
+    /// lockfile entry for a transitive dependency.
+    pub fn get_precise_fix_command(&self, fix: &TransitiveFix<'_>, dry_run: bool) -> Command {
+        let mut command = self.update_command(dry_run);
+        command
+            .arg(pkgid(fix.package))
+            .arg("--precise")
+            .arg(fix.version.to_string());
+        command
+    }
+
+    /// Is the given package a direct dependency of a workspace member?
+    ///
+    /// Workspace members are the packages in the lockfile without a source.
+    pub fn is_direct_dependency(&self, package: &Package) -> bool {
+        self.lockfile
+            .packages
+            .iter()
+            .filter(|pkg| pkg.source.is_none())
+            .flat_map(|pkg| &pkg.dependencies)
+            .any(|dep| dep.matches(package))
+    }
+
+    /// Returns a `cargo update` command without any package specs
+    fn update_command(&self, dry_run: bool) -> Command {
         let cargo_path: &Path = self.path_to_cargo.as_deref().unwrap_or(Path::new("cargo"));
-        let pkg_name = &vulnerability.package.name;
         let mut command = Command::new(cargo_path);
         command.arg("update");
         if let Some(path) = self.manifest_path.as_ref() {
@@ -52,15 +168,145 @@ impl Fixer {
         if dry_run {
             command.arg("--dry-run");
         }
-        // there can be more than one version of a given package in the lockfile, so we need to iterate over all of them
-        for pkg in self.lockfile.packages.iter().filter(|pkg| {
-            &pkg.name == pkg_name && vulnerability.versions.is_vulnerable(&pkg.version)
-        }) {
-            let pkgid = pkgid(pkg);
-            command.arg(&pkgid);
+        command
+    }
+}
+
+/// Lockfile-only update of a vulnerable transitive dependency
+#[cfg_attr(docsrs, doc(cfg(feature = "fix")))]
+#[derive(Clone, Debug)]
+pub struct TransitiveFix<'a> {
+    /// Vulnerable package in the lockfile
+    pub package: &'a Package,
+
+    /// Patched version to update it to
+    pub version: Version,
+}
+
+/// Upgrade of a vulnerable package which respects semver compatibility
+#[cfg_attr(docsrs, doc(cfg(feature = "fix")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CompatibleFix {
+    /// `cargo update` can reach this patched version
+    Update(Version),
+
+    /// Every patched version is outside of the version requirements on the
+    /// package, so `Cargo.toml` has to change (usually a breaking upgrade)
+    RequiresBreakingChange,
+}
+
+/// Find the earliest version named in the patched requirements which is
+/// newer than `current` and not vulnerable
+pub(crate) fn patched_version(vulnerability: &Vulnerability, current: &Version) -> Option<Version> {
+    patched_versions(vulnerability, current).min()
+}
+
+/// Versions named in the patched requirements which are newer than
+/// `current` and not vulnerable
+fn patched_versions<'a>(
+    vulnerability: &'a Vulnerability,
+    current: &'a Version,
+) -> impl Iterator<Item = Version> + 'a {
+    vulnerability
+        .versions
+        .patched()
+        .iter()
+        .flat_map(|req| &req.comparators)
+        .filter(|comparator| {
+            matches!(
+                comparator.op,
+                Op::Exact | Op::GreaterEq | Op::Caret | Op::Tilde
+            )
+        })
+        .map(|comparator| Version {
+            major: comparator.major,
+            minor: comparator.minor.unwrap_or(0),
+            patch: comparator.patch.unwrap_or(0),
+            pre: comparator.pre.clone(),
+            build: Default::default(),
+        })
+        .filter(move |version| version > current && !vulnerability.versions.is_vulnerable(version))
+}
+
+/// Requirements on the package named `name` in the dependency tables of the
+/// `Cargo.toml` at `path`, including target-specific and workspace ones.
+///
+/// Dependencies without a version (e.g. path and git ones) have no
+/// requirement, and neither do ones inherited from a workspace manifest
+/// other than this one.
+fn manifest_requirements(path: &Path, name: &package::Name) -> Result<Vec<VersionReq>, Error> {
+    let manifest: toml::Table = fs::read_to_string(path)?
+        .parse()
+        .map_err(Error::from_toml)?;
+    let workspace = manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("dependencies"))
+        .and_then(toml::Value::as_table);
+
+    let targets = manifest
+        .get("target")
+        .and_then(toml::Value::as_table)
+        .into_iter()
+        .flat_map(|targets| targets.values().filter_map(toml::Value::as_table));
+
+    let mut tables: Vec<&toml::Table> = vec![];
+    for section in [&manifest].into_iter().chain(targets) {
+        tables.extend(
+            DEPENDENCY_TABLES
+                .iter()
+                .filter_map(|key| section.get(*key).and_then(toml::Value::as_table)),
+        );
+    }
+    tables.extend(workspace);
+
+    let mut requirements = vec![];
+    for (key, dependency) in tables.into_iter().flatten() {
+        let dependency = match dependency.get("workspace").and_then(toml::Value::as_bool) {
+            Some(true) => match workspace.and_then(|workspace| workspace.get(key)) {
+                Some(dependency) => dependency,
+                None => continue,
+            },
+            _ => dependency,
+        };
+
+        let package = dependency
+            .get("package")
+            .and_then(toml::Value::as_str)
+            .unwrap_or(key);
+        if package != name.as_str() {
+            continue;
         }
 
-        command
+        let version = match dependency {
+            toml::Value::String(version) => Some(version.as_str()),
+            _ => dependency.get("version").and_then(toml::Value::as_str),
+        };
+        if let Some(version) = version {
+            requirements.push(VersionReq::parse(version).map_err(|err| {
+                format_err!(
+                    ErrorKind::Parse,
+                    "invalid requirement on {} in {}: {}",
+                    name,
+                    path.display(),
+                    err
+                )
+            })?);
+        }
+    }
+
+    Ok(requirements)
+}
+
+/// Requirement for the semver-compatible range of `version`, like `^1.2.3`
+fn caret_requirement(version: &Version) -> VersionReq {
+    VersionReq {
+        comparators: vec![Comparator {
+            op: Op::Caret,
+            major: version.major,
+            minor: Some(version.minor),
+            patch: Some(version.patch),
+            pre: version.pre.clone(),
+        }],
     }
 }
 
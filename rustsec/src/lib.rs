@@ -6,6 +6,8 @@
 
 #[macro_use]
 mod error;
+#[macro_use]
+mod trace;
 
 pub mod advisory;
 mod collection;
@@ -14,6 +16,9 @@ mod fixer;
 pub mod osv;
 pub mod report;
 pub mod repository;
+#[cfg(feature = "syslog")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syslog")))]
+pub mod syslog;
 mod vulnerability;
 mod warning;
 
@@ -39,11 +44,11 @@ pub use crate::{
     database::Database,
     error::{Error, ErrorKind, Result},
     report::Report,
-    vulnerability::Vulnerability,
-    warning::{Warning, WarningKind},
+    vulnerability::{AffectedPaths, RemediationConfidence, Vulnerability},
+    warning::{LatestRelease, Warning, WarningKind},
 };
 
-pub use crate::fixer::Fixer;
+pub use crate::fixer::{CompatibleFix, Fixer, TransitiveFix};
 
 #[cfg(feature = "git")]
 pub use crate::repository::git::Repository;
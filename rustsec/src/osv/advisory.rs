@@ -69,15 +69,22 @@ impl From<&cargo_lock::Name> for OsvPackage {
 #[allow(non_camel_case_types)]
 #[serde(tag = "type", content = "score")]
 pub enum OsvSeverity {
-    CVSS_V3(cvss::v3::Base),
+    CVSS_V3(cvss::v3::Vector),
+    CVSS_V4(cvss::v4::Vector),
 }
 
-impl From<cvss::v3::Base> for OsvSeverity {
-    fn from(cvss: cvss::v3::Base) -> Self {
+impl From<cvss::v3::Vector> for OsvSeverity {
+    fn from(cvss: cvss::v3::Vector) -> Self {
         OsvSeverity::CVSS_V3(cvss)
     }
 }
 
+impl From<cvss::v4::Vector> for OsvSeverity {
+    fn from(cvss: cvss::v4::Vector) -> Self {
+        OsvSeverity::CVSS_V4(cvss)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OsvAffected {
     pub(crate) package: OsvPackage,
@@ -182,7 +189,9 @@ impl From<Affected> for OsvEcosystemSpecificAffected {
 pub struct OsvDatabaseSpecific {
     #[serde(default)]
     categories: Vec<Category>,
-    cvss: Option<cvss::v3::Base>,
+    cvss: Option<cvss::v3::Vector>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cvss_v4: Option<cvss::v4::Vector>,
     informational: Option<Informational>,
 }
 
@@ -238,7 +247,14 @@ impl OsvAdvisory {
             affected: vec![OsvAffected {
                 package: (&metadata.package).into(),
                 ranges: Some(vec![timeline_for_advisory(&advisory.versions)]),
-                versions: Some(vec![]),
+                versions: Some(
+                    advisory
+                        .versions
+                        .affected()
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect(),
+                ),
                 ecosystem_specific: Some(OsvEcosystemSpecific {
                     affects: Some(advisory.affected.unwrap_or_default().into()),
                     affected_functions: None,
@@ -246,6 +262,7 @@ impl OsvAdvisory {
                 database_specific: OsvDatabaseSpecific {
                     categories: metadata.categories,
                     cvss: metadata.cvss.clone(),
+                    cvss_v4: metadata.cvss_v4.clone(),
                     informational: metadata.informational,
                 },
             }],
@@ -253,7 +270,12 @@ impl OsvAdvisory {
             aliases: metadata.aliases,
             related: metadata.related,
             summary: metadata.title,
-            severity: metadata.cvss.into_iter().map(|s| s.into()).collect(),
+            severity: metadata
+                .cvss
+                .into_iter()
+                .map(OsvSeverity::from)
+                .chain(metadata.cvss_v4.into_iter().map(OsvSeverity::from))
+                .collect(),
             details: metadata.description,
             references: osv_references(reference_urls),
             database_specific: MainOsvDatabaseSpecific {
@@ -1,4 +1,5 @@
-use semver::Version;
+use super::unaffected_range::{Bound, UnaffectedRange};
+use semver::{Version, VersionReq};
 
 /// A range of affected versions.
 ///
@@ -25,4 +26,24 @@ impl OsvRange {
             Some(end_v) => v < end_v,
         })
     }
+
+    /// Returns true if any version matching `req` is affected.
+    ///
+    /// Returns `None` if `req` can't be expressed as a single range
+    /// (e.g. it uses wildcards or has more than two comparators).
+    pub(crate) fn overlaps(&self, req: &VersionReq) -> Option<bool> {
+        let req = UnaffectedRange::try_from(req).ok()?;
+
+        let start = match &self.introduced {
+            None => Bound::Unbounded,
+            Some(v) => Bound::Inclusive(v.clone()),
+        };
+        let end = match &self.fixed {
+            None => Bound::Unbounded,
+            Some(v) => Bound::Exclusive(v.clone()),
+        };
+
+        // An empty range (e.g. `[1.0.0, 1.0.0)`) can't overlap anything
+        Some(UnaffectedRange::new(start, end).map_or(false, |range| range.overlaps(&req)))
+    }
 }
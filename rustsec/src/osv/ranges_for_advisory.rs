@@ -16,7 +16,8 @@ use semver::{Prerelease, Version, VersionReq};
 /// Panics if the ranges are malformed or range specification syntax is not supported,
 /// since that has been validated on deserialization.
 pub fn ranges_for_advisory(versions: &Versions) -> Vec<OsvRange> {
-    unaffected_to_osv_ranges(versions.unaffected(), versions.patched()).unwrap()
+    let ranges = unaffected_to_osv_ranges(versions.unaffected(), versions.patched()).unwrap();
+    restrict_to_affected_ranges(ranges, versions.affected_ranges()).unwrap()
 }
 
 /// Returns OSV ranges for all affected versions in the given advisory.
@@ -26,7 +27,88 @@ pub fn ranges_for_advisory(versions: &Versions) -> Vec<OsvRange> {
 pub(crate) fn ranges_for_unvalidated_advisory(
     versions: &RawVersions,
 ) -> Result<Vec<OsvRange>, Error> {
-    unaffected_to_osv_ranges(&versions.unaffected, &versions.patched)
+    let ranges = unaffected_to_osv_ranges(&versions.unaffected, &versions.patched)?;
+    restrict_to_affected_ranges(ranges, &versions.affected_ranges)
+}
+
+/// Intersects OSV ranges with the `affected_ranges` of an advisory, if any.
+///
+/// The affected ranges must not overlap each other, so that each version is
+/// in at most one of the resulting ranges.
+fn restrict_to_affected_ranges(
+    ranges: Vec<OsvRange>,
+    affected_req: &[VersionReq],
+) -> Result<Vec<OsvRange>, Error> {
+    if affected_req.is_empty() {
+        return Ok(ranges);
+    }
+
+    let mut affected: Vec<UnaffectedRange> = Vec::new();
+    for req in affected_req {
+        affected.push(req.try_into()?);
+    }
+
+    for (idx, a) in affected.iter().enumerate() {
+        for b in affected[idx + 1..].iter() {
+            if a.overlaps(b) {
+                fail!(
+                    crate::ErrorKind::BadParam,
+                    format!("Overlapping affected version ranges: {} and {}", a, b)
+                );
+            }
+        }
+    }
+
+    let mut result = Vec::new();
+    for band in affected.iter().map(to_osv_range) {
+        for range in &ranges {
+            if let Some(intersection) = intersect(range, &band) {
+                result.push(intersection);
+            }
+        }
+    }
+
+    result.sort_unstable_by(|a, b| match (&a.introduced, &b.introduced) {
+        (None, None) => std::cmp::Ordering::Equal,
+        (None, _) => std::cmp::Ordering::Less,
+        (_, None) => std::cmp::Ordering::Greater,
+        (Some(v1), Some(v2)) => v1.cmp(v2),
+    });
+
+    Ok(result)
+}
+
+/// Converts a range with inclusive or exclusive bounds to a `[start, end)` OSV range
+fn to_osv_range(range: &UnaffectedRange) -> OsvRange {
+    OsvRange {
+        introduced: match range.start() {
+            Bound::Unbounded => None,
+            Bound::Inclusive(v) => Some(v.clone()),
+            Bound::Exclusive(v) => Some(increment(v)),
+        },
+        fixed: match range.end() {
+            Bound::Unbounded => None,
+            Bound::Exclusive(v) => Some(v.clone()),
+            Bound::Inclusive(v) => Some(increment(v)),
+        },
+    }
+}
+
+/// Returns the versions in both OSV ranges, or `None` if there aren't any
+fn intersect(a: &OsvRange, b: &OsvRange) -> Option<OsvRange> {
+    let introduced = match (&a.introduced, &b.introduced) {
+        (Some(v1), Some(v2)) => Some(v1.max(v2).clone()),
+        (v, None) | (None, v) => v.clone(),
+    };
+    let fixed = match (&a.fixed, &b.fixed) {
+        (Some(v1), Some(v2)) => Some(v1.min(v2).clone()),
+        (v, None) | (None, v) => v.clone(),
+    };
+
+    match (&introduced, &fixed) {
+        (Some(start), Some(end)) if start >= end => None,
+        _ => Some(OsvRange { introduced, fixed }),
+    }
 }
 
 /// Converts a list of unaffected ranges to a range of affected OSV ranges.
@@ -164,7 +164,11 @@ impl TryFrom<&semver::VersionReq> for UnaffectedRange {
                             format!("More than one upper bound in the same range: {}", input)
                         );
                     }
-                    end = Bound::Inclusive(comp_to_ver(comparator));
+                    // `<= 1.2` includes all of 1.2.x, not just 1.2.0
+                    end = match end_of_partial(comparator) {
+                        Some(v) => Bound::Exclusive(v),
+                        None => Bound::Inclusive(comp_to_ver(comparator)),
+                    };
                 }
                 Op::Exact => {
                     if input.comparators.len() != 1 {
@@ -244,6 +248,20 @@ fn comp_to_ver(c: &Comparator) -> Version {
     }
 }
 
+/// For a comparator with the minor or patch version omitted (e.g. `1.2`),
+/// returns the lowest version above everything it covers (e.g. `1.3.0-0`).
+/// Returns `None` if the comparator specifies a complete version.
+fn end_of_partial(c: &Comparator) -> Option<Version> {
+    let mut end_version = match (c.minor, c.patch) {
+        (None, _) => Version::new(c.major + 1, 0, 0),
+        (Some(minor), None) => Version::new(c.major, minor + 1, 0),
+        (Some(_), Some(_)) => return None,
+    };
+    // -0 is the lowest possible prerelease, as for caret and tilde requirements
+    end_version.pre = Prerelease::new("0").unwrap();
+    Some(end_version)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -460,4 +478,21 @@ mod tests {
         let result: UnaffectedRange = (&input).try_into().unwrap();
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn partial_upper_bounds() {
+        let expected = UnaffectedRange {
+            start: Bound::Unbounded,
+            end: Bound::Exclusive(Version::parse("1.3.0-0").unwrap()),
+        };
+        let result: UnaffectedRange = (&VersionReq::parse("<=1.2").unwrap()).try_into().unwrap();
+        assert_eq!(expected, result);
+
+        let expected = UnaffectedRange {
+            start: Bound::Unbounded,
+            end: Bound::Exclusive(Version::parse("1.2.0").unwrap()),
+        };
+        let result: UnaffectedRange = (&VersionReq::parse("<1.2").unwrap()).try_into().unwrap();
+        assert_eq!(expected, result);
+    }
 }
@@ -3,16 +3,29 @@
 //! These types map directly to the JSON report generated by `cargo-audit`,
 //! but also provide the core reporting functionality used in general.
 
+mod v1;
+
 use crate::{
-    advisory,
+    advisory::{self, affected::FunctionPath},
+    cargo_lock::Dependency,
+    collection::Collection,
     database::{Database, Query},
-    map,
-    platforms::target::{Arch, OS},
-    vulnerability::Vulnerability,
+    error::{Error, ErrorKind},
+    map, package,
+    platforms::{
+        target::{Arch, OS},
+        Platform,
+    },
+    vulnerability::{AffectedPaths, Vulnerability},
     warning::{self, Warning},
-    Lockfile, Map,
+    Lockfile, Map, Set, SourceId,
 };
-use serde::{Deserialize, Serialize};
+use semver::Version;
+use serde::{Deserialize, Serialize, Serializer};
+use std::{cmp::Reverse, collections::VecDeque, fmt, path::Path, str::FromStr};
+
+#[cfg(feature = "git")]
+use std::path::PathBuf;
 
 /// Vulnerability report for a given lockfile
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -33,18 +46,37 @@ pub struct Report {
 
     /// Warnings about dependencies (from e.g. informational advisories)
     pub warnings: WarningInfo,
+
+    /// Entries of [`Settings::ignore`] which suppressed a vulnerability or
+    /// warning that would otherwise have been reported, in the order given.
+    ///
+    /// Ignored IDs missing from this list didn't match anything in this run.
+    #[serde(default)]
+    pub applied_ignores: Vec<advisory::Id>,
+
+    /// How many candidate advisories the scan checked, if [`Settings::stats`] is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<ScanStats>,
 }
 
 impl Report {
     /// Generate a report for the given advisory database and lockfile
     pub fn generate(db: &Database, lockfile: &Lockfile, settings: &Settings) -> Self {
-        let vulnerabilities = db
-            .query_vulnerabilities(lockfile, &settings.query())
-            .into_iter()
-            .filter(|vuln| !settings.ignore.contains(&vuln.advisory.id))
-            .collect();
+        let mut suppressed = Set::new();
+        let (vulnerabilities, demoted) =
+            find_vulnerabilities(db, lockfile, settings, &mut suppressed);
+
+        let mut warnings = find_warnings_suppressing(db, lockfile, settings, &mut suppressed);
+        warnings.extend(demoted);
 
-        let warnings = find_warnings(db, lockfile, settings);
+        let mut applied_ignores = vec![];
+        for id in &settings.ignore {
+            if suppressed.contains(db.canonical_id(id).unwrap_or(id))
+                && !applied_ignores.contains(id)
+            {
+                applied_ignores.push(id.clone());
+            }
+        }
 
         Self {
             #[cfg(feature = "git")]
@@ -53,10 +85,233 @@ impl Report {
             settings: settings.clone(),
             vulnerabilities: VulnerabilityInfo::new(vulnerabilities),
             warnings,
+            applied_ignores,
+            stats: settings.stats.then(|| ScanStats::new(db, lockfile)),
+        }
+    }
+
+    /// Load the advisory database at `db_path` and the lockfile at
+    /// `lockfile_path`, and generate a report for them.
+    ///
+    /// This is the whole scan [`Report::generate`] performs in one call, for
+    /// embedders which don't otherwise need the [`Database`] or [`Lockfile`].
+    pub fn new_from_lockfile(
+        db_path: &Path,
+        lockfile_path: &Path,
+        settings: &Settings,
+    ) -> Result<Self, Error> {
+        let db = Database::open(db_path)?;
+        let lockfile = Lockfile::load(lockfile_path)?;
+        Ok(Self::generate(&db, &lockfile, settings))
+    }
+
+    /// Get every vulnerability and warning in the report as a [`Finding`],
+    /// sorted by crate name, version, source, and then kind of finding.
+    ///
+    /// This is one list in a common shape, e.g. for dashboards which show
+    /// all findings together.
+    pub fn all_findings(&self) -> Vec<Finding> {
+        let vulnerabilities = self.vulnerabilities.list.iter().map(|vuln| Finding {
+            package: vuln.package.name.clone(),
+            version: vuln.package.version.clone(),
+            source: vuln.package.source.clone(),
+            kind: FindingKind::Vulnerability,
+            id: Some(vuln.advisory.id.clone()),
+            severity: vuln.advisory.severity(self.settings.cvss_preference),
+            title: Some(vuln.advisory.title.clone()),
+        });
+
+        let warnings = self.warnings.values().flatten().map(|warning| Finding {
+            package: warning.package.name.clone(),
+            version: warning.package.version.clone(),
+            source: warning.package.source.clone(),
+            kind: FindingKind::Warning(warning.kind),
+            id: warning
+                .advisory
+                .as_ref()
+                .map(|advisory| advisory.id.clone()),
+            severity: warning
+                .advisory
+                .as_ref()
+                .and_then(|advisory| advisory.severity(self.settings.cvss_preference)),
+            title: warning
+                .advisory
+                .as_ref()
+                .map(|advisory| advisory.title.clone()),
+        });
+
+        let mut findings: Vec<_> = vulnerabilities.chain(warnings).collect();
+        findings.sort();
+        findings
+    }
+
+    /// Get a serializable view of this report in the given schema version.
+    ///
+    /// Serializing a [`Report`] directly always produces the latest schema.
+    pub fn versioned(&self, version: SchemaVersion) -> VersionedReport<'_> {
+        VersionedReport {
+            report: self,
+            version,
+        }
+    }
+}
+
+/// Vulnerability or warning from a [`Report`], as returned by
+/// [`Report::all_findings`]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Finding {
+    /// Name of the affected crate
+    pub package: package::Name,
+
+    /// Version of the affected crate
+    pub version: Version,
+
+    /// Source of the affected crate (`None` for workspace members)
+    pub source: Option<SourceId>,
+
+    /// Whether this is a vulnerability or a kind of warning
+    pub kind: FindingKind,
+
+    /// ID of the advisory (`None` for warnings without one, e.g. yanked crates)
+    pub id: Option<advisory::Id>,
+
+    /// Severity of the advisory, if it has a CVSS score
+    pub severity: Option<advisory::Severity>,
+
+    /// Title of the advisory
+    pub title: Option<String>,
+}
+
+/// Kinds of [`Finding`]
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FindingKind {
+    /// Vulnerability reported by an advisory
+    Vulnerability,
+
+    /// Warning of the given kind
+    Warning(warning::WarningKind),
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Vulnerability => f.write_str("vulnerability"),
+            Self::Warning(kind) => write!(f, "{}", kind),
+        }
+    }
+}
+
+/// Version of the serialized report schema.
+///
+/// Consumers which haven't been updated for a newer schema can request an
+/// older one so the report keeps the shape they expect.
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, PartialOrd, Ord, Serialize)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct SchemaVersion(u32);
+
+impl SchemaVersion {
+    /// Original report layout, without a `schema-version` field.
+    ///
+    /// This layout is frozen: fields and warning kinds added to reports
+    /// since are left out of it.
+    pub const V1: Self = Self(1);
+
+    /// Adds the `schema-version` field
+    pub const V2: Self = Self(2);
+
+    /// Latest schema version
+    pub const LATEST: Self = Self::V2;
+
+    /// Get the schema version with the given number
+    pub fn new(version: u32) -> Result<Self, Error> {
+        if version == 0 || version > Self::LATEST.0 {
+            fail!(
+                ErrorKind::BadParam,
+                "unsupported report schema version: {} (latest is {})",
+                version,
+                Self::LATEST
+            );
+        }
+
+        Ok(Self(version))
+    }
+
+    /// Get the number of this schema version
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+}
+
+impl Default for SchemaVersion {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+impl TryFrom<u32> for SchemaVersion {
+    type Error = Error;
+
+    fn try_from(version: u32) -> Result<Self, Error> {
+        Self::new(version)
+    }
+}
+
+impl From<SchemaVersion> for u32 {
+    fn from(version: SchemaVersion) -> u32 {
+        version.0
+    }
+}
+
+impl FromStr for SchemaVersion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let version = s
+            .parse()
+            .map_err(|_| format_err!(ErrorKind::Parse, "invalid report schema version: {}", s))?;
+
+        Self::new(version)
+    }
+}
+
+impl fmt::Display for SchemaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// [`Report`] which serializes using a particular [`SchemaVersion`]
+#[derive(Copy, Clone, Debug)]
+pub struct VersionedReport<'a> {
+    report: &'a Report,
+    version: SchemaVersion,
+}
+
+impl Serialize for VersionedReport<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.version == SchemaVersion::V1 {
+            v1::ReportV1::new(self.report).serialize(serializer)
+        } else {
+            ReportV2 {
+                schema_version: self.version,
+                report: self.report,
+            }
+            .serialize(serializer)
         }
     }
 }
 
+/// Report layout which records its own schema version
+#[derive(Serialize)]
+struct ReportV2<'a> {
+    #[serde(rename = "schema-version")]
+    schema_version: SchemaVersion,
+
+    #[serde(flatten)]
+    report: &'a Report,
+}
+
 /// Options to use when generating the report
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Settings {
@@ -69,28 +324,322 @@ pub struct Settings {
     /// Severity threshold to alert at
     pub severity: Option<advisory::Severity>,
 
+    /// CVSS version severities are taken from, for advisories with vectors
+    /// for more than one (default: the highest version)
+    #[serde(default)]
+    pub cvss_preference: advisory::CvssPreference,
+
     /// List of advisory IDs to ignore
     pub ignore: Vec<advisory::Id>,
 
     /// Types of informational advisories to generate warnings for
     pub informational_warnings: Vec<advisory::Informational>,
+
+    /// Only consider advisories in this collection (default: crates)
+    #[serde(default)]
+    pub collection: Option<Collection>,
+
+    /// Report as though the audit ran on this date, leaving out advisories
+    /// dated after it (see [`Query::as_of`] for its limits)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_of: Option<advisory::Date>,
+
+    /// Version of `rustc` to check Rust toolchain advisories against
+    /// (toolchain advisories aren't checked if `None`)
+    #[serde(default)]
+    pub rust_version: Option<Version>,
+
+    /// Report `unsound` informational advisories as vulnerabilities rather
+    /// than warnings, so they count towards [`VulnerabilityInfo::count`]
+    #[serde(default)]
+    pub unsound_as_vulnerabilities: bool,
+
+    /// Cargo features enabled for each version of each package, where known
+    /// (e.g. from `cargo metadata`; `Cargo.lock` doesn't record them).
+    ///
+    /// Advisories which only affect a package when certain features are
+    /// enabled aren't reported for packages listed here without any of them.
+    /// For packages which aren't listed, they're reported with
+    /// [`Vulnerability::features_enabled`] left unknown.
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub enabled_features: Map<package::Name, Map<Version, Vec<String>>>,
+
+    /// Functions known to be reachable in each package, e.g. from a separate
+    /// call graph analysis.
+    ///
+    /// Vulnerabilities in packages listed here are tagged with whether any of
+    /// the advisory's affected functions are among them.
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub reachable_functions: Map<package::Name, Vec<FunctionPath>>,
+
+    /// Report vulnerabilities whose affected functions are all unreachable
+    /// as warnings rather than vulnerabilities
+    #[serde(default)]
+    pub unreachable_as_warnings: bool,
+
+    /// Flag vulnerabilities in advisories without a CVSS score as
+    /// [`Vulnerability::unscored`], so they're assessed manually rather than
+    /// passing severity thresholds unnoticed
+    #[serde(default)]
+    pub require_severity: bool,
+
+    /// Only report vulnerabilities in direct dependencies of workspace
+    /// members, leaving transitive ones to be handled upstream
+    #[serde(default)]
+    pub direct_only: bool,
+
+    /// When [`Settings::direct_only`] is set, report vulnerabilities in
+    /// transitive dependencies as warnings rather than dropping them
+    #[serde(default)]
+    pub transitive_as_warnings: bool,
+
+    /// Report crates.io packages without a checksum in the lockfile as
+    /// [`WarningKind::MissingChecksum`](warning::WarningKind::MissingChecksum)
+    /// warnings
+    #[serde(default)]
+    pub require_checksums: bool,
+
+    /// Minimum severity of a vulnerability which fails the audit for each
+    /// listed package, overriding [`Settings::severity`] for it
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub crate_severity: Map<package::Name, advisory::Severity>,
+
+    /// Count the candidate advisories for each package in [`Report::stats`]
+    #[serde(default)]
+    pub stats: bool,
 }
 
 impl Settings {
+    /// Create a [`SettingsBuilder`] for configuring settings fluently
+    pub fn builder() -> SettingsBuilder {
+        SettingsBuilder::new()
+    }
+
     /// Get a query which corresponds to the configured report settings.
     /// Note that queries can't filter ignored advisories, so this happens in
     /// a separate pass
     pub fn query(&self) -> Query {
+        match self.severity {
+            Some(severity) => self.query_any_severity().severity(severity),
+            None => self.query_any_severity(),
+        }
+    }
+
+    /// Get a query like [`Settings::query`] without the severity threshold,
+    /// for when it depends on the package
+    fn query_any_severity(&self) -> Query {
         let mut query = Query::crate_scope()
             .target_arch(self.target_arch.clone())
-            .target_os(self.target_os.clone());
+            .target_os(self.target_os.clone())
+            .cvss_preference(self.cvss_preference);
 
-        if let Some(severity) = self.severity {
-            query = query.severity(severity);
+        if let Some(collection) = self.collection {
+            query = query.collection(collection);
+        }
+
+        if let Some(as_of) = &self.as_of {
+            query = query.as_of(as_of.clone());
         }
 
         query
     }
+
+    /// Get the severity threshold for vulnerabilities in the given package
+    pub fn severity_for(&self, package: &package::Name) -> Option<advisory::Severity> {
+        self.crate_severity.get(package).copied().or(self.severity)
+    }
+}
+
+/// Builder for [`Settings`].
+///
+/// Unlike constructing [`Settings`] by field, code using the builder keeps
+/// compiling as new settings are added.
+#[derive(Clone, Debug, Default)]
+pub struct SettingsBuilder {
+    /// Settings being built
+    settings: Settings,
+
+    /// Target triples that couldn't be found
+    unknown_targets: Vec<String>,
+}
+
+impl SettingsBuilder {
+    /// Create a builder for the default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ignore advisories with the given IDs
+    pub fn ignore(mut self, ids: impl IntoIterator<Item = advisory::Id>) -> Self {
+        self.settings.ignore.extend(ids);
+        self
+    }
+
+    /// Only report advisories affecting the given target triple
+    /// (e.g. `x86_64-unknown-linux-gnu`). May be called more than once.
+    pub fn target(mut self, triple: &str) -> Self {
+        match Platform::find(triple) {
+            Some(platform) => {
+                if !self.settings.target_arch.contains(&platform.target_arch) {
+                    self.settings.target_arch.push(platform.target_arch);
+                }
+                if !self.settings.target_os.contains(&platform.target_os) {
+                    self.settings.target_os.push(platform.target_os);
+                }
+            }
+            None => self.unknown_targets.push(triple.to_owned()),
+        }
+        self
+    }
+
+    /// Only report vulnerabilities at or above the given severity
+    pub fn severity(mut self, severity: advisory::Severity) -> Self {
+        self.settings.severity = Some(severity);
+        self
+    }
+
+    /// Take severities from the vector for the given CVSS version, for
+    /// advisories with vectors for more than one
+    pub fn cvss_preference(mut self, preference: advisory::CvssPreference) -> Self {
+        self.settings.cvss_preference = preference;
+        self
+    }
+
+    /// Generate warnings for all kinds of informational advisories
+    /// (`notice`, `unmaintained`, and `unsound`), or for none of them
+    pub fn informational_warnings(mut self, setting: bool) -> Self {
+        self.settings.informational_warnings = if setting {
+            vec![
+                advisory::Informational::Notice,
+                advisory::Informational::Unmaintained,
+                advisory::Informational::Unsound,
+            ]
+        } else {
+            vec![]
+        };
+        self
+    }
+
+    /// Only consider advisories in the given collection
+    pub fn collection(mut self, collection: Collection) -> Self {
+        self.settings.collection = Some(collection);
+        self
+    }
+
+    /// Report as though the audit ran on the given date
+    /// (see [`Query::as_of`] for its limits)
+    pub fn as_of(mut self, date: advisory::Date) -> Self {
+        self.settings.as_of = Some(date);
+        self
+    }
+
+    /// Check Rust toolchain advisories (e.g. for `std`) against the given `rustc` version
+    pub fn rust_version(mut self, version: Version) -> Self {
+        self.settings.rust_version = Some(version);
+        self
+    }
+
+    /// Report `unsound` informational advisories as vulnerabilities.
+    ///
+    /// They're moved from the warnings into the vulnerabilities list, so they
+    /// fail the audit like any other vulnerability. This happens regardless
+    /// of whether `unsound` is one of the informational warnings.
+    pub fn unsound_as_vulnerabilities(mut self, setting: bool) -> Self {
+        self.settings.unsound_as_vulnerabilities = setting;
+        self
+    }
+
+    /// Record the cargo features enabled for a version of a package, so
+    /// advisories which need features it doesn't enable aren't reported for it
+    pub fn enabled_features(
+        mut self,
+        package: package::Name,
+        version: Version,
+        features: impl IntoIterator<Item = String>,
+    ) -> Self {
+        self.settings
+            .enabled_features
+            .entry(package)
+            .or_default()
+            .insert(version, features.into_iter().collect());
+        self
+    }
+
+    /// Record the functions known to be reachable in a package, so its
+    /// vulnerabilities are tagged with whether their affected functions are
+    pub fn reachable_functions(
+        mut self,
+        package: package::Name,
+        functions: impl IntoIterator<Item = FunctionPath>,
+    ) -> Self {
+        self.settings
+            .reachable_functions
+            .insert(package, functions.into_iter().collect());
+        self
+    }
+
+    /// Report vulnerabilities whose affected functions are all unreachable
+    /// as [`WarningKind::Unreachable`](warning::WarningKind::Unreachable)
+    /// warnings, so they don't fail the audit
+    pub fn unreachable_as_warnings(mut self, setting: bool) -> Self {
+        self.settings.unreachable_as_warnings = setting;
+        self
+    }
+
+    /// Flag vulnerabilities in advisories without a CVSS score as
+    /// [`Vulnerability::unscored`], so they can be triaged by hand
+    pub fn require_severity(mut self, setting: bool) -> Self {
+        self.settings.require_severity = setting;
+        self
+    }
+
+    /// Only report vulnerabilities in direct dependencies of workspace members
+    pub fn direct_only(mut self, setting: bool) -> Self {
+        self.settings.direct_only = setting;
+        self
+    }
+
+    /// Report vulnerabilities in transitive dependencies as
+    /// [`WarningKind::Transitive`](warning::WarningKind::Transitive) warnings
+    /// when only direct dependencies are audited, so they're still listed
+    pub fn transitive_as_warnings(mut self, setting: bool) -> Self {
+        self.settings.transitive_as_warnings = setting;
+        self
+    }
+
+    /// Report crates.io packages without a checksum in the lockfile as
+    /// [`WarningKind::MissingChecksum`](warning::WarningKind::MissingChecksum)
+    /// warnings
+    pub fn require_checksums(mut self, setting: bool) -> Self {
+        self.settings.require_checksums = setting;
+        self
+    }
+
+    /// Set the minimum severity of a vulnerability in the given package
+    /// which fails the audit, overriding the global severity threshold
+    pub fn crate_severity(mut self, package: package::Name, severity: advisory::Severity) -> Self {
+        self.settings.crate_severity.insert(package, severity);
+        self
+    }
+
+    /// Include counts of the candidate advisories for each package in the report
+    pub fn stats(mut self, setting: bool) -> Self {
+        self.settings.stats = setting;
+        self
+    }
+
+    /// Build the [`Settings`], failing if any target triple was unknown
+    pub fn build(self) -> Result<Settings, Error> {
+        if !self.unknown_targets.is_empty() {
+            fail!(
+                ErrorKind::BadParam,
+                "unknown target triple: {}",
+                self.unknown_targets.join(", ")
+            );
+        }
+
+        Ok(self.settings)
+    }
 }
 
 /// Information about the advisory database
@@ -98,11 +647,28 @@ impl Settings {
 #[cfg_attr(docsrs, doc(cfg(feature = "git")))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DatabaseInfo {
+    /// Local path the advisory database was loaded from
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+
     /// Number of advisories in the database
     #[serde(rename = "advisory-count")]
     pub advisory_count: usize,
 
+    /// Number of advisories which vulnerabilities can be matched against,
+    /// i.e. excluding withdrawn advisories
+    #[serde(rename = "advisories-available", default)]
+    pub advisories_available: usize,
+
+    /// Whether any advisories were available to check against.
+    ///
+    /// When this is `false` (e.g. the database path is wrong) a report
+    /// without vulnerabilities does not mean the lockfile is clean.
+    #[serde(rename = "database-loaded", default)]
+    pub loaded: bool,
+
     /// Git commit hash for the last commit to the database
+    /// (`None` if the database isn't a git checkout)
     #[serde(rename = "last-commit")]
     pub last_commit: Option<String>,
 
@@ -115,8 +681,13 @@ pub struct DatabaseInfo {
 impl DatabaseInfo {
     /// Create database information from the advisory db
     pub fn new(db: &Database) -> Self {
+        let advisories_available = db.iter().filter(|advisory| !advisory.withdrawn()).count();
+
         Self {
+            path: Some(db.path().to_owned()),
             advisory_count: db.iter().count(),
+            advisories_available,
+            loaded: advisories_available > 0,
             last_commit: db.latest_commit().map(|c| c.commit_id.to_hex()),
             last_updated: db.latest_commit().map(|c| c.timestamp),
         }
@@ -140,6 +711,79 @@ impl LockfileInfo {
     }
 }
 
+/// Counts of the candidate advisories for the packages a scan checked, e.g.
+/// to find the packages which make queries against a custom advisory
+/// database expensive.
+///
+/// A package's candidates are the advisories filed under its name, which
+/// are the ones a scan checks it against before filtering them by version,
+/// target and the rest of the [`Settings`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ScanStats {
+    /// Number of candidate advisories for the packages in the lockfile,
+    /// i.e. the sum of [`PackageStats::candidates`]
+    #[serde(rename = "candidate-advisories")]
+    pub candidate_advisories: usize,
+
+    /// Counts for each package in the lockfile with any advisories
+    pub packages: Map<package::Name, PackageStats>,
+}
+
+impl ScanStats {
+    /// Count the candidate advisories in `db` for the packages in `lockfile`
+    pub fn new(db: &Database, lockfile: &Lockfile) -> Self {
+        let mut stats = Self::default();
+
+        for package in &lockfile.packages {
+            let candidates = db
+                .query(
+                    &Query::new()
+                        .collection(Collection::Crates)
+                        .package_name(package.name.clone()),
+                )
+                .len();
+            if candidates == 0 {
+                continue;
+            }
+
+            let matched = db
+                .query(
+                    &Query::new()
+                        .collection(Collection::Crates)
+                        .package(package)
+                        .withdrawn(false),
+                )
+                .len();
+
+            let counts = stats.packages.entry(package.name.clone()).or_default();
+            counts.candidates += candidates;
+            counts.matched += matched;
+            stats.candidate_advisories += candidates;
+        }
+
+        stats
+    }
+
+    /// Get the packages with the most candidate advisories, most first
+    pub fn hottest(&self) -> Vec<(&package::Name, &PackageStats)> {
+        let mut packages: Vec<_> = self.packages.iter().collect();
+        packages.sort_by_key(|(_, stats)| Reverse(stats.candidates));
+        packages
+    }
+}
+
+/// Counts of the candidate advisories for one package, summed over each of
+/// its versions in the lockfile
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PackageStats {
+    /// Number of advisories filed under the package's name
+    pub candidates: usize,
+
+    /// Number of those affecting it, whether or not they were reported
+    /// with the [`Settings`] used
+    pub matched: usize,
+}
+
 /// Information about detected vulnerabilities
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct VulnerabilityInfo {
@@ -154,6 +798,9 @@ pub struct VulnerabilityInfo {
 }
 
 impl VulnerabilityInfo {
+    /// Name of the group for vulnerabilities whose advisory has no keywords
+    pub const UNCATEGORIZED: &'static str = "uncategorized";
+
     /// Create new vulnerability info
     pub fn new(list: Vec<Vulnerability>) -> Self {
         Self {
@@ -162,6 +809,391 @@ impl VulnerabilityInfo {
             list,
         }
     }
+
+    /// Vulnerabilities flagged as [`Vulnerability::unscored`]
+    pub fn unscored(&self) -> impl Iterator<Item = &Vulnerability> {
+        self.list.iter().filter(|vuln| vuln.unscored)
+    }
+
+    /// Group vulnerabilities by the keywords of their advisories.
+    ///
+    /// Vulnerabilities with several keywords appear in each of their groups,
+    /// and those without any are grouped under [`VulnerabilityInfo::UNCATEGORIZED`].
+    pub fn group_by_keyword(&self) -> Map<&str, Vec<&Vulnerability>> {
+        let mut groups = Map::<&str, Vec<&Vulnerability>>::new();
+
+        for vuln in &self.list {
+            if vuln.advisory.keywords.is_empty() {
+                groups.entry(Self::UNCATEGORIZED).or_default().push(vuln);
+            }
+
+            for keyword in &vuln.advisory.keywords {
+                groups.entry(keyword.as_str()).or_default().push(vuln);
+            }
+        }
+
+        groups
+    }
+
+    /// Deduplicate vulnerabilities with the same advisory ID, crate name,
+    /// version and source. Copies of a crate from several sources are
+    /// separate packages, so each gets its own entry.
+    ///
+    /// Each entry counts how many dependency paths in `lockfile` lead to the
+    /// affected crate from that source, starting from every workspace member
+    /// (so virtual workspaces without a root package are counted correctly).
+    /// Entries are in the order they're first found in [`Self::list`].
+    pub fn deduplicated(&self, lockfile: &Lockfile) -> Vec<DedupedVulnerability<'_>> {
+        let mut paths = DependencyPaths::new(lockfile);
+        let mut deduped: Vec<DedupedVulnerability<'_>> = vec![];
+
+        for vuln in &self.list {
+            let dependency = Dependency::from(&vuln.package);
+            let count = paths.count(&dependency, &mut Set::new());
+            let direct = paths.is_direct(&dependency);
+
+            match deduped.iter_mut().find(|entry| {
+                entry.vulnerability.advisory.id == vuln.advisory.id
+                    && entry.vulnerability.package.name == vuln.package.name
+                    && entry.vulnerability.package.version == vuln.package.version
+                    && entry.vulnerability.package.source == vuln.package.source
+            }) {
+                Some(entry) => {
+                    entry.paths += count;
+                    entry.direct |= direct;
+                }
+                None => deduped.push(DedupedVulnerability {
+                    vulnerability: vuln,
+                    paths: count,
+                    direct,
+                }),
+            }
+        }
+
+        deduped
+    }
+}
+
+/// Vulnerability which may affect a crate through several dependency paths
+#[derive(Clone, Debug)]
+pub struct DedupedVulnerability<'a> {
+    /// First vulnerability found for this advisory, crate, version and source
+    pub vulnerability: &'a Vulnerability,
+
+    /// Number of dependency paths from workspace members to the affected crate
+    pub paths: usize,
+
+    /// Is the affected crate a direct dependency of some workspace member?
+    pub direct: bool,
+}
+
+/// Dependency paths through a lockfile from its workspace members
+#[derive(Clone, Debug)]
+pub struct DependencyPaths {
+    /// Packages which depend on each package
+    dependents: Map<Dependency, Vec<Dependency>>,
+
+    /// Workspace members, i.e. the packages without a source.
+    ///
+    /// Virtual workspaces have no root package, so each member is the
+    /// start of its own paths, even if another member depends on it.
+    members: Set<Dependency>,
+
+    /// Number of paths to each package counted so far
+    counts: Map<Dependency, usize>,
+}
+
+impl DependencyPaths {
+    /// Index the dependency graph of the given lockfile
+    pub fn new(lockfile: &Lockfile) -> Self {
+        let mut dependents = Map::<Dependency, Vec<Dependency>>::new();
+
+        for package in &lockfile.packages {
+            for dependency in &package.dependencies {
+                dependents
+                    .entry(dependency.clone())
+                    .or_default()
+                    .push(Dependency::from(package));
+            }
+        }
+
+        Self {
+            dependents,
+            members: lockfile
+                .packages
+                .iter()
+                .filter(|package| package.source.is_none())
+                .map(Dependency::from)
+                .collect(),
+            counts: Map::new(),
+        }
+    }
+
+    /// Is `dependency` depended on directly by a workspace member?
+    pub fn is_direct(&self, dependency: &Dependency) -> bool {
+        self.dependents.get(dependency).map_or(false, |parents| {
+            parents.iter().any(|parent| self.members.contains(parent))
+        })
+    }
+
+    /// Count the paths from workspace members (or packages nothing depends
+    /// on, if there are none) to `dependency`
+    fn count(&mut self, dependency: &Dependency, visiting: &mut Set<Dependency>) -> usize {
+        if let Some(&count) = self.counts.get(dependency) {
+            return count;
+        }
+
+        let Some(parents) = self.dependents.get(dependency).cloned() else {
+            return 1;
+        };
+
+        // Dependency cycles (e.g. through dev-dependencies) don't add paths
+        if !visiting.insert(dependency.clone()) {
+            return 0;
+        }
+
+        let own = usize::from(self.members.contains(dependency));
+        let from_parents: usize = parents
+            .iter()
+            .map(|parent| self.count(parent, visiting))
+            .sum();
+        let count = (from_parents + own).max(1);
+
+        visiting.remove(dependency);
+        self.counts.insert(dependency.clone(), count);
+        count
+    }
+
+    /// List the paths from workspace members (or packages nothing depends
+    /// on) to `dependency`, up to [`AffectedPaths::LIMIT`] of them
+    pub fn all(&self, dependency: &Dependency) -> AffectedPaths {
+        let mut affected = AffectedPaths::default();
+        self.walk(&mut vec![dependency.clone()], &mut affected);
+        affected
+    }
+
+    /// Find the shortest path from a workspace member (or a package nothing
+    /// depends on) to `dependency`, starting at that package.
+    ///
+    /// If every path leads around a dependency cycle, the path is just
+    /// `dependency` itself.
+    pub fn shortest(&self, dependency: &Dependency) -> Vec<Dependency> {
+        // Walk up through the packages which depend on each one, breadth
+        // first, remembering which package each was reached from
+        let mut children = Map::<Dependency, Option<Dependency>>::new();
+        children.insert(dependency.clone(), None);
+        let mut queue = VecDeque::from([dependency.clone()]);
+
+        while let Some(current) = queue.pop_front() {
+            let parents = self.dependents.get(&current);
+
+            if self.members.contains(&current) || parents.is_none() {
+                let mut path = vec![];
+                let mut next = Some(current);
+                while let Some(dependency) = next {
+                    next = children[&dependency].clone();
+                    path.push(dependency);
+                }
+                return path;
+            }
+
+            for parent in parents.into_iter().flatten() {
+                if !children.contains_key(parent) {
+                    children.insert(parent.clone(), Some(current.clone()));
+                    queue.push_back(parent.clone());
+                }
+            }
+        }
+
+        vec![dependency.clone()]
+    }
+
+    /// Add the paths leading to the top of `path`, which is built up in
+    /// reverse from the vulnerable package
+    fn walk(&self, path: &mut Vec<Dependency>, affected: &mut AffectedPaths) {
+        let current = path.last().expect("path starts at the vulnerable package");
+        let parents = self.dependents.get(current);
+
+        if self.members.contains(current) || parents.is_none() {
+            if affected.paths.len() == AffectedPaths::LIMIT {
+                affected.truncated = true;
+                return;
+            }
+            affected.paths.push(path.iter().rev().cloned().collect());
+        }
+
+        for parent in parents.into_iter().flatten() {
+            // Dependency cycles (e.g. through dev-dependencies) don't add paths
+            if affected.truncated || path.contains(parent) {
+                continue;
+            }
+
+            path.push(parent.clone());
+            self.walk(path, affected);
+            path.pop();
+        }
+    }
+}
+
+/// IDs of the advisories to ignore, with aliases (e.g. GHSA IDs) in
+/// [`Settings::ignore`] resolved to the advisory they alias
+fn ignored_ids(db: &Database, settings: &Settings) -> Vec<advisory::Id> {
+    settings
+        .ignore
+        .iter()
+        .map(|id| db.canonical_id(id).unwrap_or(id).clone())
+        .collect()
+}
+
+/// Find vulnerabilities from the given advisory [`Database`] and [`Lockfile`].
+///
+/// Returns the vulnerabilities to report, along with warnings for those
+/// which are reported as warnings instead: vulnerabilities from advisories
+/// dated in the future, and transitive or unreachable ones if so
+/// configured. The IDs of advisories which were only left out because
+/// they're ignored are added to `suppressed`.
+fn find_vulnerabilities(
+    db: &Database,
+    lockfile: &Lockfile,
+    settings: &Settings,
+    suppressed: &mut Set<advisory::Id>,
+) -> (Vec<Vulnerability>, WarningInfo) {
+    // Per-package thresholds are applied below, since a query only has one
+    let query = if settings.crate_severity.is_empty() {
+        settings.query()
+    } else {
+        settings.query_any_severity()
+    };
+
+    let mut vulnerabilities = db.query_vulnerabilities(lockfile, &query);
+
+    if let Some(rust_version) = &settings.rust_version {
+        vulnerabilities.extend(db.toolchain_vulnerabilities(rust_version, &query));
+    }
+
+    if settings.unsound_as_vulnerabilities {
+        let query = query.clone().informational(true);
+        vulnerabilities.extend(
+            db.query_vulnerabilities(lockfile, &query)
+                .into_iter()
+                .filter(|vuln| {
+                    vuln.advisory.informational == Some(advisory::Informational::Unsound)
+                }),
+        );
+    }
+
+    let ignore = ignored_ids(db, settings);
+    let paths = DependencyPaths::new(lockfile);
+
+    let mut found = vec![];
+    let mut demoted = WarningInfo::default();
+
+    for mut vuln in vulnerabilities {
+        let features_enabled = features_enabled(settings, &vuln);
+        if features_enabled == Some(false) || !severe_enough(settings, &vuln) {
+            continue;
+        }
+
+        if ignore.contains(&vuln.advisory.id) {
+            suppressed.insert(vuln.advisory.id);
+            continue;
+        }
+
+        // Toolchain vulnerabilities aren't in the dependency graph at all
+        let dependency = (vuln.advisory.collection != Some(Collection::Rust))
+            .then(|| Dependency::from(&vuln.package));
+        let transitive = settings.direct_only
+            && dependency
+                .as_ref()
+                .map_or(false, |dependency| !paths.is_direct(dependency));
+        if transitive && !settings.transitive_as_warnings {
+            continue;
+        }
+
+        vuln.reachable = reachable(settings, &vuln);
+        vuln.features_enabled = features_enabled;
+        vuln.unscored =
+            settings.require_severity && vuln.advisory.severity(settings.cvss_preference).is_none();
+
+        // Advisories dated in the future aren't in effect yet, so they're
+        // reported as warnings rather than failing the audit
+        let kind = if vuln.advisory.date.is_future() {
+            warning::WarningKind::Upcoming
+        } else if transitive {
+            warning::WarningKind::Transitive
+        } else if settings.unreachable_as_warnings && vuln.reachable == Some(false) {
+            warning::WarningKind::Unreachable
+        } else {
+            vuln.affected_paths = dependency.map(|dependency| paths.all(&dependency));
+            found.push(vuln);
+            continue;
+        };
+
+        demoted.entry(kind).or_default().push(Warning::new(
+            kind,
+            &vuln.package,
+            Some(vuln.advisory),
+            vuln.affected,
+            Some(vuln.versions),
+        ));
+    }
+
+    (found, demoted)
+}
+
+/// Find the IDs of the advisories which would be reported as vulnerabilities
+/// for the given advisory [`Database`] and [`Lockfile`].
+///
+/// These are the same IDs as in the vulnerability list of
+/// [`Report::generate`], without the work of building the rest of the report.
+pub fn scan_ids(db: &Database, lockfile: &Lockfile, settings: &Settings) -> Set<advisory::Id> {
+    find_vulnerabilities(db, lockfile, settings, &mut Set::new())
+        .0
+        .into_iter()
+        .map(|vuln| vuln.advisory.id)
+        .collect()
+}
+
+/// Are the features the vulnerable code needs enabled for the affected package?
+///
+/// `None` if the advisory isn't specific to any features, or the features
+/// enabled for this version of the package aren't in
+/// [`Settings::enabled_features`].
+fn features_enabled(settings: &Settings, vuln: &Vulnerability) -> Option<bool> {
+    let affected = vuln
+        .affected
+        .as_ref()
+        .filter(|affected| !affected.features.is_empty())?;
+    let enabled = settings
+        .enabled_features
+        .get(&vuln.package.name)?
+        .get(&vuln.package.version)?;
+
+    Some(affected.features_enabled(enabled))
+}
+
+/// Does `vuln` meet the severity threshold for its package?
+///
+/// Advisories without a CVSS score always do, like in [`Query::severity`].
+fn severe_enough(settings: &Settings, vuln: &Vulnerability) -> bool {
+    match (
+        settings.severity_for(&vuln.package.name),
+        vuln.advisory.severity(settings.cvss_preference),
+    ) {
+        (Some(threshold), Some(severity)) => severity >= threshold,
+        _ => true,
+    }
+}
+
+/// Are any of the functions affected by `vuln` among the reachable
+/// functions recorded for its package? `None` if that isn't known.
+fn reachable(settings: &Settings, vuln: &Vulnerability) -> Option<bool> {
+    let reachable = settings.reachable_functions.get(&vuln.package.name)?;
+    let affected = vuln
+        .affected_functions()
+        .filter(|functions| !functions.is_empty())?;
+
+    Some(affected.iter().any(|function| reachable.contains(function)))
 }
 
 /// Information about warnings
@@ -169,15 +1201,34 @@ pub type WarningInfo = Map<warning::WarningKind, Vec<Warning>>;
 
 /// Find warnings from the given advisory [`Database`] and [`Lockfile`]
 pub fn find_warnings(db: &Database, lockfile: &Lockfile, settings: &Settings) -> WarningInfo {
+    find_warnings_suppressing(db, lockfile, settings, &mut Set::new())
+}
+
+/// Find warnings like [`find_warnings`], adding the IDs of advisories which
+/// were only left out because they're ignored to `suppressed`
+fn find_warnings_suppressing(
+    db: &Database,
+    lockfile: &Lockfile,
+    settings: &Settings,
+    suppressed: &mut Set<advisory::Id>,
+) -> WarningInfo {
     let query = settings.query().informational(true);
 
     let mut warnings = WarningInfo::default();
+    let ignore = ignored_ids(db, settings);
 
     // TODO(tarcieri): abstract `Cargo.lock` query logic between vulnerabilities/warnings
     for advisory_vuln in db.query_vulnerabilities(lockfile, &query) {
         let advisory = &advisory_vuln.advisory;
 
-        if settings.ignore.contains(&advisory.id) {
+        if features_enabled(settings, &advisory_vuln) == Some(false) {
+            continue;
+        }
+
+        // Already reported as a vulnerability
+        if settings.unsound_as_vulnerabilities
+            && advisory.informational == Some(advisory::Informational::Unsound)
+        {
             continue;
         }
 
@@ -196,6 +1247,11 @@ pub fn find_warnings(db: &Database, lockfile: &Lockfile, settings: &Settings) ->
                 None => continue,
             };
 
+            if ignore.contains(&advisory.id) {
+                suppressed.insert(advisory.id.clone());
+                continue;
+            }
+
             let warning = Warning::new(
                 warning_kind,
                 &advisory_vuln.package,
@@ -213,5 +1269,24 @@ pub fn find_warnings(db: &Database, lockfile: &Lockfile, settings: &Settings) ->
         }
     }
 
+    if settings.require_checksums {
+        let missing: Vec<_> = lockfile
+            .packages
+            .iter()
+            .filter(|pkg| {
+                pkg.checksum.is_none()
+                    && pkg
+                        .source
+                        .as_ref()
+                        .is_some_and(|source| source.is_default_registry())
+            })
+            .map(|pkg| Warning::new(warning::WarningKind::MissingChecksum, pkg, None, None, None))
+            .collect();
+
+        if !missing.is_empty() {
+            warnings.insert(warning::WarningKind::MissingChecksum, missing);
+        }
+    }
+
     warnings
 }
@@ -0,0 +1,257 @@
+//! Version 1 of the report schema.
+//!
+//! This is the report layout from before reports were versioned. Its fields
+//! are listed here explicitly rather than taken from the report types, so
+//! fields added to those types later never show up in a V1 report.
+
+#[cfg(feature = "git")]
+use super::DatabaseInfo;
+use super::{LockfileInfo, Report, Settings};
+use crate::{
+    advisory::{self, Affected, Metadata, Versions},
+    collection::Collection,
+    package::Package,
+    platforms::target::{Arch, OS},
+    vulnerability::Vulnerability,
+    warning::{Warning, WarningKind},
+    Map, SourceId,
+};
+use semver::VersionReq;
+use serde::Serialize;
+use url::Url;
+
+/// Kinds of warnings which V1 reports have
+const WARNING_KINDS: &[WarningKind] = &[
+    WarningKind::Notice,
+    WarningKind::Unmaintained,
+    WarningKind::Unsound,
+    WarningKind::Yanked,
+];
+
+/// Report in the V1 layout
+#[derive(Serialize)]
+pub(super) struct ReportV1<'a> {
+    #[cfg(feature = "git")]
+    database: DatabaseInfoV1<'a>,
+    lockfile: LockfileInfoV1,
+    settings: SettingsV1<'a>,
+    vulnerabilities: VulnerabilityInfoV1<'a>,
+    warnings: Map<WarningKind, Vec<WarningV1<'a>>>,
+}
+
+impl<'a> ReportV1<'a> {
+    pub fn new(report: &'a Report) -> Self {
+        let list: Vec<_> = report
+            .vulnerabilities
+            .list
+            .iter()
+            .map(VulnerabilityV1::new)
+            .collect();
+
+        // Warnings of kinds added later are left out, rather than reported
+        // under kinds V1 consumers don't know about
+        let warnings = report
+            .warnings
+            .iter()
+            .filter(|(kind, _)| WARNING_KINDS.contains(kind))
+            .map(|(kind, warnings)| (*kind, warnings.iter().map(WarningV1::new).collect()))
+            .collect();
+
+        Self {
+            #[cfg(feature = "git")]
+            database: DatabaseInfoV1::new(&report.database),
+            lockfile: LockfileInfoV1::new(&report.lockfile),
+            settings: SettingsV1::new(&report.settings),
+            vulnerabilities: VulnerabilityInfoV1 {
+                found: report.vulnerabilities.found,
+                count: report.vulnerabilities.count,
+                list,
+            },
+            warnings,
+        }
+    }
+}
+
+#[cfg(feature = "git")]
+#[derive(Serialize)]
+struct DatabaseInfoV1<'a> {
+    #[serde(rename = "advisory-count")]
+    advisory_count: usize,
+
+    #[serde(rename = "last-commit")]
+    last_commit: &'a Option<String>,
+
+    #[serde(rename = "last-updated", with = "time::serde::rfc3339::option")]
+    last_updated: Option<time::OffsetDateTime>,
+}
+
+#[cfg(feature = "git")]
+impl<'a> DatabaseInfoV1<'a> {
+    fn new(info: &'a DatabaseInfo) -> Self {
+        Self {
+            advisory_count: info.advisory_count,
+            last_commit: &info.last_commit,
+            last_updated: info.last_updated,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct LockfileInfoV1 {
+    #[serde(rename = "dependency-count")]
+    dependency_count: usize,
+}
+
+impl LockfileInfoV1 {
+    fn new(info: &LockfileInfo) -> Self {
+        Self {
+            dependency_count: info.dependency_count,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SettingsV1<'a> {
+    target_arch: &'a [Arch],
+    target_os: &'a [OS],
+    severity: Option<advisory::Severity>,
+    ignore: &'a [advisory::Id],
+    informational_warnings: &'a [advisory::Informational],
+}
+
+impl<'a> SettingsV1<'a> {
+    fn new(settings: &'a Settings) -> Self {
+        Self {
+            target_arch: &settings.target_arch,
+            target_os: &settings.target_os,
+            severity: settings.severity,
+            ignore: &settings.ignore,
+            informational_warnings: &settings.informational_warnings,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct VulnerabilityInfoV1<'a> {
+    found: bool,
+    count: usize,
+    list: Vec<VulnerabilityV1<'a>>,
+}
+
+#[derive(Serialize)]
+struct VulnerabilityV1<'a> {
+    advisory: MetadataV1<'a>,
+    versions: VersionsV1<'a>,
+    affected: Option<AffectedV1<'a>>,
+    package: &'a Package,
+}
+
+impl<'a> VulnerabilityV1<'a> {
+    fn new(vuln: &'a Vulnerability) -> Self {
+        Self {
+            advisory: MetadataV1::new(&vuln.advisory),
+            versions: VersionsV1::new(&vuln.versions),
+            affected: vuln.affected.as_ref().map(AffectedV1::new),
+            package: &vuln.package,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct WarningV1<'a> {
+    kind: WarningKind,
+    package: &'a Package,
+    advisory: Option<MetadataV1<'a>>,
+    affected: Option<AffectedV1<'a>>,
+    versions: Option<VersionsV1<'a>>,
+}
+
+impl<'a> WarningV1<'a> {
+    fn new(warning: &'a Warning) -> Self {
+        Self {
+            kind: warning.kind,
+            package: &warning.package,
+            advisory: warning.advisory.as_ref().map(MetadataV1::new),
+            affected: warning.affected.as_ref().map(AffectedV1::new),
+            versions: warning.versions.as_ref().map(VersionsV1::new),
+        }
+    }
+}
+
+/// Advisory metadata, whose CVSS vector only has the base metrics
+#[derive(Serialize)]
+struct MetadataV1<'a> {
+    id: &'a advisory::Id,
+    package: &'a str,
+    title: &'a str,
+    description: &'a str,
+    date: &'a advisory::Date,
+    aliases: &'a [advisory::Id],
+    related: &'a [advisory::Id],
+    collection: Option<Collection>,
+    categories: &'a [advisory::Category],
+    keywords: &'a [advisory::Keyword],
+    cvss: Option<&'a cvss::v3::Base>,
+    informational: &'a Option<advisory::Informational>,
+    references: &'a [Url],
+    source: &'a Option<SourceId>,
+    url: &'a Option<Url>,
+    withdrawn: &'a Option<advisory::Date>,
+    license: &'a advisory::License,
+}
+
+impl<'a> MetadataV1<'a> {
+    fn new(metadata: &'a Metadata) -> Self {
+        Self {
+            id: &metadata.id,
+            package: metadata.package.as_str(),
+            title: &metadata.title,
+            description: &metadata.description,
+            date: &metadata.date,
+            aliases: &metadata.aliases,
+            related: &metadata.related,
+            collection: metadata.collection,
+            categories: &metadata.categories,
+            keywords: &metadata.keywords,
+            cvss: metadata.cvss_base(),
+            informational: &metadata.informational,
+            references: &metadata.references,
+            source: &metadata.source,
+            url: &metadata.url,
+            withdrawn: &metadata.withdrawn,
+            license: &metadata.license,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct VersionsV1<'a> {
+    patched: &'a [VersionReq],
+    unaffected: &'a [VersionReq],
+}
+
+impl<'a> VersionsV1<'a> {
+    fn new(versions: &'a Versions) -> Self {
+        Self {
+            patched: versions.patched(),
+            unaffected: versions.unaffected(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct AffectedV1<'a> {
+    arch: &'a [Arch],
+    os: &'a [OS],
+    functions: &'a Map<advisory::affected::FunctionPath, Vec<VersionReq>>,
+}
+
+impl<'a> AffectedV1<'a> {
+    fn new(affected: &'a Affected) -> Self {
+        Self {
+            arch: &affected.arch,
+            os: &affected.os,
+            functions: &affected.functions,
+        }
+    }
+}
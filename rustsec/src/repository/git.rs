@@ -2,13 +2,26 @@
 
 mod commit;
 mod commit_hash;
+mod credentials;
+mod fetch_options;
+mod fetch_status;
 #[cfg(feature = "osv-export")]
 mod gitpath;
 #[cfg(feature = "osv-export")]
 mod modification_time;
+mod object_cache;
 mod repository;
+mod shared_repository;
+mod transport_error;
 
-pub use self::{commit::Commit, commit_hash::CommitHash, repository::Repository};
+pub use self::{
+    commit::Commit,
+    commit_hash::CommitHash,
+    fetch_options::{FetchOptions, Transport, UrlMismatch, CA_BUNDLE_ENV_VAR},
+    fetch_status::FetchStatus,
+    repository::Repository,
+    shared_repository::SharedRepository,
+};
 use tame_index::external::gix;
 
 #[cfg(feature = "osv-export")]
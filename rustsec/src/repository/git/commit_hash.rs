@@ -1,7 +1,9 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use tame_index::external::gix;
 
+use crate::error::{Error, ErrorKind};
+
 /// ID (i.e. SHA-1 hash) of a git commit
 ///
 /// This is a wrapper around [gix::ObjectId] to prevent gix semver changes
@@ -53,3 +55,14 @@ impl Display for CommitHash {
         self.hash.fmt(f)
     }
 }
+
+impl FromStr for CommitHash {
+    type Err = Error;
+
+    /// Parse a full hexadecimal commit hash
+    fn from_str(s: &str) -> Result<Self, Error> {
+        gix::ObjectId::from_hex(s.as_bytes())
+            .map(Self::from_gix)
+            .map_err(|err| format_err!(ErrorKind::Parse, "invalid commit hash '{}': {}", s, err))
+    }
+}
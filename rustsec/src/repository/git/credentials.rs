@@ -0,0 +1,188 @@
+//! Reuse of credentials obtained from git credential helpers
+
+use super::repository::DIR;
+use std::{
+    collections::BTreeMap,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+use tame_index::external::gix::{
+    self,
+    bstr::BString,
+    credentials::{helper::Action, protocol},
+    protocol::transport::client::Transport,
+};
+
+/// Credentials obtained so far by this process.
+///
+/// They're only ever kept in memory, so nothing is written to disk other
+/// than what the configured credential helpers store themselves.
+static CACHE: Mutex<CredentialCache> = Mutex::new(CredentialCache::new());
+
+/// Remote a credential was obtained for
+type Key = (
+    Option<BString>,
+    Option<String>,
+    Option<String>,
+    Option<BString>,
+    Option<String>,
+);
+
+/// Credentials by the remote they were obtained for
+struct CredentialCache {
+    /// Successful lookups from the credential helpers
+    outcomes: BTreeMap<Key, protocol::Outcome>,
+
+    /// Credentials already passed to the helpers to be stored
+    stored: Vec<BString>,
+}
+
+impl CredentialCache {
+    const fn new() -> Self {
+        Self {
+            outcomes: BTreeMap::new(),
+            stored: Vec::new(),
+        }
+    }
+}
+
+/// Have `connection` use the credential helpers configured for its remote,
+/// reusing credentials they provided to earlier connections made by this
+/// process rather than asking for them again.
+pub(super) fn use_cached_credentials<T: Transport>(
+    connection: &mut gix::remote::Connection<'_, '_, T>,
+) -> Result<(), gix::config::credential_helpers::Error> {
+    if let Some(url) = connection.remote().url(DIR).cloned() {
+        let helper = connection.configured_credentials(url)?;
+        connection.set_credentials(cached(&CACHE, helper));
+    }
+
+    Ok(())
+}
+
+/// Wrap `helper` so credentials it provides are remembered in `cache`
+// The error type is gix's, which is what the connection expects
+#[allow(clippy::result_large_err)]
+fn cached<'a>(
+    cache: &'static Mutex<CredentialCache>,
+    mut helper: impl FnMut(Action) -> protocol::Result + 'a,
+) -> impl FnMut(Action) -> protocol::Result + 'a {
+    move |action| match action {
+        Action::Get(context) => {
+            let key = (
+                context.url.clone(),
+                context.protocol.clone(),
+                context.host.clone(),
+                context.path.clone(),
+                context.username.clone(),
+            );
+
+            if let Some(outcome) = lock(cache).outcomes.get(&key) {
+                return Ok(Some(outcome.clone()));
+            }
+
+            let outcome = helper(Action::Get(context))?;
+            if let Some(outcome) = &outcome {
+                lock(cache).outcomes.insert(key, outcome.clone());
+            }
+            Ok(outcome)
+        }
+        // Credentials which were reused are also approved again, but the
+        // helpers only need to be told once
+        Action::Store(credentials) => {
+            if lock(cache).stored.contains(&credentials) {
+                return Ok(None);
+            }
+
+            let result = helper(Action::Store(credentials.clone()))?;
+            lock(cache).stored.push(credentials);
+            Ok(result)
+        }
+        // Credentials were rejected, so none of them can be trusted anymore
+        Action::Erase(credentials) => {
+            *lock(cache) = CredentialCache::new();
+            helper(Action::Erase(credentials))
+        }
+    }
+}
+
+/// Lock the cache, which stays consistent even if a holder panicked
+fn lock(cache: &Mutex<CredentialCache>) -> MutexGuard<'_, CredentialCache> {
+    cache.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cached, CredentialCache};
+    use std::sync::Mutex;
+    use tame_index::external::gix::{
+        credentials::{
+            helper::{Action, NextAction},
+            protocol::{Context, Outcome},
+        },
+        sec::identity::Account,
+    };
+
+    /// Credential helper which counts how often it's asked for credentials
+    #[allow(clippy::result_large_err)]
+    fn counting_helper(gets: &mut usize) -> impl FnMut(Action) -> super::protocol::Result + '_ {
+        move |action| match action {
+            Action::Get(context) => {
+                *gets += 1;
+                Ok(Some(Outcome {
+                    identity: Account {
+                        username: "rustsec".into(),
+                        password: "hunter2".into(),
+                    },
+                    next: NextAction::from(context),
+                }))
+            }
+            Action::Store(_) | Action::Erase(_) => Ok(None),
+        }
+    }
+
+    fn get(url: &str) -> Action {
+        Action::Get(Context {
+            url: Some(url.into()),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn helper_is_asked_once_per_remote() {
+        static CACHE: Mutex<CredentialCache> = Mutex::new(CredentialCache::new());
+        let mut gets = 0;
+
+        // Two sequential fetches from the same remote, each with its own connection
+        for _ in 0..2 {
+            let mut credentials = cached(&CACHE, counting_helper(&mut gets));
+            let outcome = credentials(get("https://example.com/advisory-db.git"))
+                .unwrap()
+                .unwrap();
+            assert_eq!(outcome.identity.username, "rustsec");
+        }
+        assert_eq!(gets, 1);
+
+        let mut credentials = cached(&CACHE, counting_helper(&mut gets));
+        credentials(get("https://example.org/advisory-db.git")).unwrap();
+        drop(credentials);
+        assert_eq!(gets, 2);
+    }
+
+    #[test]
+    fn rejected_credentials_are_forgotten() {
+        static CACHE: Mutex<CredentialCache> = Mutex::new(CredentialCache::new());
+        let mut gets = 0;
+
+        let mut credentials = cached(&CACHE, counting_helper(&mut gets));
+        let url = "https://example.com/advisory-db.git";
+        credentials(get(url)).unwrap();
+        credentials(Action::Erase(
+            "url=https://example.com/advisory-db.git\n".into(),
+        ))
+        .unwrap();
+        credentials(get(url)).unwrap();
+        drop(credentials);
+
+        assert_eq!(gets, 2);
+    }
+}
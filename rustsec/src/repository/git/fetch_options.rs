@@ -0,0 +1,304 @@
+//! Options controlling how an advisory DB repository is fetched
+
+use crate::error::{Error, ErrorKind};
+use serde::{Deserialize, Serialize};
+use std::{env, ffi::OsString, fmt, path::PathBuf, str::FromStr, sync::Arc, time::Duration};
+use tame_index::external::gix::protocol::transport::client;
+
+/// Default amount of time to wait for the filesystem lock on a repository
+pub(crate) const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Environment variable containing the path to a CA certificate bundle
+pub const CA_BUNDLE_ENV_VAR: &str = "RUSTSEC_CA_BUNDLE";
+
+/// Connection to a git remote, as used by `gix` to clone and fetch.
+///
+/// Any [`client::Transport`] works. To bring your own HTTP client, implement
+/// the much smaller [`client::http::Http`] trait (a `get` and a `post`
+/// returning the response headers and a streaming body) and wrap it with
+/// [`http::Transport::new_http`](client::http::Transport::new_http) before
+/// passing it to [`Transport::new`].
+pub struct Transport(BoxedTransport);
+
+impl Transport {
+    /// Connect to the remote through the given `gix` transport
+    pub fn new(transport: impl client::Transport + Send + 'static) -> Self {
+        Self(Box::new(transport))
+    }
+
+    /// Get the `gix` transport back out
+    pub(super) fn into_inner(self) -> BoxedTransport {
+        self.0
+    }
+}
+
+impl fmt::Debug for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Transport(..)")
+    }
+}
+
+/// Transport type of the connections to remotes, custom or not
+pub(super) type BoxedTransport = Box<dyn client::Transport + Send>;
+
+/// Closure creating the [`Transport`] for a remote URL
+type CreateTransport = dyn Fn(&str) -> Result<Transport, Error> + Send + Sync;
+
+/// Shareable [`CreateTransport`] closure
+#[derive(Clone)]
+pub(super) struct TransportFactory(Arc<CreateTransport>);
+
+impl TransportFactory {
+    /// Create a transport for `url`
+    pub fn create(&self, url: &str) -> Result<BoxedTransport, Error> {
+        (self.0)(url).map(Transport::into_inner)
+    }
+}
+
+impl fmt::Debug for TransportFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TransportFactory(..)")
+    }
+}
+
+/// What [`Repository::fetch_with_options`](super::Repository::fetch_with_options)
+/// does when the repository already at the path was cloned from a different
+/// URL than the one being fetched, or has no `origin` remote.
+#[cfg_attr(docsrs, doc(cfg(feature = "git")))]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum UrlMismatch {
+    /// Fail with [`ErrorKind::Repo`](crate::ErrorKind::Repo), leaving the
+    /// repository untouched (default).
+    ///
+    /// This never fetches advisories from a source other than the one
+    /// asked for, nor deletes a directory which may not be a copy of the
+    /// advisory DB at all.
+    #[default]
+    Error,
+
+    /// Remove the repository and clone the requested URL in its place
+    Reclone,
+
+    /// Point the repository's `origin` at the requested URL and fetch from it,
+    /// keeping the objects already downloaded (e.g. when moving to a mirror)
+    UpdateRemote,
+}
+
+impl UrlMismatch {
+    /// Get a `str` representing this policy
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Reclone => "reclone",
+            Self::UpdateRemote => "update-remote",
+        }
+    }
+}
+
+impl fmt::Display for UrlMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for UrlMismatch {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "error" => Ok(Self::Error),
+            "reclone" => Ok(Self::Reclone),
+            "update-remote" => Ok(Self::UpdateRemote),
+            _ => Err(format_err!(
+                ErrorKind::Parse,
+                "invalid URL mismatch policy: {}",
+                s
+            )),
+        }
+    }
+}
+
+/// Options for [`Repository::fetch_with_options`](super::Repository::fetch_with_options).
+///
+/// The defaults match [`Repository::fetch_default_repo`](super::Repository::fetch_default_repo):
+/// the repository must be fresh and the lock is waited on for up to 5 minutes.
+/// Nothing is read from the environment unless asked for, e.g. with
+/// [`FetchOptions::ca_bundle_from_env`].
+#[cfg_attr(docsrs, doc(cfg(feature = "git")))]
+#[derive(Clone, Debug)]
+pub struct FetchOptions {
+    /// Fail if the latest commit to the repository is stale
+    pub(super) ensure_fresh: bool,
+
+    /// How long to wait for the filesystem lock on the repository
+    pub(super) lock_timeout: Duration,
+
+    /// Keep holding the lock in the fetched repository until it's dropped
+    pub(super) keep_lock: bool,
+
+    /// Bare repository used as a shared object store across clones
+    pub(super) object_cache: Option<PathBuf>,
+
+    /// PEM file of additional CA certificates trusted for HTTPS remotes
+    pub(super) ca_bundle: Option<PathBuf>,
+
+    /// Custom transport used instead of the one `gix` picks for the URL
+    pub(super) transport: Option<TransportFactory>,
+
+    /// Only fetch the latest commit rather than the full history
+    pub(super) shallow: bool,
+
+    /// What to do if the existing repository's `origin` is a different URL
+    pub(super) url_mismatch: UrlMismatch,
+}
+
+impl FetchOptions {
+    /// Create the default set of fetch options
+    pub fn new() -> Self {
+        Self {
+            ensure_fresh: true,
+            lock_timeout: DEFAULT_LOCK_TIMEOUT,
+            keep_lock: false,
+            object_cache: None,
+            ca_bundle: None,
+            transport: None,
+            shallow: false,
+            url_mismatch: UrlMismatch::default(),
+        }
+    }
+
+    /// Fail if the repository hasn't been updated in the last 90 days
+    pub fn ensure_fresh(mut self, setting: bool) -> Self {
+        self.ensure_fresh = setting;
+        self
+    }
+
+    /// Set how long to wait for the filesystem lock on the repository.
+    ///
+    /// A timeout of zero fails immediately if the lock is already held.
+    pub fn lock_timeout(mut self, timeout: Duration) -> Self {
+        self.lock_timeout = timeout;
+        self
+    }
+
+    /// Keep holding the exclusive lock taken while fetching in the returned
+    /// [`Repository`](super::Repository) until it's dropped.
+    ///
+    /// This lets the repository be checked out and loaded before anyone else
+    /// updates it. While it's held, other readers and fetches wait for it,
+    /// including ones in the same process.
+    pub fn keep_lock(mut self, setting: bool) -> Self {
+        self.keep_lock = setting;
+        self
+    }
+
+    /// Share git objects between clones through a bare repository at `path`.
+    ///
+    /// The cache is created if it doesn't exist. Fresh clones fetch into the
+    /// cache first and borrow its objects via `objects/info/alternates`, so
+    /// repositories with common history (e.g. forks of the advisory DB) only
+    /// download the objects they don't already share.
+    pub fn object_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.object_cache = Some(path.into());
+        self
+    }
+
+    /// Verify HTTPS remotes against the CA certificates in the PEM file at `path`.
+    ///
+    /// This is for mirrors using a private CA. Certificate verification is
+    /// always performed; this only changes which certificates are trusted.
+    pub fn ca_bundle(mut self, path: impl Into<PathBuf>) -> Self {
+        self.ca_bundle = Some(path.into());
+        self
+    }
+
+    /// Use the CA bundle named by the `RUSTSEC_CA_BUNDLE` environment
+    /// variable, if it's set and not empty, like [`FetchOptions::ca_bundle`].
+    ///
+    /// This is for command-line tools; libraries should take the path as
+    /// configuration instead.
+    pub fn ca_bundle_from_env(self) -> Self {
+        self.ca_bundle_from_var(env::var_os(CA_BUNDLE_ENV_VAR))
+    }
+
+    /// Use the CA bundle at `path` taken from an environment variable
+    fn ca_bundle_from_var(self, path: Option<OsString>) -> Self {
+        match path.filter(|path| !path.is_empty()) {
+            Some(path) => self.ca_bundle(path),
+            None => self,
+        }
+    }
+
+    /// Connect to remotes with the [`Transport`] returned by `factory`.
+    ///
+    /// The factory is called with the remote URL each time a connection is
+    /// made, both for the initial clone and for subsequent fetches, making
+    /// it possible to use an HTTP client with custom timeouts, client
+    /// certificates or metrics. Since the transport handles the connection
+    /// entirely, [`FetchOptions::ca_bundle`] doesn't apply to it.
+    pub fn transport<F>(mut self, factory: F) -> Self
+    where
+        F: Fn(&str) -> Result<Transport, Error> + Send + Sync + 'static,
+    {
+        self.transport = Some(TransportFactory(Arc::new(factory)));
+        self
+    }
+
+    /// Only fetch the latest commit, along with the trees and blobs it needs.
+    ///
+    /// This makes the initial clone much smaller, since none of the history
+    /// is downloaded. `gix` can't make blobless partial clones
+    /// (`--filter=blob:none`) which fetch missing blobs on demand, so this is
+    /// the closest it gets: everything needed to load the current database
+    /// is present, but operations which need the history
+    /// ([`Repository::checkout_commit`](super::Repository::checkout_commit) and
+    /// [`Repository::added_advisories`](super::Repository::added_advisories))
+    /// fail on shallow clones.
+    ///
+    /// Later fetches keep the repository shallow. This has no effect on
+    /// clones made through an [`FetchOptions::object_cache`].
+    pub fn shallow(mut self, setting: bool) -> Self {
+        self.shallow = setting;
+        self
+    }
+
+    /// Choose what happens if the repository already at the path was cloned
+    /// from a different URL (default: [`UrlMismatch::Error`]).
+    pub fn url_mismatch(mut self, policy: UrlMismatch) -> Self {
+        self.url_mismatch = policy;
+        self
+    }
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FetchOptions;
+    use std::path::Path;
+
+    #[test]
+    fn ca_bundle_from_env() {
+        let options = FetchOptions::new();
+        assert_eq!(options.ca_bundle, None);
+
+        let options = options.ca_bundle_from_var(Some("".into()));
+        assert_eq!(options.ca_bundle, None);
+
+        let options = options.ca_bundle_from_var(Some("/etc/ssl/private-ca.pem".into()));
+        assert_eq!(
+            options.ca_bundle.as_deref(),
+            Some(Path::new("/etc/ssl/private-ca.pem"))
+        );
+
+        // An unset variable keeps the bundle configured so far
+        let options = options.ca_bundle("/opt/ca.pem").ca_bundle_from_var(None);
+        assert_eq!(options.ca_bundle.as_deref(), Some(Path::new("/opt/ca.pem")));
+    }
+}
@@ -0,0 +1,36 @@
+//! How a repository was obtained by a fetch
+
+use std::fmt;
+
+/// How [`Repository::fetch`](super::Repository::fetch) obtained the
+/// repository, e.g. to explain a slow run or stale results.
+#[cfg_attr(docsrs, doc(cfg(feature = "git")))]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum FetchStatus {
+    /// There was no usable checkout, so the repository was cloned
+    FreshClone,
+
+    /// An existing checkout was updated with new commits
+    Fetched,
+
+    /// An existing checkout already had the latest commit
+    UpToDate,
+}
+
+impl FetchStatus {
+    /// Get a `str` describing this status
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::FreshClone => "fresh clone",
+            Self::Fetched => "updated",
+            Self::UpToDate => "up to date",
+        }
+    }
+}
+
+impl fmt::Display for FetchStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
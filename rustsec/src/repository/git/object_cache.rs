@@ -0,0 +1,182 @@
+//! Shared object store for advisory DB repositories with common history
+
+use tame_index::external::gix;
+
+use super::{
+    fetch_options::TransportFactory,
+    repository::{connect, set_committer, set_origin_url, DIR},
+    transport_error::transport_error,
+};
+use crate::{
+    error::{Error, ErrorKind},
+    fs,
+};
+use std::{io::Write, path::Path};
+
+/// Bare repository which objects from every fetched remote are stored in.
+///
+/// Each remote's `HEAD` is kept under its own ref so that fetching a
+/// related remote (e.g. a fork) negotiates against everything already
+/// cached and only transfers the objects which are actually new.
+pub(super) struct ObjectCache {
+    repo: gix::Repository,
+
+    /// Custom transport used to fetch into the cache
+    transport: Option<TransportFactory>,
+}
+
+impl ObjectCache {
+    /// Open the object cache at the given path, creating it if needed.
+    ///
+    /// If `ca_bundle` is given, HTTPS remotes are verified against it.
+    /// If `transport` is given, it's used to connect to remotes instead.
+    pub fn open(
+        path: &Path,
+        ca_bundle: Option<&Path>,
+        transport: Option<TransportFactory>,
+    ) -> Result<Self, Error> {
+        let mut repo = if path.is_dir() && fs::read_dir(path)?.next().is_some() {
+            gix::open(path).map_err(|err| {
+                format_err!(
+                    ErrorKind::Repo,
+                    "failed to open object cache at '{}': {}",
+                    path.display(),
+                    err
+                )
+            })?
+        } else {
+            gix::init_bare(path).map_err(|err| {
+                format_err!(
+                    ErrorKind::Repo,
+                    "failed to create object cache at '{}': {}",
+                    path.display(),
+                    err
+                )
+            })?
+        };
+
+        if let Some(ca_bundle) = ca_bundle {
+            let mut config = repo.config_snapshot_mut();
+            config
+                .set_raw_value_by(
+                    "http",
+                    None,
+                    "sslCAInfo",
+                    ca_bundle.to_string_lossy().as_ref(),
+                )
+                .map_err(|err| {
+                    format_err!(ErrorKind::Repo, "failed to set `http.sslCAInfo`: {}", err)
+                })?;
+            config.commit().map_err(|err| {
+                format_err!(ErrorKind::Repo, "failed to set `http.sslCAInfo`: {}", err)
+            })?;
+        }
+
+        Ok(Self { repo, transport })
+    }
+
+    /// Fetch the remote `HEAD` of `url` into the cache, returning its commit
+    pub fn fetch(&self, url: &str) -> Result<gix::ObjectId, Error> {
+        let ref_name = format!("refs/rustsec/{}/HEAD", cache_key(url));
+        let refspec = format!("+HEAD:{}", ref_name);
+
+        let remote = self
+            .repo
+            .remote_at(url)
+            .map_err(|err| format_err!(ErrorKind::Repo, "invalid remote URL {}: {}", url, err))?
+            .with_refspecs([refspec.as_str()], DIR)
+            .map_err(|err| format_err!(ErrorKind::Repo, "invalid refspec: {}", err))?;
+
+        connect(&remote, url, self.transport.as_ref())?
+            .prepare_fetch(&mut gix::progress::Discard, Default::default())
+            .map_err(|err| transport_error(url, "failed to prepare fetch", err))?
+            .receive(&mut gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
+            .map_err(|err| transport_error(url, "failed to fetch", err))?;
+
+        let id = self
+            .repo
+            .find_reference(ref_name.as_str())
+            .map_err(|err| format_err!(ErrorKind::Repo, "unable to locate {}: {}", ref_name, err))?
+            .peel_to_id_in_place()
+            .map_err(|err| format_err!(ErrorKind::Repo, "unable to peel {}: {}", ref_name, err))?
+            .detach();
+
+        Ok(id)
+    }
+
+    /// Create a new repository at `path` whose `origin` is `url`, seeded
+    /// with the cached objects for that remote rather than a network clone.
+    ///
+    /// The returned repository still needs a regular fetch and checkout,
+    /// but the fetch will only transfer objects the cache doesn't have.
+    pub fn clone_into(&self, url: &str, path: &Path) -> Result<gix::Repository, Error> {
+        let commit_id = self.fetch(url)?;
+
+        let repo = gix::init(path).map_err(|err| {
+            format_err!(
+                ErrorKind::Repo,
+                "failed to initialize repository at '{}': {}",
+                path.display(),
+                err
+            )
+        })?;
+
+        self.link(repo.path())?;
+
+        set_origin_url(&repo, url)?;
+
+        // Reopen so the object database picks up the alternates and the
+        // configuration picks up the new remote
+        let mut repo = gix::open(path).map_err(|err| {
+            format_err!(
+                ErrorKind::Repo,
+                "failed to open repository at '{}': {}",
+                path.display(),
+                err
+            )
+        })?;
+
+        let mut config = repo.config_snapshot_mut();
+        set_committer(&mut config)?;
+        config
+            .commit()
+            .map_err(|err| format_err!(ErrorKind::Repo, "failed to set `committer`: {}", err))?;
+
+        repo.reference(
+            "refs/remotes/origin/HEAD",
+            commit_id,
+            gix::refs::transaction::PreviousValue::Any,
+            "rustsec: seeded from object cache",
+        )
+        .map_err(|err| format_err!(ErrorKind::Repo, "failed to seed origin/HEAD: {}", err))?;
+
+        Ok(repo)
+    }
+
+    /// Register the cache in the `objects/info/alternates` of the given git dir
+    fn link(&self, git_dir: &Path) -> Result<(), Error> {
+        let objects = fs::canonicalize(self.repo.path().join("objects"))?;
+        let info_dir = git_dir.join("objects").join("info");
+        fs::create_dir_all(&info_dir)?;
+
+        let alternates_path = info_dir.join("alternates");
+        let alternates = fs::read_to_string(&alternates_path).unwrap_or_default();
+
+        if !alternates.lines().any(|line| Path::new(line) == objects) {
+            let mut file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&alternates_path)?;
+            writeln!(file, "{}", objects.display())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Turn a remote URL into a string usable as a single ref name component
+fn cache_key(url: &str) -> String {
+    url.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
@@ -1,12 +1,24 @@
 //! Git repositories
-use tame_index::{external::gix, utils::flock::LockOptions};
+use tame_index::{
+    external::gix,
+    utils::flock::{FileLock, LockOptions},
+};
 
-use super::{Commit, DEFAULT_URL};
+use super::{
+    credentials,
+    fetch_options::{BoxedTransport, TransportFactory},
+    object_cache::ObjectCache,
+    transport_error::transport_error,
+    Commit, CommitHash, FetchOptions, FetchStatus, SharedRepository, UrlMismatch, DEFAULT_URL,
+};
 use crate::{
+    advisory,
+    collection::Collection,
     error::{Error, ErrorKind},
     fs,
 };
 use std::{
+    num::NonZeroU32,
     path::{Path, PathBuf},
     time::Duration,
 };
@@ -15,18 +27,32 @@ use std::{
 const ADVISORY_DB_DIRECTORY: &str = "advisory-db";
 
 /// Refspec used to fetch updates from remote advisory databases
-const REF_SPEC: &str = "+HEAD:refs/remotes/origin/HEAD";
+pub(super) const REF_SPEC: &str = "+HEAD:refs/remotes/origin/HEAD";
 
 /// The direction of the remote
-const DIR: gix::remote::Direction = gix::remote::Direction::Fetch;
-
-const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+pub(super) const DIR: gix::remote::Direction = gix::remote::Direction::Fetch;
 
 /// Git repository for a Rust advisory DB.
 #[cfg_attr(docsrs, doc(cfg(feature = "git")))]
 pub struct Repository {
     /// Repository object
     pub(super) repo: gix::Repository,
+
+    /// Lock held on the repository until it's dropped, if any
+    pub(super) lock: Option<Lock>,
+
+    /// How the repository was obtained, if it was fetched
+    pub(super) fetch_status: Option<FetchStatus>,
+}
+
+/// Filesystem lock held by a [`Repository`]
+pub(super) struct Lock {
+    /// Is this the exclusive lock taken by [`Repository::fetch`] and
+    /// [`Repository::open_with_write_lock`], rather than a shared one?
+    exclusive: bool,
+
+    /// Lock itself, released when dropped
+    _lock: FileLock,
 }
 
 impl Repository {
@@ -46,11 +72,10 @@ impl Repository {
     /// It will fail with [`rustsec::Error::LockTimeout`](Error) if the lock is still held
     /// after that time. Use [Repository::fetch] if you need to configure locking behavior.
     pub fn fetch_default_repo() -> Result<Self, Error> {
-        Self::fetch(
+        Self::fetch_with_options(
             DEFAULT_URL,
             Repository::default_path(),
-            true,
-            DEFAULT_LOCK_TIMEOUT,
+            &FetchOptions::default(),
         )
     }
 
@@ -64,20 +89,65 @@ impl Repository {
     ///
     /// If `lock_timeout` is set to `std::time::Duration::from_secs(0)`, it will not wait at all,
     /// and instead return an error immediately if it fails to aquire the lock.
+    ///
+    /// The lock is held exclusively, so this also waits for readers which opened
+    /// the repository with [`Repository::open_with_read_lock`]. It's released
+    /// once the fetch is done, unless [`FetchOptions::keep_lock`] is set.
     pub fn fetch<P: Into<PathBuf>>(
         url: &str,
         into_path: P,
         ensure_fresh: bool,
         lock_timeout: Duration,
     ) -> Result<Self, Error> {
-        if !url.starts_with("https://") {
+        Self::fetch_with_options(
+            url,
+            into_path,
+            &FetchOptions::new()
+                .ensure_fresh(ensure_fresh)
+                .lock_timeout(lock_timeout),
+        )
+    }
+
+    /// Create a new [`Repository`] with the given URL and path, and fetch its contents
+    /// according to the given [`FetchOptions`].
+    ///
+    /// Besides `https://` URLs, local `file://` URLs are accepted (e.g. for mirrors).
+    ///
+    /// If `into_path` holds a clone which was interrupted, it's resumed by
+    /// fetching if possible, and otherwise removed and cloned again. If it
+    /// holds a repository cloned from a different URL, what happens is up
+    /// to [`FetchOptions::url_mismatch`]: by default, fetching fails.
+    ///
+    /// ## Locking
+    ///
+    /// See [`Repository::fetch`].
+    pub fn fetch_with_options<P: Into<PathBuf>>(
+        url: &str,
+        into_path: P,
+        options: &FetchOptions,
+    ) -> Result<Self, Error> {
+        trace_span!("repo.fetch", url);
+
+        if !url.starts_with("https://") && !url.starts_with("file://") {
             fail!(
                 ErrorKind::BadParam,
-                "expected {} to start with https://",
+                "expected {} to start with https:// or file://",
                 url
             );
         }
 
+        if let Some(ca_bundle) = &options.ca_bundle {
+            if !ca_bundle.is_file() {
+                fail!(
+                    ErrorKind::BadParam,
+                    "CA bundle not found at '{}'",
+                    ca_bundle.display()
+                );
+            }
+        }
+
+        let lock_timeout = options.lock_timeout;
+
         let path = into_path.into();
 
         if let Some(parent) = path.parent() {
@@ -99,34 +169,36 @@ impl Repository {
         // Lock the directory to avoid several checkouts running at the same time trampling on each other.
         // We do not use Git locks because they have undesirable properties - they leave stale locks on SIGKILL or power loss
         // with no way to recover. They don't even write the PID to the lockfile.
-        let lock_path = tame_index::Path::from_path(&path)
-            .ok_or_else(|| {
-                format_err!(
-                    ErrorKind::BadParam,
-                    "Path to the advisory DB directory is not valid UTF-8!"
-                )
-            })?
-            .with_extension(".lock");
-        let lock_opts = LockOptions::new(&lock_path).exclusive(false);
-        let _lock = if lock_timeout == Duration::from_secs(0) {
-            lock_opts.try_lock()
-        } else {
-            lock_opts.lock(|_| Some(lock_timeout))
+        let lock_path = lock_path(&path)?;
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        let lock = acquire_lock(LockOptions::new(&lock_path).exclusive(false), lock_timeout)?;
+
+        trace_event!(
+            name: "repo.lock",
+            path = %lock_path,
+            duration_ms = started.elapsed().as_millis() as u64,
+            "acquired advisory DB lock"
+        );
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        // A clone which was interrupted (e.g. by a dropped connection or a
+        // killed process) would otherwise fail on every later run, since
+        // there's nothing to fetch into and it can't be cloned over
+        if is_partial_clone(&path) {
+            trace_event!(
+                name: "repo.reset",
+                path = %path.display(),
+                "removing interrupted clone of advisory DB"
+            );
+            fs::remove_dir_all(&path)?;
         }
-        .map_err(Error::from_tame)?;
 
         let open_or_clone_repo = || -> Result<_, Error> {
             let mut mapping = gix::sec::trust::Mapping::default();
-            let open_with_complete_config =
-                gix::open::Options::default().permissions(gix::open::Permissions {
-                    config: gix::open::permissions::Config {
-                        // Be sure to get all configuration, some of which is only known by the git binary.
-                        // That way we are sure to see all the systems credential helpers
-                        git_binary: true,
-                        ..Default::default()
-                    },
-                    ..Default::default()
-                });
+            let open_with_complete_config = open_options();
 
             mapping.reduced = open_with_complete_config.clone();
             mapping.full = open_with_complete_config.clone();
@@ -140,17 +212,65 @@ impl Repository {
             )
             .ok()
             .map(|repo| repo.to_thread_local())
-            .filter(|repo| {
-                repo.find_remote("origin").map_or(false, |remote| {
-                    remote
-                        .url(DIR)
-                        .map_or(false, |remote_url| remote_url.to_bstring() == url)
-                })
-            })
-            .or_else(|| gix::open_opts(&path, open_with_complete_config).ok());
+            .filter(|repo| origin_url(repo).map_or(false, |origin| is_same_url(&origin, url)))
+            .or_else(|| gix::open_opts(&path, open_with_complete_config.clone()).ok());
+
+            let repo = match repo {
+                Some(repo) => match origin_url(&repo) {
+                    Some(origin) if is_same_url(&origin, url) => Some(repo),
+                    origin => match options.url_mismatch {
+                        UrlMismatch::Error => fail!(
+                            ErrorKind::Repo,
+                            "advisory DB at '{}' {} rather than {}; \
+                             remove it or use a different path",
+                            path.display(),
+                            origin.map_or("has no `origin` remote".to_owned(), |origin| {
+                                format!("was cloned from {}", origin.to_bstring())
+                            }),
+                            url
+                        ),
+                        UrlMismatch::Reclone => {
+                            trace_event!(
+                                name: "repo.reset",
+                                path = %path.display(),
+                                "removing advisory DB cloned from a different URL"
+                            );
+                            drop(repo);
+                            fs::remove_dir_all(&path)?;
+                            None
+                        }
+                        UrlMismatch::UpdateRemote => {
+                            set_origin_url(&repo, url)?;
+                            // Reopen so the configuration picks up the new URL
+                            Some(gix::open_opts(&path, open_with_complete_config).map_err(
+                                |err| {
+                                    format_err!(
+                                        ErrorKind::Repo,
+                                        "failed to open repository at '{}': {}",
+                                        path.display(),
+                                        err
+                                    )
+                                },
+                            )?)
+                        }
+                    },
+                },
+                None => None,
+            };
 
             let res = if let Some(repo) = repo {
-                (repo, None)
+                (repo, None, false)
+            } else if let Some(cache_path) = &options.object_cache {
+                let cache = ObjectCache::open(
+                    cache_path,
+                    options.ca_bundle.as_deref(),
+                    options.transport.clone(),
+                )?;
+                (cache.clone_into(url, &path)?, None, true)
+            } else if options.transport.is_some() {
+                // `gix::prepare_clone` always picks its own transport, so
+                // start from an empty repository and fetch into it instead
+                (init_with_origin(url, &path)?, None, true)
             } else {
                 let mut progress = gix::progress::Discard;
                 let should_interrupt = &gix::interrupt::IS_INTERRUPTED;
@@ -159,11 +279,17 @@ impl Repository {
                     .map_err(|err| {
                         format_err!(ErrorKind::Repo, "failed to prepare clone: {}", err)
                     })?
+                    .with_in_memory_config_overrides(config_overrides(options))
                     .with_remote_name("origin")
                     .map_err(|err| format_err!(ErrorKind::Repo, "invalid remote name: {}", err))?
                     .configure_remote(|remote| Ok(remote.with_refspecs([REF_SPEC], DIR)?))
+                    .with_shallow(shallow(options))
+                    .configure_connection(|connection| {
+                        credentials::use_cached_credentials(connection)?;
+                        Ok(())
+                    })
                     .fetch_then_checkout(&mut progress, should_interrupt)
-                    .map_err(|err| format_err!(ErrorKind::Repo, "failed to fetch repo: {}", err))?;
+                    .map_err(|err| transport_error(url, "failed to fetch repo", err))?;
 
                 let repo = prep_checkout
                     .main_worktree(&mut progress, should_interrupt)
@@ -172,36 +298,67 @@ impl Repository {
                     })?
                     .0;
 
-                (repo, Some(out))
+                (repo, Some(out), true)
             };
 
             Ok(res)
         };
 
-        let (mut repo, fetch_outcome) = open_or_clone_repo()?;
+        let (mut repo, fetch_outcome, cloned) = open_or_clone_repo()?;
 
-        if let Some(fetch_outcome) = fetch_outcome {
+        let fetch_status = if let Some(fetch_outcome) = fetch_outcome {
             tame_index::utils::git::write_fetch_head(
                 &repo,
                 &fetch_outcome,
                 &repo.find_remote("origin").unwrap(),
             )
             .map_err(Error::from_tame)?;
+
+            trace_event!(
+                name: "repo.clone",
+                duration_ms = started.elapsed().as_millis() as u64,
+                "cloned advisory DB"
+            );
+
+            FetchStatus::FreshClone
         } else {
+            let previous_head = repo.head_id().ok().map(|id| id.detach());
+
             // If we didn't open a fresh repo we need to peform a fetch ourselves, and
             // do the work of updating the HEAD to point at the latest remote HEAD, which
             // gix doesn't currently do.
-            Self::perform_fetch(&mut repo)?;
-        }
+            Self::perform_fetch(&mut repo, options)?;
+
+            let fetch_status = if cloned {
+                FetchStatus::FreshClone
+            } else if repo.head_id().ok().map(|id| id.detach()) == previous_head {
+                FetchStatus::UpToDate
+            } else {
+                FetchStatus::Fetched
+            };
+
+            trace_event!(
+                name: "repo.fetch",
+                duration_ms = started.elapsed().as_millis() as u64,
+                status = %fetch_status,
+                "fetched advisory DB"
+            );
+
+            fetch_status
+        };
 
         repo.object_cache_size_if_unset(4 * 1024 * 1024);
-        let repo = Self { repo };
+        let mut repo = Self {
+            repo,
+            lock: None,
+            fetch_status: Some(fetch_status),
+        };
 
         let latest_commit = Commit::from_repo_head(&repo)?;
         latest_commit.reset(&repo)?;
 
         // Ensure that the upstream repository hasn't gone stale
-        if ensure_fresh && !latest_commit.is_fresh() {
+        if options.ensure_fresh && !latest_commit.is_fresh() {
             fail!(
                 ErrorKind::Repo,
                 "repository is stale (last commit: {:?})",
@@ -209,6 +366,13 @@ impl Repository {
             );
         }
 
+        if options.keep_lock {
+            repo.lock = Some(Lock {
+                exclusive: true,
+                _lock: lock,
+            });
+        }
+
         Ok(repo)
     }
 
@@ -226,7 +390,361 @@ impl Repository {
 
         // TODO: Figure out how to detect if the worktree has modifications
         // as gix currently doesn't have a status/state summary like git2 has
-        Ok(Self { repo })
+        Ok(Self {
+            repo,
+            lock: None,
+            fetch_status: None,
+        })
+    }
+
+    /// Open a repository at the given path while holding a shared lock on it.
+    ///
+    /// Any number of readers can hold the shared lock at once, but it excludes
+    /// [`Repository::fetch`], which holds the lock exclusively while updating
+    /// the checkout. This waits for up to `lock_timeout` for an in-progress
+    /// fetch to finish, so the worktree is never read while half-written,
+    /// and fetches wait until the returned repository is dropped.
+    ///
+    /// A `lock_timeout` of zero fails immediately with
+    /// [`ErrorKind::LockTimeout`] if a fetch is in progress.
+    pub fn open_with_read_lock<P: Into<PathBuf>>(
+        into_path: P,
+        lock_timeout: Duration,
+    ) -> Result<Self, Error> {
+        let path = into_path.into();
+        let lock_path = lock_path(&path)?;
+        let lock = acquire_lock(LockOptions::new(&lock_path).shared(), lock_timeout)?;
+
+        let mut repo = Self::open(path)?;
+        repo.lock = Some(Lock {
+            exclusive: false,
+            _lock: lock,
+        });
+        Ok(repo)
+    }
+
+    /// Open a repository at the given path while holding the exclusive lock
+    /// taken by [`Repository::fetch`], e.g. to check out a commit with
+    /// [`Repository::checkout_commit`] and load it before anyone else reads
+    /// or updates the checkout.
+    ///
+    /// This waits for up to `lock_timeout` for readers and fetches to finish,
+    /// and a `lock_timeout` of zero fails immediately with
+    /// [`ErrorKind::LockTimeout`] if the lock is held.
+    pub fn open_with_write_lock<P: Into<PathBuf>>(
+        into_path: P,
+        lock_timeout: Duration,
+    ) -> Result<Self, Error> {
+        let path = into_path.into();
+        let lock_path = lock_path(&path)?;
+        let lock = acquire_lock(LockOptions::new(&lock_path).exclusive(false), lock_timeout)?;
+
+        let mut repo = Self::open(path)?;
+        repo.lock = Some(Lock {
+            exclusive: true,
+            _lock: lock,
+        });
+        Ok(repo)
+    }
+
+    /// How the repository was obtained by [`Repository::fetch`]: whether it
+    /// was freshly cloned, or an existing checkout was updated or already up
+    /// to date.
+    ///
+    /// This is `None` for repositories which were opened rather than fetched.
+    pub fn fetch_status(&self) -> Option<FetchStatus> {
+        self.fetch_status
+    }
+
+    /// Convert into a [`SharedRepository`] which can be shared between threads
+    pub fn into_shared(self) -> SharedRepository {
+        self.into()
+    }
+
+    /// Check out the given commit, e.g. to audit against the exact state of
+    /// the database recorded alongside a release.
+    ///
+    /// `HEAD` is detached at the commit and the worktree is reset to match it,
+    /// including removing advisories which were added after it. The commit
+    /// must already be present locally, so it must not be older than the
+    /// history fetched into a shallow clone.
+    ///
+    /// The checkout stays at the commit until it's checked out again or
+    /// fetched. Modifying it requires the exclusive lock: unless this
+    /// repository holds it (as it does if it was opened with
+    /// [`Repository::open_with_write_lock`] or fetched with
+    /// [`FetchOptions::keep_lock`]), it's taken while checking out,
+    /// and this fails immediately with [`ErrorKind::LockTimeout`] if it's held
+    /// elsewhere (including by this repository, if it was opened with
+    /// [`Repository::open_with_read_lock`]).
+    pub fn checkout_commit(&self, id: CommitHash) -> Result<(), Error> {
+        self.ensure_full_history("check out an older commit")?;
+        let _lock = self.write_lock()?;
+
+        let commit = self
+            .repo
+            .find_object(id.to_gix())
+            .ok()
+            .and_then(|object| object.try_into_commit().ok())
+            .ok_or_else(|| {
+                format_err!(
+                    ErrorKind::NotFound,
+                    "commit {} not found in advisory database at '{}'",
+                    id,
+                    self.path().display()
+                )
+            })?;
+
+        // Files tracked at the current commit, so those which don't exist at
+        // the target commit can be removed after checking it out
+        let index = self
+            .repo
+            .index_or_empty()
+            .map_err(|err| format_err!(ErrorKind::Repo, "failed to read index: {}", err))?;
+        let previously_tracked = index
+            .entries()
+            .iter()
+            .map(|entry| entry.path(&index).to_owned())
+            .collect::<Vec<_>>();
+        drop(index);
+
+        // Updating the reflog requires a committer, as when fetching
+        let mut repo = self.repo.clone();
+        let mut config = repo.config_snapshot_mut();
+        set_committer(&mut config)?;
+        let repo = config
+            .commit_auto_rollback()
+            .map_err(|err| format_err!(ErrorKind::Repo, "failed to set `committer`: {}", err))?;
+
+        use gix::refs::{transaction as tx, Target};
+        repo.edit_reference(tx::RefEdit {
+            change: tx::Change::Update {
+                log: tx::LogChange {
+                    mode: tx::RefLog::AndReference,
+                    force_create_reflog: false,
+                    message: format!("rustsec: checkout {}", id).into(),
+                },
+                expected: tx::PreviousValue::Any,
+                new: Target::Object(commit.id),
+            },
+            name: "HEAD".try_into().unwrap(),
+            deref: false,
+        })
+        .map_err(|err| format_err!(ErrorKind::Repo, "failed to update HEAD: {}", err))?;
+
+        Commit::from_repo_head(self)?.reset(self)?;
+
+        let index = self
+            .repo
+            .index_or_empty()
+            .map_err(|err| format_err!(ErrorKind::Repo, "failed to read index: {}", err))?;
+
+        for path in previously_tracked {
+            if index.entry_by_path(path.as_ref()).is_none() {
+                let path = self.path().join(gix::path::from_bstr(&path));
+                match fs::remove_file(path) {
+                    Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                        return Err(err.into())
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fail with a clear error if this is a shallow clone (see
+    /// [`FetchOptions::shallow`]), which doesn't have the history needed to
+    /// `operation`
+    fn ensure_full_history(&self, operation: &str) -> Result<(), Error> {
+        if self.repo.is_shallow() {
+            fail!(
+                ErrorKind::Repo,
+                "can't {}: advisory database at '{}' is a shallow clone without \
+                 its history (clone it again without `FetchOptions::shallow`)",
+                operation,
+                self.path().display()
+            );
+        }
+
+        Ok(())
+    }
+
+    /// IDs of the advisories added between the commit `since` and `HEAD`.
+    ///
+    /// This is a diff of the trees of the two commits, so it can be used to
+    /// report which advisories are new since a previously fetched commit.
+    /// Advisories which were only modified or moved aren't included.
+    pub fn added_advisories(&self, since: CommitHash) -> Result<Vec<advisory::Id>, Error> {
+        use gix::{bstr::ByteSlice, diff::tree::recorder::Change, prelude::Find};
+
+        self.ensure_full_history("find the advisories added since a commit")?;
+
+        let tree_id = |id: CommitHash| {
+            self.repo
+                .find_object(id.to_gix())
+                .ok()
+                .and_then(|object| object.try_into_commit().ok())
+                .and_then(|commit| commit.tree_id().ok())
+                .map(|tree_id| tree_id.detach())
+                .ok_or_else(|| {
+                    format_err!(
+                        ErrorKind::NotFound,
+                        "commit {} not found in advisory database at '{}'",
+                        id,
+                        self.path().display()
+                    )
+                })
+        };
+
+        let previous_tree_id = tree_id(since)?;
+        let current_tree_id = tree_id(self.latest_commit()?.commit_id)?;
+
+        let db = &self.repo.objects;
+        let mut buf = Vec::new();
+        let mut buf2 = Vec::new();
+
+        fn find_tree<'a>(
+            db: &gix::OdbHandle,
+            id: &gix::ObjectId,
+            buf: &'a mut Vec<u8>,
+        ) -> Result<gix::objs::TreeRefIter<'a>, Error> {
+            db.try_find(id, buf)
+                .map_err(|err| format_err!(ErrorKind::Repo, "failed to find tree {}: {}", id, err))?
+                .and_then(|data| data.try_into_tree_iter())
+                .ok_or_else(|| format_err!(ErrorKind::Repo, "tree {} not present", id))
+        }
+
+        let previous_tree = find_tree(db, &previous_tree_id, &mut buf)?;
+        let current_tree = find_tree(db, &current_tree_id, &mut buf2)?;
+
+        let mut recorder = gix::diff::tree::Recorder::default();
+        gix::diff::tree::Changes::from(Some(previous_tree))
+            .needed_to_obtain(
+                current_tree,
+                &mut gix::diff::tree::State::default(),
+                db,
+                &mut recorder,
+            )
+            .map_err(|err| {
+                format_err!(
+                    ErrorKind::Repo,
+                    "failed to diff commit {} to HEAD: {}",
+                    since,
+                    err
+                )
+            })?;
+
+        // Advisories are located at `<collection>/<package>/<id>.md`
+        let advisory_id = |path: &gix::bstr::BStr| {
+            let path = path.to_str_lossy();
+            let segments: Vec<_> = path.split('/').collect();
+            let [collection, _package, file_name] = segments.as_slice() else {
+                return None;
+            };
+
+            collection.parse::<Collection>().ok()?;
+            file_name
+                .strip_suffix(".md")
+                .and_then(|id| id.parse::<advisory::Id>().ok())
+                .filter(|id| !id.is_placeholder())
+        };
+
+        let mut ids = vec![];
+        let mut removed = vec![];
+
+        for change in recorder.records {
+            match change {
+                Change::Addition { path, .. } => ids.extend(advisory_id(path.as_ref())),
+                Change::Deletion { path, .. } => removed.extend(advisory_id(path.as_ref())),
+                Change::Modification { .. } => (),
+            }
+        }
+
+        // An advisory which was moved (e.g. when its crate was renamed) is
+        // deleted at its old path and added at its new one, but isn't new
+        ids.retain(|id| !removed.contains(id));
+
+        ids.sort();
+        ids.dedup();
+        Ok(ids)
+    }
+
+    /// Find advisory files in the checkout which aren't in the `HEAD` commit.
+    ///
+    /// These are usually left over from advisories which were removed
+    /// upstream, in a checkout which wasn't updated cleanly, and would still
+    /// be loaded into the database. Paths are relative to [`Repository::path`].
+    pub fn orphaned_advisories(&self) -> Result<Vec<PathBuf>, Error> {
+        let tree = self
+            .repo
+            .head_commit()
+            .ok()
+            .and_then(|commit| commit.tree().ok())
+            .ok_or_else(|| {
+                format_err!(
+                    ErrorKind::Repo,
+                    "couldn't read HEAD tree of advisory database at '{}'",
+                    self.path().display()
+                )
+            })?;
+
+        let mut orphaned = vec![];
+        let mut buf = Vec::new();
+
+        for collection in Collection::all() {
+            let Ok(packages) = fs::read_dir(self.path().join(collection.as_str())) else {
+                continue;
+            };
+
+            for package in packages {
+                let package = package?;
+                if !package.file_type()?.is_dir() {
+                    continue;
+                }
+
+                for advisory in fs::read_dir(package.path())? {
+                    let file_name = advisory?.file_name();
+                    let is_advisory = file_name.to_str().map_or(false, |name| {
+                        !name.starts_with('.') && name.ends_with(".md")
+                    });
+
+                    if !is_advisory {
+                        continue;
+                    }
+
+                    let relative_path = Path::new(collection.as_str())
+                        .join(package.file_name())
+                        .join(file_name);
+
+                    if !matches!(
+                        tree.lookup_entry_by_path(&relative_path, &mut buf),
+                        Ok(Some(_))
+                    ) {
+                        orphaned.push(relative_path);
+                    }
+                }
+            }
+        }
+
+        orphaned.sort();
+        Ok(orphaned)
+    }
+
+    /// Take the exclusive lock for modifying the checkout, unless this
+    /// repository already holds it, failing immediately if it's held elsewhere
+    fn write_lock(&self) -> Result<Option<FileLock>, Error> {
+        if self.lock.as_ref().map_or(false, |lock| lock.exclusive) {
+            return Ok(None);
+        }
+
+        let lock_path = lock_path(self.path())?;
+        acquire_lock(
+            LockOptions::new(&lock_path).exclusive(false),
+            Duration::from_secs(0),
+        )
+        .map(Some)
     }
 
     /// Get information about the latest commit to the repo
@@ -256,20 +774,21 @@ impl Repository {
         lookup().unwrap_or_default()
     }
 
-    fn perform_fetch(repo: &mut gix::Repository) -> Result<(), Error> {
+    fn perform_fetch(repo: &mut gix::Repository, options: &FetchOptions) -> Result<(), Error> {
         let mut config = repo.config_snapshot_mut();
-        config
-            .set_raw_value_by("committer", None, "name", "rustsec")
-            .map_err(|err| {
-                format_err!(ErrorKind::Repo, "failed to set `committer.name`: {}", err)
-            })?;
-        // Note we _have_ to set the email as well, but luckily gix does not actually
-        // validate if it's a proper email or not :)
-        config
-            .set_raw_value_by("committer", None, "email", "")
-            .map_err(|err| {
-                format_err!(ErrorKind::Repo, "failed to set `committer.email`: {}", err)
-            })?;
+        if let Some(ca_bundle) = &options.ca_bundle {
+            config
+                .set_raw_value_by(
+                    "http",
+                    None,
+                    "sslCAInfo",
+                    ca_bundle.to_string_lossy().as_ref(),
+                )
+                .map_err(|err| {
+                    format_err!(ErrorKind::Repo, "failed to set `http.sslCAInfo`: {}", err)
+                })?;
+        }
+        set_committer(&mut config)?;
 
         let repo = config
             .commit_auto_rollback()
@@ -283,14 +802,18 @@ impl Repository {
             .replace_refspecs(Some(REF_SPEC), DIR)
             .expect("valid statically known refspec");
 
+        let url = remote
+            .url(DIR)
+            .map(|url| url.to_bstring().to_string())
+            .unwrap_or_default();
+
         // Perform the actual fetch
-        let outcome = remote
-            .connect(DIR)
-            .map_err(|err| format_err!(ErrorKind::Repo, "failed to connect to remote: {}", err))?
+        let outcome = connect(&remote, &url, options.transport.as_ref())?
             .prepare_fetch(&mut gix::progress::Discard, Default::default())
-            .map_err(|err| format_err!(ErrorKind::Repo, "failed to prepare fetch: {}", err))?
+            .map_err(|err| transport_error(&url, "failed to prepare fetch", err))?
+            .with_shallow(shallow(options))
             .receive(&mut gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
-            .map_err(|err| format_err!(ErrorKind::Repo, "failed to fetch: {}", err))?;
+            .map_err(|err| transport_error(&url, "failed to fetch", err))?;
 
         let remote_head_id = tame_index::utils::git::write_fetch_head(&repo, &outcome, &remote)
             .map_err(Error::from_tame)?;
@@ -350,3 +873,207 @@ impl Repository {
         Ok(())
     }
 }
+
+/// Set the committer used for reflog entries, which gix requires even if
+/// there's no git identity configured
+pub(super) fn set_committer(config: &mut gix::config::SnapshotMut<'_>) -> Result<(), Error> {
+    config
+        .set_raw_value_by("committer", None, "name", "rustsec")
+        .map_err(|err| format_err!(ErrorKind::Repo, "failed to set `committer.name`: {}", err))?;
+    // Note we _have_ to set the email as well, but luckily gix does not actually
+    // validate if it's a proper email or not :)
+    config
+        .set_raw_value_by("committer", None, "email", "")
+        .map_err(|err| format_err!(ErrorKind::Repo, "failed to set `committer.email`: {}", err))?;
+    Ok(())
+}
+
+/// Connect to `remote` (whose URL is `url`), using the custom transport if there is one.
+///
+/// Credentials from the configured credential helpers are reused across
+/// connections, so repeated fetches don't ask for them again.
+pub(super) fn connect<'remote, 'repo>(
+    remote: &'remote gix::Remote<'repo>,
+    url: &str,
+    transport: Option<&TransportFactory>,
+) -> Result<gix::remote::Connection<'remote, 'repo, BoxedTransport>, Error> {
+    let mut connection = match transport {
+        Some(factory) => remote.to_connection_with_transport(factory.create(url)?),
+        None => remote
+            .connect(DIR)
+            .map_err(|err| transport_error(url, "failed to connect to remote", err))?,
+    };
+
+    credentials::use_cached_credentials(&mut connection).map_err(|err| {
+        format_err!(
+            ErrorKind::Repo,
+            "failed to configure credential helpers for {}: {}",
+            url,
+            err
+        )
+    })?;
+
+    Ok(connection)
+}
+
+/// URL of the `origin` remote of `repo`, if it has one
+fn origin_url(repo: &gix::Repository) -> Option<gix::Url> {
+    let remote = repo.find_remote("origin").ok()?;
+    remote.url(DIR).cloned()
+}
+
+/// Does `origin` refer to `url`, even if it's spelled differently?
+fn is_same_url(origin: &gix::Url, url: &str) -> bool {
+    origin.to_bstring() == url || gix::url::parse(url.into()).map_or(false, |url| url == *origin)
+}
+
+/// Point the `origin` remote of `repo` at `url`, adding it if it's missing
+pub(super) fn set_origin_url(repo: &gix::Repository, url: &str) -> Result<(), Error> {
+    let config_path = repo.path().join("config");
+    let error = |err: &dyn std::fmt::Display| {
+        format_err!(
+            ErrorKind::Repo,
+            "failed to set `remote.origin.url` in '{}': {}",
+            config_path.display(),
+            err
+        )
+    };
+
+    let mut config =
+        gix::config::File::from_path_no_includes(config_path.clone(), gix::config::Source::Local)
+            .map_err(|err| error(&err))?;
+    config
+        .set_raw_value_by("remote", Some("origin".into()), "url", url)
+        .map_err(|err| error(&err))?;
+    if config
+        .raw_value_by("remote", Some("origin".into()), "fetch")
+        .is_err()
+    {
+        config
+            .set_raw_value_by("remote", Some("origin".into()), "fetch", REF_SPEC)
+            .map_err(|err| error(&err))?;
+    }
+
+    // Write the new config next to the old one and move it into place, so
+    // an interrupted write can't leave the repository without a config.
+    // `config.lock` is what git itself locks the config with.
+    let lock_path = repo.path().join("config.lock");
+    let mut file = fs::File::create(&lock_path)?;
+    config.write_to(&mut file)?;
+    file.sync_all()?;
+    fs::rename(&lock_path, &config_path)?;
+    Ok(())
+}
+
+/// Create an empty repository at `path` whose `origin` is `url`, ready to be fetched into
+fn init_with_origin(url: &str, path: &Path) -> Result<gix::Repository, Error> {
+    let repo = gix::init(path).map_err(|err| {
+        format_err!(
+            ErrorKind::Repo,
+            "failed to initialize repository at '{}': {}",
+            path.display(),
+            err
+        )
+    })?;
+
+    set_origin_url(&repo, url)?;
+
+    // Reopen so the configuration picks up the new remote
+    gix::open(path).map_err(|err| {
+        format_err!(
+            ErrorKind::Repo,
+            "failed to open repository at '{}': {}",
+            path.display(),
+            err
+        )
+    })
+}
+
+/// How much history to fetch for the given options
+fn shallow(options: &FetchOptions) -> gix::remote::fetch::Shallow {
+    if options.shallow {
+        gix::remote::fetch::Shallow::DepthAtRemote(NonZeroU32::new(1).expect("non-zero depth"))
+    } else {
+        gix::remote::fetch::Shallow::NoChange
+    }
+}
+
+/// In-memory git configuration applied to fresh clones for the given options
+fn config_overrides(options: &FetchOptions) -> Vec<String> {
+    options
+        .ca_bundle
+        .iter()
+        .map(|ca_bundle| format!("http.sslCAInfo={}", ca_bundle.display()))
+        .collect()
+}
+
+/// Path of the filesystem lock guarding the repository at `path`
+fn lock_path(path: &Path) -> Result<tame_index::PathBuf, Error> {
+    Ok(tame_index::Path::from_path(path)
+        .ok_or_else(|| {
+            format_err!(
+                ErrorKind::BadParam,
+                "Path to the advisory DB directory is not valid UTF-8!"
+            )
+        })?
+        .with_extension(".lock"))
+}
+
+/// Options the advisory DB repository is opened with
+fn open_options() -> gix::open::Options {
+    gix::open::Options::default().permissions(gix::open::Permissions {
+        config: gix::open::permissions::Config {
+            // Be sure to get all configuration, some of which is only known by the git binary.
+            // That way we are sure to see all the systems credential helpers
+            git_binary: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+/// Is `path` left behind by a clone which was interrupted before it completed?
+///
+/// That's only the case if it has a `.git` directory with neither a `HEAD`
+/// (or any commits for it to point to) nor an `origin` remote to resume
+/// fetching from. A clone which got as far as configuring `origin` is resumed
+/// by fetching. Anything else that fails to open (e.g. because of its
+/// permissions or a newer repository format) is left alone, so the error
+/// from opening it is reported instead.
+fn is_partial_clone(path: &Path) -> bool {
+    let git_dir = path.join(".git");
+    if !git_dir.is_dir() {
+        return false;
+    }
+
+    // Interrupted before `HEAD` was written, so it can't be opened at all
+    if !git_dir.join("HEAD").exists() {
+        let config = git_dir.join("config");
+        return !config.exists()
+            || gix::config::File::from_path_no_includes(config, gix::config::Source::Local)
+                .map_or(false, |config| {
+                    config.section("remote", Some("origin".into())).is_err()
+                });
+    }
+
+    match gix::open_opts(path, open_options()) {
+        Ok(repo) => {
+            !repo
+                .remote_names()
+                .iter()
+                .any(|name| name.as_ref() == "origin")
+                && repo.head().map_or(false, |head| head.is_unborn())
+        }
+        Err(_) => false,
+    }
+}
+
+/// Acquire a lock, waiting for up to `lock_timeout` (or not at all if it's zero)
+fn acquire_lock(lock_opts: LockOptions<'_>, lock_timeout: Duration) -> Result<FileLock, Error> {
+    if lock_timeout == Duration::from_secs(0) {
+        lock_opts.try_lock()
+    } else {
+        lock_opts.lock(|_| Some(lock_timeout))
+    }
+    .map_err(Error::from_tame)
+}
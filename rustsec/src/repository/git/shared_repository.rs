@@ -0,0 +1,69 @@
+//! Thread-safe handle to an advisory DB repository
+
+use tame_index::external::gix;
+
+use super::{repository::Lock, Repository};
+use crate::error::{Error, ErrorKind};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// Thread-safe handle to a git repository for a Rust advisory DB.
+///
+/// [`Repository`] is not `Sync`, so it can't be shared between threads or
+/// held across `await` points in multithreaded async runtimes. This handle
+/// is `Send + Sync` and cheap to clone; use [`SharedRepository::to_repository`]
+/// to get a [`Repository`] for use on the current thread.
+#[cfg_attr(docsrs, doc(cfg(feature = "git")))]
+#[derive(Clone)]
+pub struct SharedRepository {
+    /// Repository object
+    repo: gix::ThreadSafeRepository,
+
+    /// Lock taken over from a [`Repository`] which held one (e.g. if it was
+    /// opened with [`Repository::open_with_read_lock`]), held until every
+    /// clone is dropped
+    _lock: Option<Arc<Lock>>,
+}
+
+impl SharedRepository {
+    /// Open a repository at the given path
+    pub fn open<P: Into<PathBuf>>(into_path: P) -> Result<Self, Error> {
+        let path = into_path.into();
+        let repo = gix::ThreadSafeRepository::open(&path).map_err(|err| {
+            format_err!(
+                ErrorKind::Repo,
+                "failed to open repository at '{}': {}",
+                path.display(),
+                err
+            )
+        })?;
+
+        Ok(Self { repo, _lock: None })
+    }
+
+    /// Get a [`Repository`] for use on the current thread
+    pub fn to_repository(&self) -> Repository {
+        Repository {
+            repo: self.repo.to_thread_local(),
+            lock: None,
+            fetch_status: None,
+        }
+    }
+
+    /// Path to the local checkout of a git repository
+    pub fn path(&self) -> &Path {
+        // Safety: Would fail if this is a bare repo, which we aren't
+        self.repo.work_dir().unwrap()
+    }
+}
+
+impl From<Repository> for SharedRepository {
+    fn from(repo: Repository) -> Self {
+        Self {
+            repo: repo.repo.into_sync(),
+            _lock: repo.lock.map(Arc::new),
+        }
+    }
+}
@@ -0,0 +1,276 @@
+//! Classification of git transport errors into actionable messages
+
+use crate::error::{Error, ErrorKind};
+use std::{fmt, io};
+use tame_index::external::gix::{self, protocol::transport::client};
+
+/// Broad categories of failures which can occur when talking to a remote
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(super) enum TransportFailure {
+    /// The remote host's name could not be resolved
+    Dns,
+
+    /// The TLS handshake with the remote host failed
+    Tls,
+
+    /// The remote requires credentials, or rejected the ones provided
+    Auth,
+
+    /// The remote repository does not exist
+    NotFound,
+
+    /// The remote host refused the connection
+    Refused,
+
+    /// The connection to the remote host timed out
+    Timeout,
+}
+
+impl TransportFailure {
+    /// Classify an error by inspecting it and its chain of sources.
+    ///
+    /// Transport errors are nested several layers deep inside `gix` errors, so
+    /// this walks the chain looking at the error types `gix` uses, I/O error
+    /// kinds, and the HTTP status reported by its HTTP backend.
+    pub fn classify(err: &(dyn std::error::Error + 'static)) -> Option<Self> {
+        let mut next = Some(err);
+
+        while let Some(err) = next {
+            if let Some(
+                client::Error::AuthenticationUnsupported | client::Error::AuthenticationRefused(_),
+            ) = err.downcast_ref::<client::Error>()
+            {
+                return Some(Self::Auth);
+            }
+
+            if let Some(io_err) = err.downcast_ref::<io::Error>() {
+                match io_err.kind() {
+                    io::ErrorKind::ConnectionRefused => return Some(Self::Refused),
+                    io::ErrorKind::TimedOut => return Some(Self::Timeout),
+                    io::ErrorKind::PermissionDenied => return Some(Self::Auth),
+                    io::ErrorKind::NotFound => return Some(Self::NotFound),
+                    _ => (),
+                }
+            }
+
+            let msg = err.to_string();
+            if let Some(failure) = Self::from_http_status(&msg).or_else(|| Self::from_message(&msg))
+            {
+                return Some(failure);
+            }
+
+            next = err.source();
+        }
+
+        None
+    }
+
+    /// Classify the HTTP status of a failed request, which the `gix` HTTP
+    /// backend reports as `Received HTTP status <code>`
+    fn from_http_status(msg: &str) -> Option<Self> {
+        let status: u16 = msg.strip_prefix("Received HTTP status ")?.parse().ok()?;
+
+        match status {
+            401 | 403 => Some(Self::Auth),
+            404 => Some(Self::NotFound),
+            _ => None,
+        }
+    }
+
+    /// Classify name resolution and TLS failures, which HTTP clients and
+    /// `ssh` only report as messages, using the messages of the common ones
+    /// (`getaddrinfo`, curl, OpenSSH, rustls and OpenSSL)
+    fn from_message(msg: &str) -> Option<Self> {
+        const DNS: &[&str] = &[
+            "failed to lookup address information",
+            "Could not resolve host",
+            "Could not resolve hostname",
+        ];
+        const TLS: &[&str] = &[
+            "invalid peer certificate",
+            "certificate verify failed",
+            "SSL certificate problem",
+        ];
+
+        if DNS.iter().any(|phrase| msg.contains(phrase)) {
+            Some(Self::Dns)
+        } else if TLS.iter().any(|phrase| msg.contains(phrase)) {
+            Some(Self::Tls)
+        } else {
+            None
+        }
+    }
+
+    /// How the failure relates to the remote's host, e.g. "for host"
+    fn preposition(self) -> &'static str {
+        match self {
+            Self::Dns => "for host",
+            Self::Tls => "with host",
+            Self::Auth | Self::Refused => "by host",
+            Self::NotFound => "on host",
+            Self::Timeout => "to host",
+        }
+    }
+
+    /// Describe the failure for the remote at `url`, naming its host if it
+    /// has one (local remotes like `file://` URLs don't)
+    fn describe(self, url: &str) -> String {
+        match host(url) {
+            Some(host) => format!("{} {} {}", self, self.preposition(), host),
+            None => format!("{}: {}", self, url),
+        }
+    }
+}
+
+impl fmt::Display for TransportFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Dns => "DNS resolution failed",
+            Self::Tls => "TLS handshake failed",
+            Self::Auth => "authentication required",
+            Self::NotFound => "repository not found",
+            Self::Refused => "connection refused",
+            Self::Timeout => "connection timed out",
+        })
+    }
+}
+
+/// Build an [`ErrorKind::Repo`] error for a failed operation against `url`.
+///
+/// Errors which can be classified get a specific message naming the host,
+/// otherwise `context` (e.g. "failed to connect to remote") is used.
+pub(super) fn transport_error<E>(url: &str, context: &str, err: E) -> Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let msg = match TransportFailure::classify(&err) {
+        Some(failure) => failure.describe(url),
+        None => context.to_owned(),
+    };
+
+    Error::with_source(ErrorKind::Repo, msg, err)
+}
+
+/// Get the host of a remote URL for use in error messages
+fn host(url: &str) -> Option<String> {
+    let url = gix::url::parse(url.into()).ok()?;
+    url.host().map(ToOwned::to_owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{transport_error, TransportFailure};
+    use crate::ErrorKind;
+    use std::{fmt, io};
+
+    /// Error wrapping another, as `gix` does for transport failures
+    #[derive(Debug)]
+    struct Wrapped(&'static str, Box<dyn std::error::Error + Send + Sync>);
+
+    impl fmt::Display for Wrapped {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl std::error::Error for Wrapped {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(self.1.as_ref())
+        }
+    }
+
+    fn wrap(inner: impl std::error::Error + Send + Sync + 'static) -> Wrapped {
+        Wrapped(
+            "An IO error occurred when talking to the server",
+            Box::new(inner),
+        )
+    }
+
+    #[test]
+    fn classify_dns_failure() {
+        let err = wrap(io::Error::new(
+            io::ErrorKind::Other,
+            "Could not resolve host: github.invalid",
+        ));
+        assert_eq!(
+            TransportFailure::classify(&err),
+            Some(TransportFailure::Dns)
+        );
+
+        let err = transport_error(
+            "https://github.invalid/RustSec/advisory-db.git",
+            "failed to connect to remote",
+            err,
+        );
+        assert_eq!(err.kind(), ErrorKind::Repo);
+        assert!(err
+            .to_string()
+            .contains("DNS resolution failed for host github.invalid"));
+    }
+
+    #[test]
+    fn classify_connection_refused() {
+        let err = wrap(io::Error::from(io::ErrorKind::ConnectionRefused));
+        assert_eq!(
+            TransportFailure::classify(&err),
+            Some(TransportFailure::Refused)
+        );
+    }
+
+    #[test]
+    fn classify_auth_failure() {
+        let err = wrap(io::Error::new(
+            io::ErrorKind::Other,
+            "Received HTTP status 401",
+        ));
+        assert_eq!(
+            TransportFailure::classify(&err),
+            Some(TransportFailure::Auth)
+        );
+    }
+
+    #[test]
+    fn unclassified_uses_context() {
+        let err = transport_error(
+            "https://user@example.com:8443/db.git",
+            "failed to fetch",
+            io::Error::new(io::ErrorKind::Other, "unexpected packet line"),
+        );
+        assert!(err.to_string().ends_with("failed to fetch"));
+        assert_eq!(
+            super::host("https://user@example.com:8443/db.git").as_deref(),
+            Some("example.com")
+        );
+    }
+
+    #[test]
+    fn classify_http_status() {
+        let err = wrap(io::Error::new(
+            io::ErrorKind::Other,
+            "Received HTTP status 404",
+        ));
+        assert_eq!(
+            TransportFailure::classify(&err),
+            Some(TransportFailure::NotFound)
+        );
+
+        // Other messages mentioning a status aren't taken for one
+        let err = wrap(io::Error::new(
+            io::ErrorKind::Other,
+            "object 404abc not found in pack",
+        ));
+        assert_eq!(TransportFailure::classify(&err), None);
+    }
+
+    #[test]
+    fn local_remotes_are_named_by_url() {
+        let err = transport_error(
+            "file:///nonexistent/advisory-db",
+            "failed to connect to remote",
+            wrap(io::Error::from(io::ErrorKind::NotFound)),
+        );
+        assert!(err
+            .to_string()
+            .ends_with("repository not found: file:///nonexistent/advisory-db"));
+    }
+}
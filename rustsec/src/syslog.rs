@@ -0,0 +1,225 @@
+//! Emit report findings as syslog messages
+//!
+//! Each vulnerability and warning in a [`Report`] becomes one message, with
+//! the syslog severity derived from the advisory's CVSS severity. Messages
+//! are delivered to a [`Sink`]; [`UnixSink`] sends them to the local syslog
+//! daemon (or journald) through `/dev/log`.
+
+use crate::{
+    advisory::{self, CvssPreference, Severity},
+    package::Package,
+    report::Report,
+    warning::Warning,
+    Vulnerability,
+};
+use std::{fmt, io};
+
+#[cfg(unix)]
+use std::{
+    os::unix::net::UnixDatagram,
+    path::{Path, PathBuf},
+};
+
+/// Name messages are logged under
+pub const APP_NAME: &str = "rustsec";
+
+/// Syslog facility for user-level messages
+const FACILITY_USER: u8 = 1;
+
+/// Syslog message severities, as defined in RFC 5424
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Level {
+    /// System is unusable (not used for findings)
+    Emergency = 0,
+
+    /// Action must be taken immediately (not used for findings)
+    Alert = 1,
+
+    /// Critical conditions
+    Critical = 2,
+
+    /// Error conditions
+    Error = 3,
+
+    /// Warning conditions
+    Warning = 4,
+
+    /// Normal but significant condition
+    Notice = 5,
+
+    /// Informational messages
+    Informational = 6,
+
+    /// Debug-level messages (not used for findings)
+    Debug = 7,
+}
+
+impl Level {
+    /// Level for a vulnerability with the given CVSS severity.
+    ///
+    /// Vulnerabilities without CVSS information are logged as errors, since
+    /// their severity is unknown rather than low.
+    pub fn from_severity(severity: Option<Severity>) -> Self {
+        match severity {
+            Some(Severity::Critical) => Level::Critical,
+            Some(Severity::High) | None => Level::Error,
+            Some(Severity::Medium) => Level::Warning,
+            Some(Severity::Low) => Level::Notice,
+            Some(Severity::None) => Level::Informational,
+        }
+    }
+}
+
+/// A single finding formatted for syslog
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Message {
+    /// Severity of the message
+    pub level: Level,
+
+    /// Kind of finding (`vulnerability`, or the kind of warning)
+    pub kind: String,
+
+    /// Advisory ID, if the finding has an advisory
+    pub id: Option<advisory::Id>,
+
+    /// Affected package
+    pub package: Package,
+
+    /// Human-readable description of the finding
+    pub text: String,
+}
+
+impl Message {
+    /// Create a message for a vulnerability, whose level comes from the
+    /// severity for the given CVSS version preference
+    pub fn vulnerability(vulnerability: &Vulnerability, preference: CvssPreference) -> Self {
+        let severity = vulnerability.advisory.severity(preference);
+
+        Self {
+            level: Level::from_severity(severity),
+            kind: "vulnerability".to_owned(),
+            id: Some(vulnerability.advisory.id.clone()),
+            package: vulnerability.package.clone(),
+            text: vulnerability.advisory.title.clone(),
+        }
+    }
+
+    /// Create a message for a warning
+    pub fn warning(warning: &Warning) -> Self {
+        Self {
+            level: Level::Warning,
+            kind: warning.kind.as_str().to_owned(),
+            id: warning
+                .advisory
+                .as_ref()
+                .map(|advisory| advisory.id.clone()),
+            package: warning.package.clone(),
+            text: warning
+                .advisory
+                .as_ref()
+                .map(|advisory| advisory.title.clone())
+                .unwrap_or_else(|| format!("{} crate", warning.kind)),
+        }
+    }
+
+    /// Format as an RFC 5424 syslog message.
+    ///
+    /// The timestamp and hostname are left for the syslog daemon to fill in.
+    pub fn to_rfc5424(&self) -> String {
+        format!(
+            "<{}>1 - - {} - {} - {}",
+            FACILITY_USER * 8 + self.level as u8,
+            APP_NAME,
+            self.kind.to_ascii_uppercase(),
+            self
+        )
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Newlines would split the message
+        let text = self.text.replace(['\n', '\r'], " ");
+
+        match &self.id {
+            Some(id) => write!(
+                f,
+                "{} {} {}: {}",
+                id, self.package.name, self.package.version, text
+            ),
+            None => write!(
+                f,
+                "{} {}: {}",
+                self.package.name, self.package.version, text
+            ),
+        }
+    }
+}
+
+/// Destination for syslog messages
+pub trait Sink {
+    /// Deliver a single message
+    fn send(&mut self, message: &Message) -> io::Result<()>;
+}
+
+/// Send one message per finding in the report to `sink`, returning how many were sent
+pub fn emit(report: &Report, sink: &mut dyn Sink) -> io::Result<usize> {
+    let mut count = 0;
+
+    for vulnerability in &report.vulnerabilities.list {
+        sink.send(&Message::vulnerability(
+            vulnerability,
+            report.settings.cvss_preference,
+        ))?;
+        count += 1;
+    }
+
+    for warnings in report.warnings.values() {
+        for warning in warnings {
+            sink.send(&Message::warning(warning))?;
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Default path of the local syslog socket (also served by journald)
+#[cfg(unix)]
+pub const DEFAULT_SOCKET: &str = "/dev/log";
+
+/// [`Sink`] which sends messages to a local syslog socket
+#[cfg(unix)]
+#[derive(Debug)]
+pub struct UnixSink {
+    /// Unbound socket messages are sent from
+    socket: UnixDatagram,
+
+    /// Path of the syslog socket
+    path: PathBuf,
+}
+
+#[cfg(unix)]
+impl UnixSink {
+    /// Send messages to the syslog socket at [`DEFAULT_SOCKET`]
+    pub fn new() -> io::Result<Self> {
+        Self::with_path(DEFAULT_SOCKET)
+    }
+
+    /// Send messages to the syslog socket at the given path
+    pub fn with_path(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self {
+            socket: UnixDatagram::unbound()?,
+            path: path.as_ref().to_owned(),
+        })
+    }
+}
+
+#[cfg(unix)]
+impl Sink for UnixSink {
+    fn send(&mut self, message: &Message) -> io::Result<()> {
+        self.socket
+            .send_to(message.to_rfc5424().as_bytes(), &self.path)
+            .map(|_| ())
+    }
+}
@@ -0,0 +1,17 @@
+//! Diagnostics emitted through the `tracing` crate (when the `tracing` feature is enabled)
+
+/// Emit a debug-level `tracing` event, or nothing if the `tracing` feature is disabled
+macro_rules! trace_event {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)+);
+    };
+}
+
+/// Enter a debug-level `tracing` span for the rest of the enclosing scope
+macro_rules! trace_span {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!($($arg)+).entered();
+    };
+}
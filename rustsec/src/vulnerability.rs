@@ -3,9 +3,13 @@
 
 use crate::{
     advisory::{self, affected::FunctionPath, Advisory},
+    cargo_lock::Dependency,
+    fixer,
     package::Package,
 };
+use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// A vulnerable package and the associated advisory
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -21,6 +25,52 @@ pub struct Vulnerability {
 
     /// Vulnerable package
     pub package: Package,
+
+    /// Semver requirements for the patched versions, taken from the advisory.
+    ///
+    /// This duplicates [`Versions::patched`](advisory::Versions::patched) at
+    /// the top level so tools can compute the minimal upgrade directly.
+    #[serde(default)]
+    pub patched_versions: Vec<VersionReq>,
+
+    /// Semver requirements for the versions which were never affected
+    #[serde(default)]
+    pub unaffected_versions: Vec<VersionReq>,
+
+    /// Whether any of the affected functions are reachable, according to
+    /// [`Settings::reachable_functions`](crate::report::Settings::reachable_functions).
+    ///
+    /// `None` if this isn't known for the package, or the advisory doesn't
+    /// list affected functions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reachable: Option<bool>,
+
+    /// Whether any of the features the vulnerable code needs are enabled,
+    /// according to [`Settings::enabled_features`](crate::report::Settings::enabled_features).
+    ///
+    /// `None` if the advisory doesn't list affected features, or the
+    /// package's enabled features aren't known. In the latter case the
+    /// package may not actually be affected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub features_enabled: Option<bool>,
+
+    /// Whether the advisory has no CVSS score, so the vulnerability needs to
+    /// be assessed manually. Only set with
+    /// [`Settings::require_severity`](crate::report::Settings::require_severity).
+    #[serde(default)]
+    pub unscored: bool,
+
+    /// How big an upgrade to the earliest patched version is, to help
+    /// triage the findings which are quick to fix
+    #[serde(default)]
+    pub remediation_confidence: RemediationConfidence,
+
+    /// Every dependency path through which the vulnerable package is
+    /// reached, filled in by [`Report::generate`](crate::Report::generate).
+    ///
+    /// `None` for toolchain vulnerabilities, which aren't dependencies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub affected_paths: Option<AffectedPaths>,
 }
 
 impl Vulnerability {
@@ -31,7 +81,24 @@ impl Vulnerability {
             versions: advisory.versions.clone(),
             affected: advisory.affected.clone(),
             package: package.clone(),
+            patched_versions: advisory.versions.patched().to_vec(),
+            unaffected_versions: advisory.versions.unaffected().to_vec(),
+            reachable: None,
+            features_enabled: None,
+            unscored: false,
+            remediation_confidence: RemediationConfidence::None,
+            affected_paths: None,
         }
+        .with_remediation_confidence()
+    }
+
+    /// Compute [`Vulnerability::remediation_confidence`] from the versions
+    fn with_remediation_confidence(mut self) -> Self {
+        self.remediation_confidence = match fixer::patched_version(&self, &self.package.version) {
+            Some(patched) => RemediationConfidence::new(&self.package.version, &patched),
+            None => RemediationConfidence::None,
+        };
+        self
     }
 
     /// Get the set of functions affected by this vulnerability (if available)
@@ -54,3 +121,104 @@ impl Vulnerability {
         })
     }
 }
+
+/// Dependency paths from workspace members (or packages nothing depends
+/// on) to the package affected by a [`Vulnerability`].
+///
+/// Widely shared crates can be reached through exponentially many paths,
+/// so at most [`AffectedPaths::LIMIT`] of them are listed.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct AffectedPaths {
+    /// Each path, starting at a workspace member and ending at the
+    /// vulnerable package
+    pub paths: Vec<Vec<Dependency>>,
+
+    /// Were there more paths than [`AffectedPaths::LIMIT`]?
+    pub truncated: bool,
+}
+
+impl AffectedPaths {
+    /// Maximum number of paths listed
+    pub const LIMIT: usize = 64;
+}
+
+/// How risky the upgrade fixing a [`Vulnerability`] is, based on the semver
+/// distance from the locked version to the earliest patched version.
+///
+/// Distances follow Cargo's compatibility rules, so e.g. upgrading from
+/// `0.5.1` to `0.6.0` is a [`RemediationConfidence::Major`] upgrade.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RemediationConfidence {
+    /// A patch release fixes it, which should be safe to upgrade to
+    Patch,
+
+    /// A semver-compatible minor release fixes it
+    Minor,
+
+    /// Only a breaking release fixes it
+    Major,
+
+    /// No patched version is known
+    #[default]
+    None,
+}
+
+impl RemediationConfidence {
+    /// Classify the upgrade from `current` to `patched`
+    pub fn new(current: &Version, patched: &Version) -> Self {
+        let breaking = if current.major > 0 {
+            patched.major != current.major
+        } else if current.minor > 0 {
+            patched.major != 0 || patched.minor != current.minor
+        } else {
+            patched.major != 0 || patched.minor != 0 || patched.patch != current.patch
+        };
+
+        if breaking {
+            Self::Major
+        } else if current.major > 0 && patched.minor != current.minor {
+            Self::Minor
+        } else {
+            Self::Patch
+        }
+    }
+
+    /// Get a `str` representing this confidence level
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Patch => "patch",
+            Self::Minor => "minor",
+            Self::Major => "major",
+            Self::None => "none",
+        }
+    }
+}
+
+impl fmt::Display for RemediationConfidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RemediationConfidence;
+
+    fn confidence(current: &str, patched: &str) -> RemediationConfidence {
+        RemediationConfidence::new(&current.parse().unwrap(), &patched.parse().unwrap())
+    }
+
+    #[test]
+    fn semver_distances() {
+        assert_eq!(confidence("1.2.3", "1.2.4"), RemediationConfidence::Patch);
+        assert_eq!(confidence("1.2.3", "1.3.0"), RemediationConfidence::Minor);
+        assert_eq!(confidence("1.2.3", "2.0.0"), RemediationConfidence::Major);
+
+        // Minor releases before 1.0 are breaking
+        assert_eq!(confidence("0.5.1", "0.5.2"), RemediationConfidence::Patch);
+        assert_eq!(confidence("0.5.1", "0.6.0"), RemediationConfidence::Major);
+        assert_eq!(confidence("0.5.1", "1.0.0"), RemediationConfidence::Major);
+        assert_eq!(confidence("0.0.1", "0.0.2"), RemediationConfidence::Major);
+    }
+}
@@ -1,7 +1,7 @@
 //! Warnings sourced from the Advisory DB
 
 use crate::error::{Error, ErrorKind};
-use crate::{advisory, package::Package};
+use crate::{advisory, package::Package, Version};
 use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 
@@ -22,6 +22,11 @@ pub struct Warning {
 
     /// Versions impacted by this warning
     pub versions: Option<advisory::Versions>,
+
+    /// Latest release of the crate, if it was looked up in the registry index
+    /// (e.g. to show how long an unmaintained crate has gone without one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latest_release: Option<LatestRelease>,
 }
 
 impl Warning {
@@ -39,6 +44,7 @@ impl Warning {
             advisory,
             affected,
             versions,
+            latest_release: None,
         }
     }
 
@@ -61,6 +67,61 @@ impl Warning {
     pub fn is_yanked(&self) -> bool {
         self.kind == WarningKind::Yanked
     }
+
+    /// Is this a warning about an advisory which isn't in effect yet?
+    pub fn is_upcoming(&self) -> bool {
+        self.kind == WarningKind::Upcoming
+    }
+
+    /// Is this a warning about a vulnerability in unreachable code?
+    pub fn is_unreachable(&self) -> bool {
+        self.kind == WarningKind::Unreachable
+    }
+
+    /// Is this a warning about a vulnerability in a transitive dependency?
+    pub fn is_transitive(&self) -> bool {
+        self.kind == WarningKind::Transitive
+    }
+
+    /// Is this a warning about a crate version which was never published?
+    pub fn is_unpublished(&self) -> bool {
+        self.kind == WarningKind::Unpublished
+    }
+
+    /// Is this a warning about a registry package without a checksum?
+    pub fn is_missing_checksum(&self) -> bool {
+        self.kind == WarningKind::MissingChecksum
+    }
+}
+
+/// Latest release of a crate according to the registry
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct LatestRelease {
+    /// Latest version which hasn't been yanked
+    pub version: Version,
+
+    /// When it was published, if that could be looked up
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<advisory::Date>,
+
+    /// Number of days since it was published, as of when this was created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age_days: Option<u64>,
+}
+
+impl LatestRelease {
+    /// Create a `LatestRelease`, computing its age from the publication date
+    pub fn new(version: Version, date: Option<advisory::Date>) -> Self {
+        let age_days = date
+            .as_ref()
+            .map(|date| u64::try_from(date.days_ago()).unwrap_or(0));
+
+        Self {
+            version,
+            date,
+            age_days,
+        }
+    }
 }
 
 /// Kinds of warnings
@@ -82,6 +143,29 @@ pub enum WarningKind {
     /// Yanked packages
     #[serde(rename = "yanked")]
     Yanked,
+
+    /// Vulnerabilities in advisories dated in the future, which aren't in effect yet
+    #[serde(rename = "upcoming")]
+    Upcoming,
+
+    /// Vulnerabilities whose affected functions are known to be unreachable
+    #[serde(rename = "unreachable")]
+    Unreachable,
+
+    /// Vulnerabilities in crates which aren't direct dependencies of any
+    /// workspace member
+    #[serde(rename = "transitive")]
+    Transitive,
+
+    /// Packages pinned to a version which isn't published in the registry,
+    /// which may indicate a malformed or tampered lockfile
+    #[serde(rename = "unpublished")]
+    Unpublished,
+
+    /// Registry packages without a checksum in the lockfile, which may
+    /// indicate tampering
+    #[serde(rename = "missing-checksum")]
+    MissingChecksum,
 }
 
 impl WarningKind {
@@ -92,6 +176,11 @@ impl WarningKind {
             Self::Unmaintained => "unmaintained",
             Self::Unsound => "unsound",
             Self::Yanked => "yanked",
+            Self::Upcoming => "upcoming",
+            Self::Unreachable => "unreachable",
+            Self::Transitive => "transitive",
+            Self::Unpublished => "unpublished",
+            Self::MissingChecksum => "missing-checksum",
         }
     }
 }
@@ -105,6 +194,11 @@ impl FromStr for WarningKind {
             "unmaintained" => WarningKind::Unmaintained,
             "unsound" => WarningKind::Unsound,
             "yanked" => WarningKind::Yanked,
+            "upcoming" => WarningKind::Upcoming,
+            "unreachable" => WarningKind::Unreachable,
+            "transitive" => WarningKind::Transitive,
+            "unpublished" => WarningKind::Unpublished,
+            "missing-checksum" => WarningKind::MissingChecksum,
             other => fail!(ErrorKind::Parse, "invalid warning type: {}", other),
         })
     }
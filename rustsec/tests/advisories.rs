@@ -3,7 +3,7 @@
 #![warn(rust_2018_idioms, unused_qualifications)]
 
 use rustsec::advisory::{Category, License};
-use std::path::Path;
+use std::{fs, path::Path};
 
 /// Load example advisory from the filesystem
 fn load_advisory(case: &str) -> rustsec::Advisory {
@@ -84,15 +84,94 @@ fn parse_cvss_vector_string() {
     );
 
     let cvss = advisory.metadata.cvss.unwrap();
-    assert_eq!(cvss.av.unwrap(), cvss::v3::base::AttackVector::Network);
-    assert_eq!(cvss.ac.unwrap(), cvss::v3::base::AttackComplexity::Low);
-    assert_eq!(cvss.pr.unwrap(), cvss::v3::base::PrivilegesRequired::None);
-    assert_eq!(cvss.ui.unwrap(), cvss::v3::base::UserInteraction::None);
-    assert_eq!(cvss.s.unwrap(), cvss::v3::base::Scope::Changed);
-    assert_eq!(cvss.c.unwrap(), cvss::v3::base::Confidentiality::High);
-    assert_eq!(cvss.i.unwrap(), cvss::v3::base::Integrity::High);
-    assert_eq!(cvss.a.unwrap(), cvss::v3::base::Availability::High);
+    assert_eq!(cvss.base.av.unwrap(), cvss::v3::base::AttackVector::Network);
+    assert_eq!(cvss.base.ac.unwrap(), cvss::v3::base::AttackComplexity::Low);
+    assert_eq!(
+        cvss.base.pr.unwrap(),
+        cvss::v3::base::PrivilegesRequired::None
+    );
+    assert_eq!(cvss.base.ui.unwrap(), cvss::v3::base::UserInteraction::None);
+    assert_eq!(cvss.base.s.unwrap(), cvss::v3::base::Scope::Changed);
+    assert_eq!(cvss.base.c.unwrap(), cvss::v3::base::Confidentiality::High);
+    assert_eq!(cvss.base.i.unwrap(), cvss::v3::base::Integrity::High);
+    assert_eq!(cvss.base.a.unwrap(), cvss::v3::base::Availability::High);
     assert_eq!(cvss.score().value(), 10.0);
+    assert!(cvss.temporal.is_empty());
+    assert_eq!(cvss.temporal_score(), None);
+}
+
+/// Parsing of CVSS v3.1 vector strings with Temporal Metrics
+#[test]
+fn parse_cvss_vector_string_with_temporal_metrics() {
+    let advisory_data = fs::read_to_string("./tests/support/example_advisory_v3.md")
+        .unwrap()
+        .replace(
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H/E:U/RL:O/RC:C",
+        );
+    let advisory: rustsec::Advisory = advisory_data.parse().unwrap();
+
+    let cvss = advisory.metadata.cvss.as_ref().unwrap();
+    assert_eq!(
+        cvss.temporal.e,
+        Some(cvss::v3::temporal::ExploitCodeMaturity::Unproven)
+    );
+    assert_eq!(
+        cvss.temporal.rl,
+        Some(cvss::v3::temporal::RemediationLevel::OfficialFix)
+    );
+    assert_eq!(
+        cvss.temporal.rc,
+        Some(cvss::v3::temporal::ReportConfidence::Confirmed)
+    );
+    assert_eq!(cvss.score().value(), 10.0);
+    assert_eq!(cvss.temporal_score().unwrap().value(), 8.7);
+
+    // The severity is still based on the Base Metrics
+    assert_eq!(
+        advisory.severity().unwrap(),
+        rustsec::advisory::Severity::Critical
+    );
+}
+
+/// Advisories with both a CVSS v3 and a CVSS v4 vector, which disagree,
+/// take their severity from the preferred version's vector
+#[test]
+fn severity_with_cvss_v3_and_v4_vectors() {
+    use rustsec::advisory::{CvssPreference, Severity};
+
+    let advisory_data = fs::read_to_string("./tests/support/example_advisory_v3.md")
+        .unwrap()
+        .replace(
+            "cvss = \"CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H\"",
+            "cvss = \"CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H\"\n\
+             cvss_v4 = \"CVSS:4.0/AV:P/AC:H/AT:P/PR:H/UI:A/VC:L/VI:N/VA:N/SC:N/SI:N/SA:N\"",
+        );
+    let advisory: rustsec::Advisory = advisory_data.parse().unwrap();
+    assert!(advisory.metadata.cvss_v4.is_some());
+
+    // The highest version is preferred by default
+    assert_eq!(advisory.severity(), Some(Severity::Low));
+    assert_eq!(
+        advisory.severity_with(CvssPreference::Latest),
+        Some(Severity::Low)
+    );
+    assert_eq!(
+        advisory.severity_with(CvssPreference::V4),
+        Some(Severity::Low)
+    );
+    assert_eq!(
+        advisory.severity_with(CvssPreference::V3),
+        Some(Severity::Critical)
+    );
+
+    // Without a vector for the preferred version, the other one is used
+    let mut v4_only = advisory.clone();
+    v4_only.metadata.cvss = None;
+    assert_eq!(
+        v4_only.severity_with(CvssPreference::V3),
+        Some(Severity::Low)
+    );
 }
 
 /// Parsing of patched version reqs
@@ -104,3 +183,97 @@ fn parse_patched_version_reqs() {
     assert!(req.matches(&"1.2.3".parse().unwrap()));
     assert!(req.matches(&"1.2.4".parse().unwrap()));
 }
+
+/// Keywords and categories accessors
+#[test]
+fn parse_keywords_and_categories() {
+    let advisory = load_advisory("v3");
+    let keywords: Vec<&str> = advisory.keywords().iter().map(|k| k.as_str()).collect();
+    assert_eq!(keywords, ["how", "are", "you", "gentlemen"]);
+    assert_eq!(
+        advisory.categories(),
+        [Category::CodeExecution, Category::PrivilegeEscalation]
+    );
+}
+
+/// Parsing an advisory from a string rather than a file
+#[test]
+fn parse_from_str() {
+    const ADVISORY: &str = include_str!("support/example_advisory_v4.md");
+
+    let advisory = rustsec::advisory::parse(ADVISORY).unwrap();
+    assert_eq!(advisory, load_advisory("v4"));
+    assert_eq!(advisory.id().as_str(), "RUSTSEC-2001-2101");
+    assert!(rustsec::advisory::parse("# not an advisory").is_err());
+}
+
+/// Affected versions at the boundaries of the patched and unaffected ranges
+#[test]
+fn version_affected_boundaries() {
+    // patched = [">= 1.2.3"], unaffected = ["0.1.2"] (i.e. `^0.1.2`)
+    let advisory = load_advisory("v4");
+    let affected = |version: &str| advisory.is_version_affected(&version.parse().unwrap());
+
+    assert!(affected("0.0.0"));
+    assert!(affected("0.1.1"));
+    assert!(!affected("0.1.2"));
+    assert!(!affected("0.1.99"));
+    assert!(affected("0.2.0"));
+    assert!(affected("1.0.0"));
+    assert!(affected("1.2.2"));
+    assert!(!affected("1.2.3"));
+    assert!(!affected("1.2.4"));
+    assert!(!affected("2.0.0"));
+}
+
+/// Only explicitly listed releases are affected, when there are any
+#[test]
+fn version_affected_explicit_list() {
+    let advisory = rustsec::advisory::parse(
+        r#"```toml
+[advisory]
+id = "RUSTSEC-2001-2101"
+package = "base"
+date = "2001-02-03"
+
+[versions]
+patched = [">= 1.0.4"]
+affected = ["1.0.1", "1.0.3"]
+```
+
+# Only some releases are affected
+"#,
+    )
+    .unwrap();
+    let affected = |version: &str| advisory.is_version_affected(&version.parse().unwrap());
+
+    assert!(!affected("1.0.0"));
+    assert!(affected("1.0.1"));
+    assert!(!affected("1.0.2"));
+    assert!(affected("1.0.3"));
+    assert!(!affected("1.0.4"));
+}
+
+/// Without patched or unaffected versions, every version is affected
+#[test]
+fn version_affected_without_patches() {
+    let advisory = rustsec::advisory::parse(
+        r#"```toml
+[advisory]
+id = "RUSTSEC-2001-2101"
+package = "base"
+date = "2001-02-03"
+
+[versions]
+patched = []
+```
+
+# No fix is available
+"#,
+    )
+    .unwrap();
+
+    for version in ["0.0.0", "0.1.0", "1.0.0", "99.99.99"] {
+        assert!(advisory.is_version_affected(&version.parse().unwrap()));
+    }
+}
@@ -0,0 +1,109 @@
+//! Tests for loading advisory databases from archives in memory
+#![cfg(feature = "archive")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{
+    database::{ArchiveFormat, OpenOptions, Query},
+    Database, ErrorKind, Lockfile,
+};
+use std::io::Read;
+
+/// Snapshot of a database with one advisory, inside an `advisory-db/` directory
+const ARCHIVE: &[u8] = include_bytes!("support/archive_db.tar.gz");
+
+const ADVISORY: &str = r#"```toml
+[advisory]
+id = "RUSTSEC-2017-0004"
+package = "base64"
+date = "2017-05-03"
+
+[versions]
+patched = [">= 0.5.2"]
+```
+
+# Integer overflow leads to heap-based buffer overflow in encode_config_buf
+"#;
+
+/// Build a tarball with the advisory at `path`, adding pax extended
+/// header records before it if there are any
+fn tarball(path: &str, pax_records: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut builder = tar::Builder::new(vec![]);
+    if !pax_records.is_empty() {
+        builder
+            .append_pax_extensions(pax_records.iter().copied())
+            .unwrap();
+    }
+
+    let mut header = tar::Header::new_gnu();
+    header.set_size(ADVISORY.len() as u64);
+    header.set_mode(0o644);
+    builder
+        .append_data(&mut header, path, ADVISORY.as_bytes())
+        .unwrap();
+    builder.into_inner().unwrap()
+}
+
+#[test]
+fn load_embedded_archive() {
+    let db = Database::from_archive_bytes(ARCHIVE, ArchiveFormat::TarGz).unwrap();
+    assert_eq!(db.iter().count(), 1);
+    assert!(db.path().as_os_str().is_empty());
+
+    let advisory = db.get(&"RUSTSEC-2017-0004".parse().unwrap()).unwrap();
+    assert_eq!(advisory.metadata.package.as_str(), "base64");
+
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    let vulns = db.query_vulnerabilities(&lockfile, &Query::crate_scope());
+    assert_eq!(vulns.len(), 1);
+    assert_eq!(vulns[0].advisory.id.as_str(), "RUSTSEC-2017-0004");
+}
+
+#[test]
+fn load_embedded_archive_with_options() {
+    let options = OpenOptions::new().retain_raw_source(true);
+    let db =
+        Database::from_archive_bytes_with_options(ARCHIVE, ArchiveFormat::TarGz, &options).unwrap();
+    let raw_source = db.raw_source(&"RUSTSEC-2017-0004".parse().unwrap());
+    assert!(raw_source.unwrap().contains("package = \"base64\""));
+}
+
+#[test]
+fn invalid_archive_is_rejected() {
+    let err = Database::from_archive_bytes(b"not an archive", ArchiveFormat::TarGz).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Parse);
+
+    // The compressed bytes aren't a tarball themselves
+    let err = Database::from_archive_bytes(ARCHIVE, ArchiveFormat::Tar).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Parse);
+}
+
+#[test]
+fn load_uncompressed_archive() {
+    let mut tar = vec![];
+    flate2::read::GzDecoder::new(ARCHIVE)
+        .read_to_end(&mut tar)
+        .unwrap();
+
+    let db = Database::from_archive_bytes(&tar, ArchiveFormat::Tar).unwrap();
+    assert_eq!(db.iter().count(), 1);
+}
+
+#[test]
+fn load_archive_with_long_names() {
+    // Too long for a plain tar header, so it's in a GNU long name entry
+    let root = "advisory-db-".to_owned() + &"0123456789".repeat(10);
+    let tar = tarball(&format!("{}/crates/base64/RUSTSEC-2017-0004.md", root), &[]);
+
+    let db = Database::from_archive_bytes(&tar, ArchiveFormat::Tar).unwrap();
+    assert!(db.get(&"RUSTSEC-2017-0004".parse().unwrap()).is_some());
+}
+
+#[test]
+fn load_archive_with_pax_paths() {
+    // The pax header's path overrides the one in the entry's own header
+    let path = "advisory-db/crates/base64/RUSTSEC-2017-0004.md";
+    let tar = tarball("placeholder.md", &[("path", path.as_bytes())]);
+
+    let db = Database::from_archive_bytes(&tar, ArchiveFormat::Tar).unwrap();
+    assert!(db.get(&"RUSTSEC-2017-0004".parse().unwrap()).is_some());
+}
@@ -2,8 +2,12 @@
 
 use cargo_lock::Lockfile;
 use once_cell::sync::Lazy;
-use rustsec::{database::Query, repository::git::Repository, Database};
-use std::{path::Path, sync::Mutex};
+use rustsec::{
+    database::{OpenOptions, Query},
+    repository::git::Repository,
+    Advisory, Collection, Database, ErrorKind,
+};
+use std::{fs, path::Path, sync::Mutex};
 
 static DEFAULT_DATABASE: Lazy<Mutex<Database>> = Lazy::new(|| {
     Mutex::new(
@@ -32,3 +36,450 @@ fn query_vulnerabilities_with_crate_scope() {
     let vuln = db.vulnerabilities(&lockfile);
     assert_eq!(vuln_all, vuln);
 }
+
+#[test]
+fn empty_database_with_require_advisories() {
+    let dir = tempfile::tempdir().unwrap();
+
+    assert!(Database::open(dir.path()).is_ok());
+
+    let err = Database::open_with_options(dir.path(), &OpenOptions::new().require_advisories(true))
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::EmptyDatabase);
+}
+
+#[test]
+fn database_missing_crates_collection_with_require_collections() {
+    // Only the `rust` collection made it into this copy of the database
+    let dir = tempfile::tempdir().unwrap();
+    let rust_advisory = include_str!("support/example_advisory_v4.md")
+        .replace("package = \"base\"", "package = \"std\"");
+    fs::create_dir_all(dir.path().join("rust/std")).unwrap();
+    fs::write(
+        dir.path().join("rust/std/RUSTSEC-2001-2101.md"),
+        rust_advisory,
+    )
+    .unwrap();
+
+    let options = OpenOptions::new().require_advisories(true);
+    assert!(Database::open_with_options(dir.path(), &options).is_ok());
+    let options = options.require_collections([Collection::Rust]);
+    assert!(Database::open_with_options(dir.path(), &options).is_ok());
+
+    let options = options.require_collections([Collection::Crates]);
+    let err = Database::open_with_options(dir.path(), &options).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::EmptyDatabase);
+    assert!(err.to_string().contains("`crates` collection"));
+}
+
+#[test]
+fn advisories_report_their_collection() {
+    let dir = tempfile::tempdir().unwrap();
+    let crate_advisory = include_str!("support/example_advisory_v4.md");
+    let rust_advisory = crate_advisory
+        .replace("RUSTSEC-2001-2101", "RUSTSEC-2001-2102")
+        .replace("package = \"base\"", "package = \"std\"");
+
+    fs::create_dir_all(dir.path().join("crates/base")).unwrap();
+    fs::write(
+        dir.path().join("crates/base/RUSTSEC-2001-2101.md"),
+        crate_advisory,
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("rust/std")).unwrap();
+    fs::write(
+        dir.path().join("rust/std/RUSTSEC-2001-2102.md"),
+        rust_advisory,
+    )
+    .unwrap();
+
+    let db = Database::open(dir.path()).unwrap();
+    let crate_advisory = db.get(&"RUSTSEC-2001-2101".parse().unwrap()).unwrap();
+    let rust_advisory = db.get(&"RUSTSEC-2001-2102".parse().unwrap()).unwrap();
+    assert_eq!(crate_advisory.collection(), Some(Collection::Crates));
+    assert_eq!(rust_advisory.collection(), Some(Collection::Rust));
+
+    let rust_only = db.query(&Query::new().collection(Collection::Rust));
+    assert_eq!(rust_only, vec![rust_advisory]);
+}
+
+#[test]
+fn duplicate_advisory_ids() {
+    let dir = tempfile::tempdir().unwrap();
+    let advisory = include_str!("support/example_advisory_v4.md");
+
+    // The same advisory merged in from two sources under different packages
+    for package in ["base", "base-fork"] {
+        let package_dir = dir.path().join("crates").join(package);
+        fs::create_dir_all(&package_dir).unwrap();
+        fs::write(
+            package_dir.join("RUSTSEC-2001-2101.md"),
+            advisory.replace("package = \"base\"", &format!("package = \"{}\"", package)),
+        )
+        .unwrap();
+    }
+
+    let err = Database::open(dir.path()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Parse);
+
+    let db = Database::open_with_options(dir.path(), &OpenOptions::new().allow_duplicate_ids(true))
+        .unwrap();
+    let id = "RUSTSEC-2001-2101".parse().unwrap();
+    let mut packages = db
+        .get_all(&id)
+        .iter()
+        .map(|advisory| advisory.metadata.package.as_str())
+        .collect::<Vec<_>>();
+    packages.sort();
+
+    assert_eq!(packages, ["base", "base-fork"]);
+    assert!(db.get(&id).is_some());
+    assert!(db.get_all(&"RUSTSEC-2001-2102".parse().unwrap()).is_empty());
+}
+
+/// Create a database with several advisories and one which fails to parse
+fn create_database_with_invalid_advisory(dir: &Path) {
+    let advisory = include_str!("support/example_advisory_v4.md");
+
+    for (n, package) in ["base", "base-fork", "other"].iter().enumerate() {
+        let id = format!("RUSTSEC-2001-{}", 2101 + n);
+        let package_dir = dir.join("crates").join(package);
+        fs::create_dir_all(&package_dir).unwrap();
+        fs::write(
+            package_dir.join(format!("{}.md", id)),
+            advisory
+                .replace("RUSTSEC-2001-2101", &id)
+                .replace("package = \"base\"", &format!("package = \"{}\"", package)),
+        )
+        .unwrap();
+    }
+
+    fs::write(
+        dir.join("crates/other/RUSTSEC-2001-2999.md"),
+        "```toml\n[advisory]\nid = \"RUSTSEC-2001-2999\"\n```\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn invalid_advisories_are_collected_in_load_report() {
+    let dir = tempfile::tempdir().unwrap();
+    create_database_with_invalid_advisory(dir.path());
+
+    assert!(Database::open(dir.path()).is_err());
+
+    let options = OpenOptions::new().skip_invalid_advisories(true);
+    let db = Database::open_with_options(dir.path(), &options).unwrap();
+    assert_eq!(db.iter().count(), 3);
+
+    let report = db.load_report();
+    assert!(!report.is_clean());
+    assert_eq!(report.errors.len(), 1);
+    assert!(report.errors[0]
+        .0
+        .ends_with("crates/other/RUSTSEC-2001-2999.md"));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_and_serial_loads_are_identical() {
+    let dir = tempfile::tempdir().unwrap();
+    create_database_with_invalid_advisory(dir.path());
+
+    let options = OpenOptions::new().skip_invalid_advisories(true);
+    let parallel =
+        Database::open_with_options(dir.path(), &options.clone().parallel(true)).unwrap();
+    let serial = Database::open_with_options(dir.path(), &options.parallel(false)).unwrap();
+
+    assert!(parallel.iter().eq(serial.iter()));
+    let error_paths = |db: &Database| {
+        db.load_report()
+            .errors
+            .iter()
+            .map(|(path, err)| (path.clone(), err.to_string()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(error_paths(&parallel), error_paths(&serial));
+
+    let id = "RUSTSEC-2001-2102".parse().unwrap();
+    assert_eq!(parallel.get(&id), serial.get(&id));
+    assert_eq!(
+        parallel.query(&Query::crate_scope()),
+        serial.query(&Query::crate_scope())
+    );
+}
+
+#[test]
+fn future_schema_version_is_rejected() {
+    let path = Path::new("./tests/support/future_schema_db");
+
+    let err = Database::open(path).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnsupportedSchema);
+
+    assert!(
+        Database::open_with_options(path, &OpenOptions::new().check_schema_version(false)).is_ok()
+    );
+}
+
+#[test]
+fn validate_reports_missing_fields() {
+    let dir = tempfile::tempdir().unwrap();
+    let package_dir = dir.path().join("crates/base");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(
+        package_dir.join("RUSTSEC-2001-2101.md"),
+        include_str!("support/example_advisory_v3.md"),
+    )
+    .unwrap();
+
+    // Parses, but has no description
+    fs::write(
+        package_dir.join("RUSTSEC-2001-2102.md"),
+        r#"```toml
+[advisory]
+id = "RUSTSEC-2001-2102"
+package = "base"
+date = "2001-02-03"
+
+[versions]
+patched = [">= 1.2.3"]
+```
+
+# Advisory without a description
+"#,
+    )
+    .unwrap();
+
+    let db = Database::open(dir.path()).unwrap();
+    let issues = db.validate();
+
+    assert_eq!(issues.len(), 1, "unexpected issues: {:?}", issues);
+    assert_eq!(issues[0].id.as_ref().unwrap().as_str(), "RUSTSEC-2001-2102");
+    assert_eq!(issues[0].path, package_dir.join("RUSTSEC-2001-2102.md"));
+    assert!(issues[0].message.contains("`description`"));
+}
+
+#[test]
+fn validate_reports_invalid_advisories() {
+    let dir = tempfile::tempdir().unwrap();
+    let package_dir = dir.path().join("crates/base");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(
+        package_dir.join("RUSTSEC-2001-2101.md"),
+        include_str!("support/example_advisory_v3.md"),
+    )
+    .unwrap();
+
+    // Doesn't parse: no `date` or `[versions]`
+    fs::write(
+        package_dir.join("RUSTSEC-2001-2102.md"),
+        "```toml\n[advisory]\nid = \"RUSTSEC-2001-2102\"\npackage = \"base\"\n```\n\n# Broken\n",
+    )
+    .unwrap();
+
+    // Parses, but isn't a valid crate name
+    let bad_name_dir = dir.path().join("crates/bad name");
+    fs::create_dir_all(&bad_name_dir).unwrap();
+    fs::write(
+        bad_name_dir.join("RUSTSEC-2001-2103.md"),
+        include_str!("support/example_advisory_v3.md")
+            .replace("RUSTSEC-2001-2101", "RUSTSEC-2001-2103")
+            .replace("package = \"base\"", "package = \"bad name\""),
+    )
+    .unwrap();
+
+    assert!(Database::open(dir.path()).is_err());
+
+    let options = OpenOptions::new().skip_invalid_advisories(true);
+    let db = Database::open_with_options(dir.path(), &options).unwrap();
+    let issues = db.validate();
+
+    let invalid: Vec<_> = issues.iter().filter(|issue| issue.id.is_none()).collect();
+    assert_eq!(invalid.len(), 1, "unexpected issues: {:?}", issues);
+    assert_eq!(invalid[0].path, package_dir.join("RUSTSEC-2001-2102.md"));
+
+    assert!(issues.iter().any(|issue| {
+        issue.path == bad_name_dir.join("RUSTSEC-2001-2103.md")
+            && issue.message == "invalid crate name `bad name`"
+    }));
+}
+
+#[test]
+fn flat_layout_database() {
+    let path = Path::new("./tests/support/flat_db");
+    assert!(Database::open(path).unwrap().iter().next().is_none());
+
+    let db = Database::open_with_options(path, &OpenOptions::new().flat_layout(true)).unwrap();
+    assert_eq!(db.iter().count(), 2);
+
+    let toml_advisory = db.get(&"RUSTSEC-2001-2101".parse().unwrap()).unwrap();
+    assert_eq!(toml_advisory.metadata.package.as_str(), "base");
+    assert_eq!(toml_advisory.title(), "All your base are belong to us");
+    assert_eq!(toml_advisory.collection(), Some(Collection::Crates));
+
+    let nested_advisory = db.get(&"RUSTSEC-2001-2102".parse().unwrap()).unwrap();
+    assert_eq!(nested_advisory.metadata.package.as_str(), "other");
+
+    let query = Query::crate_scope().package_name("other".parse().unwrap());
+    assert_eq!(db.query(&query), vec![nested_advisory]);
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinked_advisories() {
+    use std::os::unix::fs::symlink;
+
+    let advisories = tempfile::tempdir().unwrap();
+    let advisory_path = advisories.path().join("RUSTSEC-2001-2101.md");
+    fs::write(
+        &advisory_path,
+        include_str!("support/example_advisory_v4.md"),
+    )
+    .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let package_dir = dir.path().join("crates").join("base");
+    fs::create_dir_all(&package_dir).unwrap();
+    symlink(&advisory_path, package_dir.join("RUSTSEC-2001-2101.md")).unwrap();
+
+    // Skipped symlinks are reported rather than silently dropped
+    let db = Database::open(dir.path()).unwrap();
+    assert_eq!(db.iter().count(), 0);
+    assert_eq!(
+        db.load_report().skipped_symlinks,
+        [package_dir.join("RUSTSEC-2001-2101.md")]
+    );
+
+    let options = OpenOptions::new().follow_symlinks(true);
+    let db = Database::open_with_options(dir.path(), &options).unwrap();
+    assert_eq!(db.iter().count(), 1);
+    assert!(db.load_report().skipped_symlinks.is_empty());
+    assert!(db.get(&"RUSTSEC-2001-2101".parse().unwrap()).is_some());
+}
+
+#[cfg(unix)]
+#[test]
+fn symlink_cycles_are_walked_once() {
+    use std::os::unix::fs::symlink;
+
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("RUSTSEC-2001-2101.md"),
+        include_str!("support/example_advisory_v4.md"),
+    )
+    .unwrap();
+    symlink(dir.path(), dir.path().join("loop")).unwrap();
+
+    for follow_symlinks in [false, true] {
+        let options = OpenOptions::new()
+            .flat_layout(true)
+            .follow_symlinks(follow_symlinks);
+        let db = Database::open_with_options(dir.path(), &options).unwrap();
+        assert_eq!(db.iter().count(), 1);
+        assert_eq!(
+            db.load_report().skipped_symlinks.len(),
+            !follow_symlinks as usize
+        );
+    }
+}
+
+#[test]
+fn package_names_match_across_hyphens_and_underscores() {
+    let dir = tempfile::tempdir().unwrap();
+    let package_dir = dir.path().join("crates").join("foo-bar");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(
+        package_dir.join("RUSTSEC-2001-2101.md"),
+        include_str!("support/example_advisory_v4.md")
+            .replace("package = \"base\"", "package = \"foo-bar\""),
+    )
+    .unwrap();
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile: Lockfile = r#"
+version = 3
+
+[[package]]
+name = "foo_bar"
+version = "1.2.2"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#
+    .parse()
+    .unwrap();
+
+    let vulns = db.vulnerabilities(&lockfile);
+    assert_eq!(vulns.len(), 1);
+    assert_eq!(vulns[0].advisory.id.as_str(), "RUSTSEC-2001-2101");
+    assert_eq!(vulns[0].package.name.as_str(), "foo_bar");
+
+    let query = Query::crate_scope().package_name("foo_bar".parse().unwrap());
+    assert_eq!(db.query(&query).len(), 1);
+}
+
+#[test]
+fn query_as_of_date() {
+    let dir = tempfile::tempdir().unwrap();
+    let package_dir = dir.path().join("crates").join("base");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(
+        package_dir.join("RUSTSEC-2001-2101.md"),
+        include_str!("support/example_advisory_v4.md").replace(
+            "date = \"2001-02-03\"",
+            "date = \"2001-02-03\"\nwithdrawn = \"2005-06-07\"",
+        ),
+    )
+    .unwrap();
+
+    let db = Database::open(dir.path()).unwrap();
+    let query = |as_of: &str| {
+        let query = Query::crate_scope()
+            .package_name("base".parse().unwrap())
+            .package_version("1.2.2".parse().unwrap())
+            .as_of(as_of.parse().unwrap());
+        db.query(&query).len()
+    };
+
+    // Not yet published, even though the version is affected
+    assert_eq!(query("2001-02-02"), 0);
+    assert_eq!(query("2001-02-03"), 1);
+
+    // Published, and not yet withdrawn
+    assert_eq!(query("2005-06-06"), 1);
+    assert_eq!(query("2005-06-07"), 0);
+    assert!(db.query(&Query::crate_scope()).is_empty());
+}
+
+#[test]
+fn raw_advisory_source_is_retained() {
+    let dir = tempfile::tempdir().unwrap();
+    let package_dir = dir.path().join("crates").join("base");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(
+        package_dir.join("RUSTSEC-2001-2101.md"),
+        include_str!("support/example_advisory_v4.md"),
+    )
+    .unwrap();
+
+    let id = "RUSTSEC-2001-2101".parse().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    assert_eq!(db.raw_source(&id), None);
+
+    let options = OpenOptions::new().retain_raw_source(true);
+    let db = Database::open_with_options(dir.path(), &options).unwrap();
+    let advisory = db.get(&id).unwrap();
+    let raw_source = db.raw_source(&id).unwrap();
+    assert!(raw_source.contains("id = \"RUSTSEC-2001-2101\""));
+
+    // The source parses back into the same advisory
+    let reparsed: Advisory = format!(
+        "```toml\n{}\n```\n\n# {}\n\n{}\n",
+        raw_source,
+        advisory.title(),
+        advisory.description()
+    )
+    .parse()
+    .unwrap();
+    assert_eq!(reparsed.id(), advisory.id());
+    assert_eq!(reparsed.versions, advisory.versions);
+    assert_eq!(reparsed.affected, advisory.affected);
+}
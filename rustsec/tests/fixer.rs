@@ -0,0 +1,139 @@
+//! Tests for automatically fixing vulnerable dependencies
+
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{advisory::Versions, Advisory, CompatibleFix, Fixer, Lockfile, Vulnerability};
+use std::{fs, path::PathBuf};
+use tempfile::tempdir;
+
+#[test]
+fn transitive_dependency_precise_fix() {
+    let lockfile = Lockfile::load("./tests/support/transitive_cargo.lock").unwrap();
+    let advisory = Advisory::load_file("./tests/support/example_advisory_v3.md").unwrap();
+    let base = lockfile
+        .packages
+        .iter()
+        .find(|pkg| pkg.name.as_str() == "base")
+        .unwrap();
+    let middle = lockfile
+        .packages
+        .iter()
+        .find(|pkg| pkg.name.as_str() == "middle")
+        .unwrap();
+    let vulnerability = Vulnerability::new(&advisory, base);
+
+    let fixer = Fixer::new(lockfile.clone(), None, Some(PathBuf::from("cargo")));
+    assert!(fixer.is_direct_dependency(middle));
+    assert!(!fixer.is_direct_dependency(base));
+
+    let fixes = fixer.transitive_fixes(&vulnerability);
+    assert_eq!(fixes.len(), 1);
+    assert_eq!(fixes[0].package.name.as_str(), "base");
+    assert_eq!(fixes[0].version.to_string(), "1.2.3");
+
+    let command = fixer.get_precise_fix_command(&fixes[0], true);
+    let args = command
+        .get_args()
+        .map(|arg| arg.to_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        args,
+        [
+            "update",
+            "--dry-run",
+            "registry+https://github.com/rust-lang/crates.io-index#base@1.0.0",
+            "--precise",
+            "1.2.3"
+        ]
+    );
+}
+
+#[test]
+fn compatible_fix_requires_breaking_change() {
+    let lockfile = Lockfile::load("./tests/support/transitive_cargo.lock").unwrap();
+    let mut advisory = Advisory::load_file("./tests/support/example_advisory_v3.md").unwrap();
+    let base = lockfile
+        .packages
+        .iter()
+        .find(|pkg| pkg.name.as_str() == "base")
+        .unwrap();
+    let fixer = Fixer::new(lockfile.clone(), None, Some(PathBuf::from("cargo")));
+
+    // `base` is locked at 1.0.0 and 1.2.3 is patched
+    let vulnerability = Vulnerability::new(&advisory, base);
+    assert_eq!(
+        fixer.compatible_fix(&vulnerability).unwrap(),
+        Some(CompatibleFix::Update("1.2.3".parse().unwrap()))
+    );
+
+    // Only a major version bump is patched
+    advisory.versions = Versions::new(vec![">= 2.0.0".parse().unwrap()], vec![]).unwrap();
+    let vulnerability = Vulnerability::new(&advisory, base);
+    assert_eq!(
+        fixer.compatible_fix(&vulnerability).unwrap(),
+        Some(CompatibleFix::RequiresBreakingChange)
+    );
+
+    advisory.versions = Versions::new(vec![], vec![]).unwrap();
+    let vulnerability = Vulnerability::new(&advisory, base);
+    assert_eq!(fixer.compatible_fix(&vulnerability).unwrap(), None);
+}
+
+#[test]
+fn compatible_fix_follows_manifest_requirements() {
+    let lockfile = Lockfile::load("./tests/support/transitive_cargo.lock").unwrap();
+    let mut advisory = Advisory::load_file("./tests/support/example_advisory_v3.md").unwrap();
+    advisory.versions = Versions::new(
+        vec!["~1.1.5".parse().unwrap(), ">= 1.2.3".parse().unwrap()],
+        vec![],
+    )
+    .unwrap();
+    let base = lockfile
+        .packages
+        .iter()
+        .find(|pkg| pkg.name.as_str() == "base")
+        .unwrap();
+    let vulnerability = Vulnerability::new(&advisory, base);
+
+    let dir = tempdir().unwrap();
+    let manifest = dir.path().join("Cargo.toml");
+    let fix_with = |manifest_contents: &str| {
+        fs::write(&manifest, manifest_contents).unwrap();
+        Fixer::new(lockfile.clone(), Some(manifest.clone()), None)
+            .compatible_fix(&vulnerability)
+            .unwrap()
+    };
+
+    // Without a requirement on `base`, the newest compatible patched version
+    assert_eq!(
+        fix_with("[package]\nname = \"app\"\n"),
+        Some(CompatibleFix::Update("1.2.3".parse().unwrap()))
+    );
+
+    // The newest patched version the requirement allows, even if renamed
+    assert_eq!(
+        fix_with("[target.'cfg(unix)'.dependencies]\nrenamed = { package = \"base\", version = \">= 1, < 1.2\" }\n"),
+        Some(CompatibleFix::Update("1.1.5".parse().unwrap()))
+    );
+
+    // Every requirement has to allow it
+    assert_eq!(
+        fix_with("[dependencies]\nbase = \"~1.0\"\n\n[dev-dependencies]\nbase = \">= 1, < 1.2\"\n"),
+        Some(CompatibleFix::RequiresBreakingChange)
+    );
+    assert_eq!(
+        fix_with("[workspace.dependencies]\nbase = \"=1.0.0\"\n\n[dependencies]\nbase = { workspace = true }\n"),
+        Some(CompatibleFix::RequiresBreakingChange)
+    );
+
+    // Requirements the locked version doesn't meet are for other copies
+    assert_eq!(
+        fix_with("[dependencies]\nbase = \"2\"\n"),
+        Some(CompatibleFix::Update("1.2.3".parse().unwrap()))
+    );
+
+    fs::write(&manifest, "[dependencies]\nbase = \"not a requirement\"\n").unwrap();
+    assert!(Fixer::new(lockfile.clone(), Some(manifest.clone()), None)
+        .compatible_fix(&vulnerability)
+        .is_err());
+}
@@ -12,6 +12,14 @@ fn valid_advisory() {
     assert_eq!(lint.errors(), &[]);
 }
 
+/// Ensure V4 advisories (without an `[advisory]` table) pass lint
+#[test]
+fn valid_v4_advisory() {
+    let lint =
+        rustsec::advisory::Linter::lint_file("./tests/support/example_advisory_v4.md").unwrap();
+    assert_eq!(lint.errors(), &[]);
+}
+
 /// Example advisory used in the subsequent `#[test]`
 const INVALID_ADVISORY_MD: &str = r#"```toml
 [advisory]
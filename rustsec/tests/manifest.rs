@@ -0,0 +1,149 @@
+//! Tests for verifying signed advisory database manifests
+#![cfg(feature = "manifest-signature")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use ring::{
+    digest,
+    signature::{Ed25519KeyPair, KeyPair},
+};
+use rustsec::{
+    database::{ManifestKey, OpenOptions, MANIFEST_FILE, MANIFEST_SIGNATURE_FILE},
+    Database, ErrorKind,
+};
+use std::{fs, path::Path};
+use tempfile::tempdir;
+
+const ADVISORY_PATH: &str = "crates/base64/RUSTSEC-2017-0004.md";
+
+const ADVISORY: &str = r#"```toml
+[advisory]
+id = "RUSTSEC-2017-0004"
+package = "base64"
+date = "2017-05-03"
+
+[versions]
+patched = [">= 0.5.2"]
+```
+
+# Integer overflow leads to heap-based buffer overflow in encode_config_buf
+"#;
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Write a database with one advisory and a manifest of it signed by `key_pair`
+fn write_signed_database(dir: &Path, key_pair: &Ed25519KeyPair) {
+    fs::create_dir_all(dir.join("crates/base64")).unwrap();
+    fs::write(dir.join(ADVISORY_PATH), ADVISORY).unwrap();
+
+    let manifest = manifest_line(ADVISORY_PATH, ADVISORY.as_bytes());
+    write_manifest(dir, key_pair, manifest.as_bytes());
+}
+
+/// Line of a manifest listing the file at `path` with the given contents
+fn manifest_line(path: &str, contents: &[u8]) -> String {
+    let hash = digest::digest(&digest::SHA256, contents);
+    format!("{}  {}\n", hex(hash.as_ref()), path)
+}
+
+/// Write `manifest` with a signature of it by `key_pair`
+fn write_manifest(dir: &Path, key_pair: &Ed25519KeyPair, manifest: &[u8]) {
+    fs::write(dir.join(MANIFEST_FILE), manifest).unwrap();
+    fs::write(
+        dir.join(MANIFEST_SIGNATURE_FILE),
+        hex(key_pair.sign(manifest).as_ref()),
+    )
+    .unwrap();
+}
+
+fn key(key_pair: &Ed25519KeyPair) -> ManifestKey {
+    hex(key_pair.public_key().as_ref()).parse().unwrap()
+}
+
+#[test]
+fn signed_manifest_is_verified_before_loading() {
+    let key_pair = Ed25519KeyPair::from_seed_unchecked(&[7; 32]).unwrap();
+    let dir = tempdir().unwrap();
+    write_signed_database(dir.path(), &key_pair);
+
+    let options = OpenOptions::new().verify_manifest(key(&key_pair));
+    let db = Database::open_with_options(dir.path(), &options).unwrap();
+    assert_eq!(db.iter().count(), 1);
+
+    // A manifest signed by someone else isn't trusted
+    let other = Ed25519KeyPair::from_seed_unchecked(&[8; 32]).unwrap();
+    let options = OpenOptions::new().verify_manifest(key(&other));
+    let err = Database::open_with_options(dir.path(), &options).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Verification);
+}
+
+#[test]
+fn tampered_manifest_fails_verification() {
+    let key_pair = Ed25519KeyPair::from_seed_unchecked(&[7; 32]).unwrap();
+    let options = OpenOptions::new().verify_manifest(key(&key_pair));
+
+    // An advisory was changed along with its hash in the manifest, which
+    // no longer matches its signature
+    let dir = tempdir().unwrap();
+    write_signed_database(dir.path(), &key_pair);
+    let tampered = ADVISORY.replace(">= 0.5.2", ">= 0.5.0");
+    fs::write(dir.path().join(ADVISORY_PATH), &tampered).unwrap();
+    let hash = digest::digest(&digest::SHA256, tampered.as_bytes());
+    fs::write(
+        dir.path().join(MANIFEST_FILE),
+        format!("{}  {}\n", hex(hash.as_ref()), ADVISORY_PATH),
+    )
+    .unwrap();
+    let err = Database::open_with_options(dir.path(), &options).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Verification);
+
+    // An advisory was changed without updating the manifest
+    let dir = tempdir().unwrap();
+    write_signed_database(dir.path(), &key_pair);
+    fs::write(dir.path().join(ADVISORY_PATH), &tampered).unwrap();
+    let err = Database::open_with_options(dir.path(), &options).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Verification);
+
+    // An advisory was added which the manifest doesn't list
+    let dir = tempdir().unwrap();
+    write_signed_database(dir.path(), &key_pair);
+    fs::write(
+        dir.path().join("crates/base64/RUSTSEC-2017-0005.md"),
+        ADVISORY.replace("RUSTSEC-2017-0004", "RUSTSEC-2017-0005"),
+    )
+    .unwrap();
+    let err = Database::open_with_options(dir.path(), &options).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Verification);
+
+    // Without a manifest there's nothing to trust
+    let dir = tempdir().unwrap();
+    write_signed_database(dir.path(), &key_pair);
+    fs::remove_file(dir.path().join(MANIFEST_FILE)).unwrap();
+    let err = Database::open_with_options(dir.path(), &options).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Verification);
+}
+
+#[test]
+fn signed_manifests_must_be_well_formed() {
+    let key_pair = Ed25519KeyPair::from_seed_unchecked(&[7; 32]).unwrap();
+    let options = OpenOptions::new().verify_manifest(key(&key_pair));
+
+    // Files outside the database can't be listed, even in a signed manifest
+    let root = tempdir().unwrap();
+    let dir = root.path().join("db");
+    write_signed_database(&dir, &key_pair);
+    fs::write(root.path().join("outside.md"), "outside").unwrap();
+    let manifest = manifest_line(ADVISORY_PATH, ADVISORY.as_bytes())
+        + &manifest_line("../outside.md", b"outside");
+    write_manifest(&dir, &key_pair, manifest.as_bytes());
+    let err = Database::open_with_options(&dir, &options).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Verification);
+
+    // A signed manifest which isn't text
+    let mut manifest = manifest_line(ADVISORY_PATH, ADVISORY.as_bytes()).into_bytes();
+    manifest.push(0xff);
+    write_manifest(&dir, &key_pair, &manifest);
+    let err = Database::open_with_options(&dir, &options).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Verification);
+}
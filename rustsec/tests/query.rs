@@ -63,3 +63,43 @@ fn matches_target_arch() {
     let query_normal = Query::new().target_arch(vec![Arch::Mips, Arch::Mips64]);
     assert!(!query_normal.matches(&advisory));
 }
+
+#[test]
+fn matches_target_cfg() {
+    let advisory: rustsec::Advisory = r#"```toml
+[advisory]
+id = "RUSTSEC-2001-2103"
+package = "base"
+date = "2001-02-03"
+
+[versions]
+patched = [">= 1.2.3"]
+
+[affected]
+cfg = "cfg(windows)"
+```
+
+# Windows-only vulnerability
+"#
+    .parse()
+    .unwrap();
+
+    let cfg = advisory.affected.as_ref().unwrap().cfg.as_ref().unwrap();
+    assert_eq!(cfg.to_string(), "windows");
+
+    let query_windows = Query::new()
+        .target_arch(vec![Arch::X86_64])
+        .target_os(vec![OS::Windows]);
+    assert!(query_windows.matches(&advisory));
+
+    let query_unix = Query::new()
+        .target_arch(vec![Arch::X86_64])
+        .target_os(vec![OS::Linux, OS::MacOS]);
+    assert!(!query_unix.matches(&advisory));
+
+    let query_any = Query::new().target_os(vec![OS::Linux, OS::Windows]);
+    assert!(query_any.matches(&advisory));
+
+    // Without a target, the advisory can't be ruled out
+    assert!(Query::new().matches(&advisory));
+}
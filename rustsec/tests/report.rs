@@ -0,0 +1,1111 @@
+//! Tests for generating and serializing vulnerability reports
+
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{
+    advisory::{affected::FunctionPath, Informational, Severity},
+    cargo_lock::{Dependency, ResolveVersion},
+    database::Query,
+    package,
+    platforms::target::{Arch, OS},
+    report::{DependencyPaths, FindingKind, SchemaVersion, Settings, VulnerabilityInfo},
+    Advisory, Database, Fixer, Lockfile, RemediationConfidence, Report, Vulnerability, WarningKind,
+};
+use std::{collections::BTreeMap, fs, path::Path};
+use tempfile::tempdir;
+
+/// Date and versions of RUSTSEC-2017-0004, which affects the base64 0.5.1 in
+/// `cratesio_cargo.lock`
+const BASE64_OVERFLOW: &str = r#"date = "2017-05-03"
+
+[versions]
+patched = [">= 0.5.2"]
+"#;
+
+/// Write advisory `id` for the crates.io crate `package` into the database at
+/// `dir`, with `toml` following its `id` and `package` fields
+fn write_advisory(dir: &Path, package: &str, id: &str, toml: &str) {
+    let package_dir = dir.join("crates").join(package);
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(
+        package_dir.join(format!("{}.md", id)),
+        format!(
+            "```toml\n[advisory]\nid = \"{}\"\npackage = \"{}\"\n{}```\n\n# Example advisory\n",
+            id, package, toml
+        ),
+    )
+    .unwrap();
+}
+
+/// Generate a report for the example lockfile against an empty database
+fn generate_report() -> Report {
+    let dir = tempdir().unwrap();
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    Report::generate(&db, &lockfile, &Settings::default())
+}
+
+#[test]
+fn serialize_schema_versions() {
+    let report = generate_report();
+
+    let v1 = serde_json::to_value(report.versioned(SchemaVersion::V1)).unwrap();
+    assert!(v1.get("schema-version").is_none());
+    assert!(v1.get("vulnerabilities").is_some());
+
+    let latest = serde_json::to_value(report.versioned(SchemaVersion::LATEST)).unwrap();
+    assert_eq!(latest["schema-version"], SchemaVersion::LATEST.as_u32());
+    assert_eq!(latest["vulnerabilities"], v1["vulnerabilities"]);
+}
+
+#[test]
+fn schema_v1_leaves_out_newer_fields() {
+    let dir = tempdir().unwrap();
+    write_advisory(dir.path(), "base64", "RUSTSEC-2017-0004", BASE64_OVERFLOW);
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+
+    let v1 = serde_json::to_value(report.versioned(SchemaVersion::V1)).unwrap();
+    assert!(v1.get("applied_ignores").is_none());
+    assert!(v1["database"].get("path").is_none());
+    assert!(v1["settings"].get("collection").is_none());
+
+    let vuln = &v1["vulnerabilities"]["list"][0];
+    assert_eq!(vuln["advisory"]["id"], "RUSTSEC-2017-0004");
+    assert!(vuln.get("patched_versions").is_none());
+    assert!(vuln.get("remediation_confidence").is_none());
+    assert!(vuln["advisory"].get("context").is_none());
+    assert!(vuln["affected"].is_null());
+
+    let latest = serde_json::to_value(report.versioned(SchemaVersion::LATEST)).unwrap();
+    let vuln = &latest["vulnerabilities"]["list"][0];
+    assert_eq!(vuln["patched_versions"][0], ">=0.5.2");
+}
+
+#[test]
+fn deserialize_schema_versions() {
+    let dir = tempdir().unwrap();
+    write_advisory(
+        dir.path(),
+        "base64",
+        "RUSTSEC-2017-0004",
+        r#"date = "2017-05-03"
+cvss = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P"
+
+[versions]
+patched = [">= 0.5.2"]
+
+[affected]
+os = ["linux"]
+functions = { "base64::encode_config_buf" = ["< 0.5.2"] }
+"#,
+    );
+    write_advisory(
+        dir.path(),
+        "byteorder",
+        "RUSTSEC-2001-2101",
+        r#"date = "2001-02-03"
+informational = "unmaintained"
+
+[versions]
+patched = []
+"#,
+    );
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    let settings = Settings {
+        informational_warnings: vec![Informational::Unmaintained],
+        ..Settings::default()
+    };
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert_eq!(report.warnings[&WarningKind::Unmaintained].len(), 1);
+
+    for version in [SchemaVersion::V1, SchemaVersion::LATEST] {
+        let json = serde_json::to_string(&report.versioned(version)).unwrap();
+        let parsed: Report = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.vulnerabilities.count, 1);
+        assert_eq!(
+            parsed.vulnerabilities.list[0].advisory.id,
+            report.vulnerabilities.list[0].advisory.id
+        );
+        assert_eq!(parsed.warnings[&WarningKind::Unmaintained].len(), 1);
+    }
+}
+
+#[test]
+fn parse_schema_version() {
+    assert_eq!("1".parse::<SchemaVersion>().unwrap(), SchemaVersion::V1);
+    assert!("0".parse::<SchemaVersion>().is_err());
+    assert!("999".parse::<SchemaVersion>().is_err());
+}
+
+#[test]
+fn group_vulnerabilities_by_keyword() {
+    let advisory = Advisory::load_file("./tests/support/example_advisory_v3.md").unwrap();
+    let mut uncategorized = advisory.clone();
+    uncategorized.metadata.keywords.clear();
+
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    let package = &lockfile.packages[0];
+
+    let info = VulnerabilityInfo::new(vec![
+        Vulnerability::new(&advisory, package),
+        Vulnerability::new(&uncategorized, package),
+    ]);
+    let groups = info.group_by_keyword();
+
+    assert_eq!(groups.len(), advisory.keywords().len() + 1);
+    assert_eq!(groups["gentlemen"].len(), 1);
+    assert_eq!(groups[VulnerabilityInfo::UNCATEGORIZED].len(), 1);
+}
+
+#[test]
+fn future_dated_advisories_are_upcoming() {
+    let dir = tempdir().unwrap();
+    write_advisory(
+        dir.path(),
+        "base64",
+        "RUSTSEC-2099-0001",
+        r#"date = "2099-01-01"
+
+[versions]
+patched = [">= 0.6.0"]
+"#,
+    );
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+
+    assert!(!report.vulnerabilities.found);
+    let upcoming = &report.warnings[&WarningKind::Upcoming];
+    assert_eq!(upcoming.len(), 1);
+    assert!(upcoming[0].is_upcoming());
+    assert_eq!(upcoming[0].package.name.as_str(), "base64");
+}
+
+#[test]
+fn crate_from_multiple_sources_is_reported_per_source() {
+    let dir = tempdir().unwrap();
+    write_advisory(dir.path(), "base64", "RUSTSEC-2017-0004", BASE64_OVERFLOW);
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/two_sources_cargo.lock").unwrap();
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+
+    let mut sources: Vec<_> = report
+        .vulnerabilities
+        .list
+        .iter()
+        .map(|vuln| {
+            assert_eq!(vuln.package.name.as_str(), "base64");
+            vuln.package.source.as_ref().unwrap().to_string()
+        })
+        .collect();
+    sources.sort();
+
+    assert_eq!(report.vulnerabilities.count, 2);
+    assert!(sources[0].starts_with("git+https://github.com/example/base64"));
+    assert!(sources[1].starts_with("registry+"));
+}
+
+#[test]
+fn deduplicate_vulnerabilities_across_paths() {
+    let dir = tempdir().unwrap();
+    write_advisory(dir.path(), "base64", "RUSTSEC-2017-0004", BASE64_OVERFLOW);
+
+    let db = Database::open(dir.path()).unwrap();
+
+    // `base64` is reached through both `left` and `right`
+    let lockfile = Lockfile::load("./tests/support/diamond_cargo.lock").unwrap();
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+    let deduped = report.vulnerabilities.deduplicated(&lockfile);
+    assert_eq!(deduped.len(), 1);
+    assert_eq!(
+        deduped[0].vulnerability.advisory.id.as_str(),
+        "RUSTSEC-2017-0004"
+    );
+    assert_eq!(deduped[0].paths, 2);
+    assert!(!deduped[0].direct);
+
+    let affected_paths = report.vulnerabilities.list[0]
+        .affected_paths
+        .as_ref()
+        .unwrap();
+    assert!(!affected_paths.truncated);
+    let mut paths: Vec<Vec<_>> = affected_paths
+        .paths
+        .iter()
+        .map(|path| path.iter().map(|dep| dep.name.as_str()).collect())
+        .collect();
+    paths.sort();
+    assert_eq!(
+        paths,
+        [
+            vec!["app", "left", "base64"],
+            vec!["app", "right", "base64"]
+        ]
+    );
+
+    let json = serde_json::to_value(report).unwrap();
+    assert_eq!(
+        json["vulnerabilities"]["list"][0]["affected_paths"]["paths"]
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+
+    // Copies from several sources are separate packages, each with its own paths
+    let lockfile = Lockfile::load("./tests/support/two_sources_cargo.lock").unwrap();
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+    assert_eq!(report.vulnerabilities.list.len(), 2);
+    let deduped = report.vulnerabilities.deduplicated(&lockfile);
+    assert_eq!(deduped.len(), 2);
+    assert_ne!(
+        deduped[0].vulnerability.package.source,
+        deduped[1].vulnerability.package.source
+    );
+    assert!(deduped.iter().all(|entry| entry.paths == 1 && entry.direct));
+}
+
+#[test]
+fn shortest_dependency_path() {
+    let lockfile = Lockfile::load("./tests/support/diamond_cargo.lock").unwrap();
+    let base64 = lockfile
+        .packages
+        .iter()
+        .find(|package| package.name.as_str() == "base64")
+        .unwrap();
+
+    let path = DependencyPaths::new(&lockfile).shortest(&Dependency::from(base64));
+    let names: Vec<_> = path.iter().map(|dep| dep.name.as_str()).collect();
+    assert_eq!(names.len(), 3);
+    assert_eq!(names[0], "app");
+    assert_eq!(names[2], "base64");
+}
+
+#[test]
+fn advisories_with_separate_affected_ranges() {
+    let dir = tempdir().unwrap();
+    write_advisory(
+        dir.path(),
+        "base64",
+        "RUSTSEC-2001-2101",
+        r#"date = "2001-02-03"
+
+[versions]
+patched = []
+affected_ranges = ["^1", "^3"]
+"#,
+    );
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile: Lockfile = r#"
+version = 3
+
+[[package]]
+name = "base64"
+version = "1.2.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "base64"
+version = "2.1.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "base64"
+version = "3.0.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#
+    .parse()
+    .unwrap();
+
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+    let mut versions: Vec<_> = report
+        .vulnerabilities
+        .list
+        .iter()
+        .map(|vuln| vuln.package.version.to_string())
+        .collect();
+    versions.sort();
+    assert_eq!(versions, ["1.2.0", "3.0.1"]);
+}
+
+#[test]
+fn virtual_workspace_members_are_path_roots() {
+    let dir = tempdir().unwrap();
+    write_advisory(dir.path(), "base64", "RUSTSEC-2017-0004", BASE64_OVERFLOW);
+
+    let db = Database::open(dir.path()).unwrap();
+
+    // No root package: `member-a` depends on `base64` and on `member-b`,
+    // which reaches `base64` through `middle`
+    let lockfile = Lockfile::load("./tests/support/virtual_workspace_cargo.lock").unwrap();
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+    let deduped = report.vulnerabilities.deduplicated(&lockfile);
+    assert_eq!(deduped.len(), 1);
+
+    // member-a -> base64, member-a -> member-b -> middle -> base64,
+    // and member-b -> middle -> base64
+    assert_eq!(deduped[0].paths, 3);
+    assert!(deduped[0].direct);
+
+    let fixer = Fixer::new(lockfile.clone(), None, None);
+    let middle = lockfile
+        .packages
+        .iter()
+        .find(|pkg| pkg.name.as_str() == "middle")
+        .unwrap();
+    assert!(fixer.is_direct_dependency(middle));
+}
+
+#[test]
+fn toolchain_advisories_match_rust_version() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("rust/std")).unwrap();
+    fs::write(
+        dir.path().join("rust/std/CVE-2021-28876.md"),
+        r#"```toml
+[advisory]
+id = "CVE-2021-28876"
+package = "std"
+date = "2021-02-18"
+
+[versions]
+patched = [">= 1.52.0"]
+```
+
+# Panic safety issue in `Zip` specialization
+"#,
+    )
+    .unwrap();
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    let report_for = |rust_version: &str| {
+        let settings = Settings::builder()
+            .rust_version(rust_version.parse().unwrap())
+            .build()
+            .unwrap();
+        Report::generate(&db, &lockfile, &settings)
+    };
+
+    let report = report_for("1.51.0");
+    assert_eq!(report.vulnerabilities.count, 1);
+    let vuln = &report.vulnerabilities.list[0];
+    assert_eq!(vuln.advisory.id.as_str(), "CVE-2021-28876");
+    assert_eq!(vuln.package.name.as_str(), "std");
+    assert_eq!(vuln.package.version.to_string(), "1.51.0");
+
+    assert!(!report_for("1.52.0").vulnerabilities.found);
+
+    // Toolchain advisories are only checked when a rustc version is given
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+    assert!(!report.vulnerabilities.found);
+}
+
+#[test]
+fn feature_conditional_advisory_context() {
+    let dir = tempdir().unwrap();
+    write_advisory(
+        dir.path(),
+        "base64",
+        "RUSTSEC-2017-0004",
+        r#"date = "2017-05-03"
+context = "Only affected when the `std` feature is enabled"
+
+[versions]
+patched = [">= 0.5.2"]
+
+[affected]
+features = ["std"]
+"#,
+    );
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+
+    // Cargo.lock doesn't record enabled features, so the advisory is reported
+    // along with the note explaining when it applies
+    assert_eq!(report.vulnerabilities.count, 1);
+    let json = serde_json::to_value(&report).unwrap();
+    let vuln = &json["vulnerabilities"]["list"][0];
+    assert_eq!(
+        vuln["advisory"]["context"],
+        "Only affected when the `std` feature is enabled"
+    );
+    assert_eq!(vuln["affected"]["features"][0], "std");
+
+    let query = Query::crate_scope().enabled_features(vec!["alloc".to_owned()]);
+    assert!(db.query_vulnerabilities(&lockfile, &query).is_empty());
+
+    let query = Query::crate_scope().enabled_features(vec!["std".to_owned()]);
+    assert_eq!(db.query_vulnerabilities(&lockfile, &query).len(), 1);
+}
+
+#[test]
+fn report_as_of_date() {
+    let dir = tempdir().unwrap();
+    write_advisory(
+        dir.path(),
+        "base64",
+        "RUSTSEC-2017-0004",
+        r#"date = "2017-05-03"
+
+[versions]
+patched = [">= 0.5.2"]
+"#,
+    );
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    let report_as_of = |date: &str| {
+        let settings = Settings::builder()
+            .as_of(date.parse().unwrap())
+            .build()
+            .unwrap();
+        Report::generate(&db, &lockfile, &settings)
+    };
+
+    // The affected version was in use, but the advisory didn't exist yet
+    let report = report_as_of("2017-05-02");
+    assert!(!report.vulnerabilities.found);
+    assert_eq!(report.settings.as_of, Some("2017-05-02".parse().unwrap()));
+
+    assert!(report_as_of("2017-05-03").vulnerabilities.found);
+}
+
+#[test]
+fn ignore_advisory_by_alias() {
+    let dir = tempdir().unwrap();
+    write_advisory(
+        dir.path(),
+        "base64",
+        "RUSTSEC-2017-0004",
+        r#"date = "2017-05-03"
+aliases = ["CVE-2017-1000430", "GHSA-r7q2-5gqg-6c7q"]
+
+[versions]
+patched = [">= 0.5.2"]
+"#,
+    );
+
+    let db = Database::open(dir.path()).unwrap();
+    let ghsa_id = "GHSA-r7q2-5gqg-6c7q".parse().unwrap();
+    assert_eq!(db.get(&ghsa_id).unwrap().id().as_str(), "RUSTSEC-2017-0004");
+
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    assert!(
+        Report::generate(&db, &lockfile, &Settings::default())
+            .vulnerabilities
+            .found
+    );
+
+    // Only the ignore which matched a finding took effect
+    let unused_id = "RUSTSEC-2001-2101".parse().unwrap();
+    let settings = Settings::builder()
+        .ignore([ghsa_id.clone(), unused_id])
+        .build()
+        .unwrap();
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert!(!report.vulnerabilities.found);
+    assert_eq!(report.applied_ignores, [ghsa_id]);
+}
+
+#[test]
+fn unsound_advisories_as_vulnerabilities() {
+    let dir = tempdir().unwrap();
+    write_advisory(
+        dir.path(),
+        "base64",
+        "RUSTSEC-2017-0099",
+        r#"date = "2017-05-03"
+informational = "unsound"
+
+[versions]
+patched = [">= 0.5.2"]
+"#,
+    );
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+
+    let settings = Settings::builder()
+        .informational_warnings(true)
+        .build()
+        .unwrap();
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert!(!report.vulnerabilities.found);
+    assert_eq!(report.warnings[&WarningKind::Unsound].len(), 1);
+
+    let settings = Settings::builder()
+        .informational_warnings(true)
+        .unsound_as_vulnerabilities(true)
+        .build()
+        .unwrap();
+    let report = Report::generate(&db, &lockfile, &settings);
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json.pointer("/vulnerabilities/count").unwrap(), 1);
+    assert_eq!(
+        report.vulnerabilities.list[0].advisory.id.as_str(),
+        "RUSTSEC-2017-0099"
+    );
+    assert!(!report.warnings.contains_key(&WarningKind::Unsound));
+}
+
+#[test]
+fn feature_gated_advisory_matches_enabled_features() {
+    let dir = tempdir().unwrap();
+    write_advisory(
+        dir.path(),
+        "base64",
+        "RUSTSEC-2017-0004",
+        r#"date = "2017-05-03"
+
+[versions]
+patched = [">= 0.5.2"]
+
+[affected]
+features = ["std"]
+"#,
+    );
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    let generate = |version: &str, features: &[&str]| {
+        let settings = Settings::builder()
+            .enabled_features(
+                "base64".parse().unwrap(),
+                version.parse().unwrap(),
+                features.iter().map(|feature| feature.to_string()),
+            )
+            .build()
+            .unwrap();
+        Report::generate(&db, &lockfile, &settings)
+    };
+
+    let report = generate("0.5.1", &["std"]);
+    assert_eq!(report.vulnerabilities.count, 1);
+    assert_eq!(report.vulnerabilities.list[0].features_enabled, Some(true));
+    assert!(!generate("0.5.1", &["alloc"]).vulnerabilities.found);
+    assert!(!generate("0.5.1", &[]).vulnerabilities.found);
+
+    // Features aren't known for other versions or packages, so they aren't
+    // filtered, but the vulnerability is flagged as possibly not applying
+    let report = generate("0.6.0", &[]);
+    assert_eq!(report.vulnerabilities.count, 1);
+    assert_eq!(report.vulnerabilities.list[0].features_enabled, None);
+
+    let settings = Settings::builder()
+        .enabled_features(
+            "byteorder".parse().unwrap(),
+            "1.3.1".parse().unwrap(),
+            vec![],
+        )
+        .build()
+        .unwrap();
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert_eq!(report.vulnerabilities.count, 1);
+    assert_eq!(report.vulnerabilities.list[0].features_enabled, None);
+}
+
+#[test]
+fn unreachable_vulnerabilities_from_hint_file() {
+    let dir = tempdir().unwrap();
+    write_advisory(
+        dir.path(),
+        "base64",
+        "RUSTSEC-2017-0004",
+        r#"date = "2017-05-03"
+
+[versions]
+patched = [">= 0.5.2"]
+
+[affected]
+functions = { "base64::encode_config_buf" = ["< 0.5.2"] }
+"#,
+    );
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+
+    // Hint file from a call graph analysis which never reaches `encode_config_buf`
+    let hints: BTreeMap<package::Name, Vec<FunctionPath>> =
+        toml::from_str(r#"base64 = ["base64::decode", "base64::encode"]"#).unwrap();
+    let mut settings = Settings {
+        reachable_functions: hints,
+        ..Default::default()
+    };
+
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert_eq!(report.vulnerabilities.count, 1);
+    assert_eq!(report.vulnerabilities.list[0].reachable, Some(false));
+
+    settings.unreachable_as_warnings = true;
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert!(!report.vulnerabilities.found);
+    let unreachable = &report.warnings[&WarningKind::Unreachable];
+    assert_eq!(unreachable.len(), 1);
+    assert!(unreachable[0].is_unreachable());
+
+    // Reachable vulnerabilities are still reported as such
+    let settings = Settings::builder()
+        .reachable_functions(
+            "base64".parse().unwrap(),
+            vec!["base64::encode_config_buf".parse().unwrap()],
+        )
+        .unreachable_as_warnings(true)
+        .build()
+        .unwrap();
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert_eq!(report.vulnerabilities.list[0].reachable, Some(true));
+}
+
+#[test]
+fn unscored_advisories_with_require_severity() {
+    let dir = tempdir().unwrap();
+    for (id, cvss) in [
+        ("RUSTSEC-2017-0004", ""),
+        (
+            "RUSTSEC-2017-0097",
+            "cvss = \"CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H\"\n",
+        ),
+    ] {
+        write_advisory(
+            dir.path(),
+            "base64",
+            id,
+            &format!("{}{}", cvss, BASE64_OVERFLOW),
+        );
+    }
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+    assert_eq!(report.vulnerabilities.count, 2);
+    assert_eq!(report.vulnerabilities.unscored().count(), 0);
+
+    // Unscored vulnerabilities are still reported, even above a threshold
+    let settings = Settings::builder()
+        .severity(Severity::Critical)
+        .require_severity(true)
+        .build()
+        .unwrap();
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert_eq!(report.vulnerabilities.count, 2);
+    assert!(report.warnings.is_empty());
+
+    let unscored: Vec<_> = report.vulnerabilities.unscored().collect();
+    assert_eq!(unscored.len(), 1);
+    assert_eq!(unscored[0].advisory.id.as_str(), "RUSTSEC-2017-0004");
+}
+
+#[test]
+fn transitive_vulnerabilities_with_direct_only() {
+    let dir = tempdir().unwrap();
+    write_advisory(dir.path(), "base64", "RUSTSEC-2017-0004", BASE64_OVERFLOW);
+
+    let db = Database::open(dir.path()).unwrap();
+
+    // `app` only depends on `base64` through `envelope`
+    let lockfile: Lockfile = r#"
+version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = ["envelope"]
+
+[[package]]
+name = "base64"
+version = "0.5.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "envelope"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = ["base64"]
+"#
+    .parse()
+    .unwrap();
+
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+    assert_eq!(report.vulnerabilities.count, 1);
+
+    let settings = Settings::builder().direct_only(true).build().unwrap();
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert!(!report.vulnerabilities.found);
+    assert!(report.warnings.is_empty());
+
+    let settings = Settings::builder()
+        .direct_only(true)
+        .transitive_as_warnings(true)
+        .build()
+        .unwrap();
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert!(!report.vulnerabilities.found);
+    let transitive = &report.warnings[&WarningKind::Transitive];
+    assert_eq!(transitive.len(), 1);
+    assert!(transitive[0].is_transitive());
+}
+
+#[test]
+fn crate_severity_overrides_global_threshold() {
+    let dir = tempdir().unwrap();
+    for (package, id, patched) in [
+        ("base64", "RUSTSEC-2017-0004", ">= 0.5.2"),
+        ("envelope", "RUSTSEC-2017-0097", ">= 1.0.1"),
+    ] {
+        write_advisory(
+            dir.path(),
+            package,
+            id,
+            &format!(
+                r#"date = "2017-05-03"
+cvss = "CVSS:3.1/AV:N/AC:H/PR:N/UI:R/S:U/C:L/I:L/A:N"
+
+[versions]
+patched = ["{}"]
+"#,
+                patched
+            ),
+        );
+    }
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile: Lockfile = r#"
+version = 3
+
+[[package]]
+name = "base64"
+version = "0.5.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "envelope"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#
+    .parse()
+    .unwrap();
+
+    let settings = Settings::builder()
+        .severity(Severity::High)
+        .build()
+        .unwrap();
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert!(!report.vulnerabilities.found);
+
+    // `base64` is critical to this project, so medium severity is enough
+    let settings = Settings::builder()
+        .severity(Severity::High)
+        .crate_severity("base64".parse().unwrap(), Severity::Medium)
+        .build()
+        .unwrap();
+    let report = Report::generate(&db, &lockfile, &settings);
+    assert_eq!(report.vulnerabilities.count, 1);
+    assert_eq!(
+        report.vulnerabilities.list[0].advisory.id.as_str(),
+        "RUSTSEC-2017-0004"
+    );
+}
+
+#[test]
+fn advisories_only_match_crates_from_their_registry() {
+    let dir = tempdir().unwrap();
+    for (id, source) in [
+        ("RUSTSEC-2017-0004", ""),
+        (
+            "RUSTSEC-2017-0097",
+            "source = \"registry+https://registry.example.com/index\"\n",
+        ),
+    ] {
+        write_advisory(
+            dir.path(),
+            "base64",
+            id,
+            &format!("{}{}", source, BASE64_OVERFLOW),
+        );
+    }
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/alt_registry_cargo.lock").unwrap();
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+
+    let mut matches: Vec<_> = report
+        .vulnerabilities
+        .list
+        .iter()
+        .map(|vuln| {
+            format!(
+                "{} {}",
+                vuln.advisory.id,
+                vuln.package.source.as_ref().unwrap()
+            )
+        })
+        .collect();
+    matches.sort();
+
+    // The crates.io advisory doesn't fire on the same-named crate from the
+    // other registry, and vice versa
+    assert_eq!(
+        matches,
+        [
+            "RUSTSEC-2017-0004 registry+https://github.com/rust-lang/crates.io-index",
+            "RUSTSEC-2017-0097 registry+https://registry.example.com/index",
+        ]
+    );
+}
+
+#[test]
+fn build_settings_fluently() {
+    let id = "RUSTSEC-2001-2101".parse().unwrap();
+
+    let built = Settings::builder()
+        .ignore([id])
+        .target("x86_64-unknown-linux-gnu")
+        .severity(Severity::High)
+        .informational_warnings(true)
+        .build()
+        .unwrap();
+
+    let direct = Settings {
+        target_arch: vec![Arch::X86_64],
+        target_os: vec![OS::Linux],
+        severity: Some(Severity::High),
+        cvss_preference: Default::default(),
+        ignore: vec!["RUSTSEC-2001-2101".parse().unwrap()],
+        informational_warnings: vec![
+            Informational::Notice,
+            Informational::Unmaintained,
+            Informational::Unsound,
+        ],
+        collection: None,
+        as_of: None,
+        rust_version: None,
+        unsound_as_vulnerabilities: false,
+        enabled_features: Default::default(),
+        reachable_functions: Default::default(),
+        unreachable_as_warnings: false,
+        require_severity: false,
+        direct_only: false,
+        transitive_as_warnings: false,
+        require_checksums: false,
+        crate_severity: Default::default(),
+        stats: false,
+    };
+
+    assert_eq!(
+        serde_json::to_value(&built).unwrap(),
+        serde_json::to_value(&direct).unwrap()
+    );
+
+    assert!(Settings::builder().target("not-a-target").build().is_err());
+}
+
+#[test]
+fn report_from_database_and_lockfile_paths() {
+    let dir = tempdir().unwrap();
+    write_advisory(dir.path(), "base64", "RUSTSEC-2017-0004", BASE64_OVERFLOW);
+
+    let report = Report::new_from_lockfile(
+        dir.path(),
+        Path::new("./tests/support/cratesio_cargo.lock"),
+        &Settings::default(),
+    )
+    .unwrap();
+
+    assert!(report.vulnerabilities.found);
+    assert_eq!(report.vulnerabilities.list.len(), 1);
+    let vulnerability = &report.vulnerabilities.list[0];
+    assert_eq!(vulnerability.advisory.id.as_str(), "RUSTSEC-2017-0004");
+    assert_eq!(vulnerability.package.name.as_str(), "base64");
+
+    assert!(Report::new_from_lockfile(
+        dir.path(),
+        Path::new("./tests/support/missing_cargo.lock"),
+        &Settings::default(),
+    )
+    .is_err());
+}
+
+#[test]
+fn stats_count_candidate_advisories() {
+    let dir = tempdir().unwrap();
+    write_advisory(dir.path(), "base64", "RUSTSEC-2017-0004", BASE64_OVERFLOW);
+    // Doesn't affect base64 0.5.1 in the lockfile, but is still a candidate
+    write_advisory(
+        dir.path(),
+        "base64",
+        "RUSTSEC-2017-0005",
+        &BASE64_OVERFLOW.replace(">= 0.5.2", ">= 0.3.0"),
+    );
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+    assert!(report.stats.is_none());
+    let json = serde_json::to_value(report.versioned(SchemaVersion::LATEST)).unwrap();
+    assert!(json.get("stats").is_none());
+
+    let settings = Settings::builder().stats(true).build().unwrap();
+    let report = Report::generate(&db, &lockfile, &settings);
+    let stats = report.stats.as_ref().unwrap();
+    assert_eq!(stats.candidate_advisories, 2);
+    assert_eq!(stats.packages.len(), 1);
+
+    let (name, base64) = stats.hottest()[0];
+    assert_eq!(name.as_str(), "base64");
+    assert_eq!(base64.candidates, 2);
+    assert_eq!(base64.matched, 1);
+
+    let json = serde_json::to_value(report.versioned(SchemaVersion::LATEST)).unwrap();
+    assert_eq!(json["stats"]["candidate-advisories"], 2);
+    assert_eq!(json["stats"]["packages"]["base64"]["matched"], 1);
+}
+
+#[test]
+fn scan_ids_match_report() {
+    let dir = tempdir().unwrap();
+    for (id, extra, date) in [
+        ("RUSTSEC-2017-0004", "", "2017-05-03"),
+        (
+            "RUSTSEC-2017-0098",
+            "informational = \"unsound\"\n",
+            "2017-05-03",
+        ),
+        ("RUSTSEC-2099-0001", "", "2099-01-01"),
+    ] {
+        write_advisory(
+            dir.path(),
+            "base64",
+            id,
+            &format!(
+                r#"date = "{}"
+{}
+[versions]
+patched = [">= 0.5.2"]
+"#,
+                date, extra
+            ),
+        );
+    }
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    let settings = Settings::builder()
+        .informational_warnings(true)
+        .build()
+        .unwrap();
+
+    let report = Report::generate(&db, &lockfile, &settings);
+    let ids = rustsec::report::scan_ids(&db, &lockfile, &settings);
+
+    assert_eq!(
+        ids,
+        report
+            .vulnerabilities
+            .list
+            .iter()
+            .map(|vuln| vuln.advisory.id.clone())
+            .collect()
+    );
+    assert_eq!(ids.len(), 1);
+    assert_eq!(ids.first().unwrap().as_str(), "RUSTSEC-2017-0004");
+}
+
+#[test]
+fn all_findings_merge_vulnerabilities_and_warnings() {
+    let dir = tempdir().unwrap();
+    for (id, extra, date) in [
+        (
+            "RUSTSEC-2017-0004",
+            "cvss = \"CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H\"\n",
+            "2017-05-03",
+        ),
+        (
+            "RUSTSEC-2017-0098",
+            "informational = \"unsound\"\n",
+            "2017-05-03",
+        ),
+        ("RUSTSEC-2099-0001", "", "2099-01-01"),
+    ] {
+        write_advisory(
+            dir.path(),
+            "base64",
+            id,
+            &format!(
+                r#"date = "{}"
+{}
+[versions]
+patched = [">= 0.5.2"]
+"#,
+                date, extra
+            ),
+        );
+    }
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo.lock").unwrap();
+    let settings = Settings::builder()
+        .informational_warnings(true)
+        .build()
+        .unwrap();
+
+    let report = Report::generate(&db, &lockfile, &settings);
+    let findings = report.all_findings();
+
+    let warning_count: usize = report.warnings.values().map(Vec::len).sum();
+    assert_eq!(warning_count, 2);
+    assert_eq!(findings.len(), report.vulnerabilities.count + warning_count);
+
+    let kinds: Vec<_> = findings.iter().map(|finding| finding.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            FindingKind::Vulnerability,
+            FindingKind::Warning(WarningKind::Unsound),
+            FindingKind::Warning(WarningKind::Upcoming),
+        ]
+    );
+
+    let vulnerability = &findings[0];
+    assert_eq!(vulnerability.package.as_str(), "base64");
+    assert_eq!(
+        vulnerability.id.as_ref().unwrap().as_str(),
+        "RUSTSEC-2017-0004"
+    );
+    assert_eq!(vulnerability.severity, Some(Severity::Critical));
+    assert_eq!(vulnerability.title.as_deref(), Some("Example advisory"));
+}
+
+#[test]
+fn scan_v4_lockfile() {
+    let dir = tempdir().unwrap();
+    write_advisory(dir.path(), "base64", "RUSTSEC-2017-0004", BASE64_OVERFLOW);
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/cratesio_cargo_v4.lock").unwrap();
+    assert_eq!(lockfile.version, ResolveVersion::V4);
+
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+    assert_eq!(report.vulnerabilities.count, 1);
+
+    let vulnerability = &report.vulnerabilities.list[0];
+    assert_eq!(vulnerability.advisory.id.as_str(), "RUSTSEC-2017-0004");
+    assert_eq!(vulnerability.package.name.as_str(), "base64");
+    assert!(vulnerability.package.checksum.is_some());
+    assert_eq!(
+        vulnerability.remediation_confidence,
+        RemediationConfidence::Patch
+    );
+}
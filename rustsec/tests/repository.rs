@@ -0,0 +1,659 @@
+//! Tests for fetching advisory DB repositories from local `file://` remotes
+#![cfg(feature = "git")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{
+    repository::git::{
+        CommitHash, FetchOptions, FetchStatus, Repository, SharedRepository, Transport, UrlMismatch,
+    },
+    Database, Error, ErrorKind,
+};
+use std::{
+    fs,
+    path::Path,
+    process::Command,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+use tame_index::external::gix;
+use tempfile::tempdir;
+
+/// Run `git` with the given arguments in `dir`, panicking on failure
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args([
+            "-c",
+            "user.name=rustsec",
+            "-c",
+            "user.email=rustsec@example.com",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .expect("git should be installed");
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Create a source repository with a single commit
+fn create_source_repo(dir: &Path) {
+    git(dir, &["init", "--quiet"]);
+    fs::create_dir_all(dir.join("crates/example")).unwrap();
+    fs::write(dir.join("crates/example/README.md"), "example\n").unwrap();
+    git(dir, &["add", "."]);
+    git(dir, &["commit", "--quiet", "-m", "Initial commit"]);
+}
+
+/// Count the packfiles stored in a repository's own object directory
+fn count_packs(git_dir: &Path) -> usize {
+    fs::read_dir(git_dir.join("objects/pack"))
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().extension().map_or(false, |ext| ext == "pack"))
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+#[test]
+fn clones_share_object_cache() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    fs::create_dir_all(&source).unwrap();
+    create_source_repo(&source);
+
+    let url = format!("file://{}", source.display());
+    let cache = tmp.path().join("cache");
+    let options = FetchOptions::new().ensure_fresh(false).object_cache(&cache);
+
+    let first = Repository::fetch_with_options(&url, tmp.path().join("first"), &options).unwrap();
+    let second = Repository::fetch_with_options(&url, tmp.path().join("second"), &options).unwrap();
+
+    assert!(count_packs(&cache) > 0);
+    assert_eq!(count_packs(&tmp.path().join("second/.git")), 0);
+    assert_eq!(
+        first.latest_commit().unwrap().commit_id,
+        second.latest_commit().unwrap().commit_id
+    );
+    assert!(second.path().join("crates/example/README.md").exists());
+}
+
+#[test]
+fn remote_urls_are_escaped_in_git_config() {
+    let tmp = tempdir().unwrap();
+
+    // `;` starts a comment in git config, so written raw the URL would be cut short
+    let source = tmp.path().join("source;1");
+    fs::create_dir_all(&source).unwrap();
+    create_source_repo(&source);
+
+    let url = format!("file://{}", source.display());
+    let cached = FetchOptions::new()
+        .ensure_fresh(false)
+        .object_cache(tmp.path().join("cache"));
+    let custom_transport = FetchOptions::new().ensure_fresh(false).transport(|url| {
+        gix::protocol::transport::connect(url, Default::default())
+            .map(Transport::new)
+            .map_err(|err| Error::new(ErrorKind::Repo, &err))
+    });
+
+    for (name, options) in [("cached", cached), ("custom", custom_transport)] {
+        let clone = tmp.path().join(name);
+        Repository::fetch_with_options(&url, &clone, &options).unwrap();
+
+        // Fetching again checks the recorded `origin` is still the same URL
+        Repository::fetch_with_options(&url, &clone, &options).unwrap();
+    }
+}
+
+#[test]
+fn missing_ca_bundle_is_rejected() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    fs::create_dir_all(&source).unwrap();
+    create_source_repo(&source);
+
+    let url = format!("file://{}", source.display());
+    let options = FetchOptions::new()
+        .ensure_fresh(false)
+        .ca_bundle(tmp.path().join("missing-ca.pem"));
+
+    let err = Repository::fetch_with_options(&url, tmp.path().join("clone"), &options)
+        .err()
+        .expect("fetch should fail without the CA bundle");
+    assert_eq!(err.kind(), ErrorKind::BadParam);
+    assert!(!tmp.path().join("clone").exists());
+}
+
+#[test]
+fn shared_repository_across_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SharedRepository>();
+
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    fs::create_dir_all(&source).unwrap();
+    create_source_repo(&source);
+
+    let url = format!("file://{}", source.display());
+    let options = FetchOptions::new().ensure_fresh(false);
+    let repo = Repository::fetch_with_options(&url, tmp.path().join("clone"), &options).unwrap();
+    let expected = repo.latest_commit().unwrap().commit_id;
+    let shared = repo.into_shared();
+
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                let repo = shared.to_repository();
+                assert_eq!(repo.latest_commit().unwrap().commit_id, expected);
+                assert!(repo.has_relative_path(Path::new("crates/example/README.md")));
+            });
+        }
+    });
+
+    assert_eq!(
+        shared.path(),
+        SharedRepository::open(shared.path()).unwrap().path()
+    );
+}
+
+#[test]
+fn checkout_older_commit() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    let package_dir = source.join("crates/base");
+    fs::create_dir_all(&package_dir).unwrap();
+
+    let advisory = fs::read_to_string("./tests/support/example_advisory_v3.md").unwrap();
+    git(&source, &["init", "--quiet"]);
+    fs::write(package_dir.join("RUSTSEC-2001-2101.md"), &advisory).unwrap();
+    git(&source, &["add", "."]);
+    git(
+        &source,
+        &["commit", "--quiet", "-m", "Add RUSTSEC-2001-2101"],
+    );
+    fs::write(
+        package_dir.join("RUSTSEC-2001-2102.md"),
+        advisory.replace("RUSTSEC-2001-2101", "RUSTSEC-2001-2102"),
+    )
+    .unwrap();
+    git(&source, &["add", "."]);
+    git(
+        &source,
+        &["commit", "--quiet", "-m", "Add RUSTSEC-2001-2102"],
+    );
+
+    let url = format!("file://{}", source.display());
+    let options = FetchOptions::new().ensure_fresh(false);
+    let clone = tmp.path().join("clone");
+    let repo = Repository::fetch_with_options(&url, &clone, &options).unwrap();
+    assert_eq!(Database::load_from_repo(&repo).unwrap().iter().count(), 2);
+
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD~1"])
+        .current_dir(&source)
+        .output()
+        .unwrap();
+    let first: CommitHash = String::from_utf8(output.stdout)
+        .unwrap()
+        .trim()
+        .parse()
+        .unwrap();
+
+    repo.checkout_commit(first).unwrap();
+    assert_eq!(repo.latest_commit().unwrap().commit_id, first);
+
+    let db = Database::load_from_repo(&repo).unwrap();
+    assert_eq!(db.iter().count(), 1);
+    assert!(db.get(&"RUSTSEC-2001-2101".parse().unwrap()).is_some());
+
+    let missing: CommitHash = "0123456789abcdef0123456789abcdef01234567".parse().unwrap();
+    let err = repo.checkout_commit(missing).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    assert!(err.to_string().contains("not found"));
+    drop(repo);
+
+    // Checking out modifies the worktree, so readers exclude it
+    let reader = Repository::open_with_read_lock(&clone, Duration::from_secs(0)).unwrap();
+    let err = Repository::open(&clone)
+        .unwrap()
+        .checkout_commit(first)
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::LockTimeout);
+    assert_eq!(
+        reader.checkout_commit(first).unwrap_err().kind(),
+        ErrorKind::LockTimeout
+    );
+    drop(reader);
+
+    let writer = Repository::open_with_write_lock(&clone, Duration::from_secs(0)).unwrap();
+    writer.checkout_commit(first).unwrap();
+    assert!(Repository::open_with_read_lock(&clone, Duration::from_secs(0)).is_err());
+}
+
+#[test]
+fn readers_and_fetch_coordinate() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    fs::create_dir_all(&source).unwrap();
+    create_source_repo(&source);
+
+    let url = format!("file://{}", source.display());
+    let clone = tmp.path().join("clone");
+    let options = FetchOptions::new()
+        .ensure_fresh(false)
+        .lock_timeout(Duration::from_secs(0));
+    Repository::fetch_with_options(&url, &clone, &options).unwrap();
+    assert!(Repository::open_with_read_lock(&clone, Duration::from_secs(0)).is_ok());
+
+    // Unless asked to release it, the fetched repository is locked until it's
+    // dropped, so it can be loaded before anyone else updates it
+    let fetched =
+        Repository::fetch_with_options(&url, &clone, &options.clone().keep_lock(true)).unwrap();
+    let err = Repository::open_with_read_lock(&clone, Duration::from_secs(0))
+        .err()
+        .expect("the fetched repository should still hold the lock");
+    assert_eq!(err.kind(), ErrorKind::LockTimeout);
+    drop(fetched);
+
+    // Any number of readers can hold the lock at once
+    let reader = Repository::open_with_read_lock(&clone, Duration::from_secs(0)).unwrap();
+    let other = Repository::open_with_read_lock(&clone, Duration::from_secs(0)).unwrap();
+
+    // ...but a fetch can't modify the checkout while it's being read
+    let err = Repository::fetch_with_options(&url, &clone, &options)
+        .err()
+        .expect("fetch should not acquire the lock while it is being read");
+    assert_eq!(err.kind(), ErrorKind::LockTimeout);
+    assert!(reader.has_relative_path(Path::new("crates/example/README.md")));
+    drop(other);
+
+    // A waiting fetch proceeds once the last reader is done, whether it
+    // started waiting before or after then
+    thread::scope(|scope| {
+        let fetch = scope.spawn(|| {
+            let options = options.clone().lock_timeout(Duration::from_secs(60));
+            Repository::fetch_with_options(&url, &clone, &options).map(|_| ())
+        });
+
+        assert!(reader.has_relative_path(Path::new("crates/example/README.md")));
+        drop(reader);
+
+        fetch.join().unwrap().unwrap();
+    });
+
+    assert!(Repository::open_with_read_lock(&clone, Duration::from_secs(0)).is_ok());
+}
+
+#[test]
+fn fetch_errors_have_distinct_kinds() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    fs::create_dir_all(&source).unwrap();
+    create_source_repo(&source);
+
+    let url = format!("file://{}", source.display());
+    let clone = tmp.path().join("clone");
+    let options = FetchOptions::new().ensure_fresh(false);
+    Repository::fetch_with_options(&url, &clone, &options).unwrap();
+
+    // Waiting for a lock which is never released times out
+    let reader = Repository::open_with_read_lock(&clone, Duration::from_secs(0)).unwrap();
+    let options = options.lock_timeout(Duration::from_millis(200));
+    let err = Repository::fetch_with_options(&url, &clone, &options)
+        .err()
+        .expect("fetch should time out waiting for the lock");
+    assert_eq!(err.kind(), ErrorKind::LockTimeout);
+    drop(reader);
+
+    let err = Repository::fetch_with_options("http://example.com/advisory-db", &clone, &options)
+        .err()
+        .expect("only https:// and file:// URLs are accepted");
+    assert_eq!(err.kind(), ErrorKind::BadParam);
+}
+
+#[test]
+fn advisories_added_since_commit() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    let package_dir = source.join("crates/base");
+    fs::create_dir_all(&package_dir).unwrap();
+
+    let advisory = fs::read_to_string("./tests/support/example_advisory_v3.md").unwrap();
+    git(&source, &["init", "--quiet"]);
+    fs::write(package_dir.join("RUSTSEC-2001-2101.md"), &advisory).unwrap();
+    git(&source, &["add", "."]);
+    git(
+        &source,
+        &["commit", "--quiet", "-m", "Add RUSTSEC-2001-2101"],
+    );
+
+    let url = format!("file://{}", source.display());
+    let clone = tmp.path().join("clone");
+    let options = FetchOptions::new().ensure_fresh(false);
+    let repo = Repository::fetch_with_options(&url, &clone, &options).unwrap();
+    let previous = repo.latest_commit().unwrap().commit_id;
+    assert!(repo.added_advisories(previous).unwrap().is_empty());
+
+    fs::write(
+        package_dir.join("RUSTSEC-2001-2102.md"),
+        advisory.replace("RUSTSEC-2001-2101", "RUSTSEC-2001-2102"),
+    )
+    .unwrap();
+    fs::write(
+        package_dir.join("RUSTSEC-2001-2101.md"),
+        advisory + "\nUpdated.\n",
+    )
+    .unwrap();
+    git(&source, &["add", "."]);
+    git(
+        &source,
+        &["commit", "--quiet", "-m", "Add RUSTSEC-2001-2102"],
+    );
+
+    let repo = Repository::fetch_with_options(&url, &clone, &options).unwrap();
+    let added = repo.added_advisories(previous).unwrap();
+    assert_eq!(added.len(), 1);
+    assert_eq!(added[0].as_str(), "RUSTSEC-2001-2102");
+
+    // Moving an advisory to another crate's directory doesn't make it new
+    let previous = repo.latest_commit().unwrap().commit_id;
+    fs::create_dir_all(source.join("crates/renamed")).unwrap();
+    git(
+        &source,
+        &[
+            "mv",
+            "crates/base/RUSTSEC-2001-2101.md",
+            "crates/renamed/RUSTSEC-2001-2101.md",
+        ],
+    );
+    git(
+        &source,
+        &["commit", "--quiet", "-m", "Move RUSTSEC-2001-2101"],
+    );
+
+    let repo = Repository::fetch_with_options(&url, &clone, &options).unwrap();
+    assert_eq!(repo.added_advisories(previous).unwrap(), vec![]);
+}
+
+#[test]
+fn custom_transport_is_used_for_clone_and_fetch() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    fs::create_dir_all(&source).unwrap();
+    create_source_repo(&source);
+
+    let url = format!("file://{}", source.display());
+    let requested = Arc::new(Mutex::new(Vec::new()));
+    let recorder = Arc::clone(&requested);
+    let options = FetchOptions::new()
+        .ensure_fresh(false)
+        .transport(move |url| {
+            recorder.lock().unwrap().push(url.to_owned());
+            gix::protocol::transport::connect(url, Default::default())
+                .map(Transport::new)
+                .map_err(|err| Error::new(ErrorKind::Repo, &err))
+        });
+
+    let clone = tmp.path().join("clone");
+    Repository::fetch_with_options(&url, &clone, &options).unwrap();
+    assert!(clone.join("crates/example/README.md").exists());
+    assert_eq!(*requested.lock().unwrap(), [url.as_str()]);
+
+    fs::write(source.join("crates/example/CHANGELOG.md"), "changes\n").unwrap();
+    git(&source, &["add", "."]);
+    git(&source, &["commit", "--quiet", "-m", "Add changelog"]);
+
+    Repository::fetch_with_options(&url, &clone, &options).unwrap();
+    assert!(clone.join("crates/example/CHANGELOG.md").exists());
+    assert_eq!(*requested.lock().unwrap(), [url.clone(), url]);
+}
+
+#[test]
+fn orphaned_advisories_in_worktree() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    let package_dir = source.join("crates/base");
+    fs::create_dir_all(&package_dir).unwrap();
+
+    let advisory = fs::read_to_string("./tests/support/example_advisory_v3.md").unwrap();
+    git(&source, &["init", "--quiet"]);
+    fs::write(package_dir.join("RUSTSEC-2001-2101.md"), &advisory).unwrap();
+    git(&source, &["add", "."]);
+    git(
+        &source,
+        &["commit", "--quiet", "-m", "Add RUSTSEC-2001-2101"],
+    );
+
+    let url = format!("file://{}", source.display());
+    let clone = tmp.path().join("clone");
+    let options = FetchOptions::new().ensure_fresh(false);
+    let repo = Repository::fetch_with_options(&url, &clone, &options).unwrap();
+    assert!(repo.orphaned_advisories().unwrap().is_empty());
+
+    // An advisory left behind in the checkout, e.g. after it was removed upstream
+    fs::write(
+        clone.join("crates/base/RUSTSEC-2001-2102.md"),
+        advisory.replace("RUSTSEC-2001-2101", "RUSTSEC-2001-2102"),
+    )
+    .unwrap();
+
+    let orphaned = repo.orphaned_advisories().unwrap();
+    assert_eq!(orphaned, [Path::new("crates/base/RUSTSEC-2001-2102.md")]);
+
+    let db = Database::load_from_repo(&repo).unwrap();
+    assert_eq!(db.iter().count(), 2);
+    assert_eq!(db.orphaned_advisories(), orphaned.as_slice());
+}
+
+#[test]
+fn interrupted_clones_are_recovered() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    fs::create_dir_all(&source).unwrap();
+    create_source_repo(&source);
+
+    let url = format!("file://{}", source.display());
+    let options = FetchOptions::new().ensure_fresh(false);
+
+    // Interrupted before `origin` was configured: there's nothing to resume
+    let unconfigured = tmp.path().join("unconfigured");
+    fs::create_dir_all(&unconfigured).unwrap();
+    git(&unconfigured, &["init", "--quiet"]);
+
+    // Interrupted while the `.git` directory was being written
+    let uninitialized = tmp.path().join("uninitialized");
+    fs::create_dir_all(uninitialized.join(".git/objects")).unwrap();
+
+    // Interrupted after `origin` was configured, so fetching resumes the clone
+    let resumable = tmp.path().join("resumable");
+    fs::create_dir_all(&resumable).unwrap();
+    git(&resumable, &["init", "--quiet"]);
+    git(&resumable, &["remote", "add", "origin", &url]);
+
+    for path in [unconfigured, uninitialized, resumable] {
+        let repo = Repository::fetch_with_options(&url, &path, &options).unwrap();
+        assert!(repo.latest_commit().is_ok());
+        assert!(path.join("crates/example/README.md").exists());
+    }
+}
+
+#[test]
+fn repositories_which_fail_to_open_are_kept() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    fs::create_dir_all(&source).unwrap();
+    create_source_repo(&source);
+
+    // A checkout whose config can't be parsed
+    let url = format!("file://{}", source.display());
+    let broken = tmp.path().join("broken");
+    fs::create_dir_all(&broken).unwrap();
+    git(&broken, &["init", "--quiet"]);
+    git(&broken, &["remote", "add", "origin", &url]);
+    let config = broken.join(".git/config");
+    let mut contents = fs::read_to_string(&config).unwrap();
+    contents.push_str("[broken\n");
+    fs::write(&config, contents).unwrap();
+    fs::write(broken.join("notes.txt"), "keep me\n").unwrap();
+
+    let options = FetchOptions::new().ensure_fresh(false);
+    assert!(Repository::fetch_with_options(&url, &broken, &options).is_err());
+    assert!(broken.join("notes.txt").exists());
+}
+
+#[test]
+fn url_mismatch_policies() {
+    let tmp = tempdir().unwrap();
+    let original = tmp.path().join("original");
+    let mirror = tmp.path().join("mirror");
+    for source in [&original, &mirror] {
+        fs::create_dir_all(source).unwrap();
+        create_source_repo(source);
+    }
+    // Give the mirror a commit the original doesn't have
+    fs::write(mirror.join("crates/example/README.md"), "mirrored\n").unwrap();
+    git(&mirror, &["commit", "--quiet", "-am", "Update example"]);
+
+    let original_url = format!("file://{}", original.display());
+    let mirror_url = format!("file://{}", mirror.display());
+    let options = FetchOptions::new().ensure_fresh(false);
+    let origin_of = |path: &Path| {
+        let repo = gix::open(path).unwrap();
+        let remote = repo.find_remote("origin").unwrap();
+        remote
+            .url(gix::remote::Direction::Fetch)
+            .unwrap()
+            .to_bstring()
+            .to_string()
+    };
+
+    for policy in [
+        UrlMismatch::Error,
+        UrlMismatch::Reclone,
+        UrlMismatch::UpdateRemote,
+    ] {
+        let clone = tmp.path().join(format!("{:?}", policy));
+        Repository::fetch_with_options(&original_url, &clone, &options).unwrap();
+
+        let result = Repository::fetch_with_options(
+            &mirror_url,
+            &clone,
+            &options.clone().url_mismatch(policy),
+        );
+        let readme = fs::read_to_string(clone.join("crates/example/README.md")).unwrap();
+
+        match policy {
+            UrlMismatch::Error => {
+                // The existing clone is left as it was
+                assert_eq!(result.err().unwrap().kind(), ErrorKind::Repo);
+                assert_eq!(origin_of(&clone), original_url);
+                assert_eq!(readme, "example\n");
+            }
+            _ => {
+                assert!(result.is_ok(), "{:?} failed: {:?}", policy, result.err());
+                assert_eq!(origin_of(&clone), mirror_url);
+                assert_eq!(readme, "mirrored\n");
+                assert!(!clone.join(".git/config.lock").exists());
+            }
+        }
+    }
+
+    // Fetching without a policy is the same as `UrlMismatch::Error`
+    let clone = tmp.path().join("Error");
+    let err = Repository::fetch_with_options(&mirror_url, &clone, &options)
+        .err()
+        .expect("fetching from a different URL should fail by default");
+    assert_eq!(err.kind(), ErrorKind::Repo);
+}
+
+#[test]
+fn shallow_clone_loads_current_database() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    let package_dir = source.join("crates/base");
+    fs::create_dir_all(&package_dir).unwrap();
+
+    let advisory = fs::read_to_string("./tests/support/example_advisory_v3.md").unwrap();
+    git(&source, &["init", "--quiet"]);
+    for id in [
+        "RUSTSEC-2001-2101",
+        "RUSTSEC-2001-2102",
+        "RUSTSEC-2001-2103",
+    ] {
+        fs::write(
+            package_dir.join(format!("{}.md", id)),
+            advisory.replace("RUSTSEC-2001-2101", id),
+        )
+        .unwrap();
+        git(&source, &["add", "."]);
+        git(
+            &source,
+            &["commit", "--quiet", "-m", &format!("Add {}", id)],
+        );
+    }
+
+    let url = format!("file://{}", source.display());
+    let clone = tmp.path().join("clone");
+    let options = FetchOptions::new().ensure_fresh(false).shallow(true);
+    let repo = Repository::fetch_with_options(&url, &clone, &options).unwrap();
+
+    // Only the latest commit was fetched, but every current advisory is there
+    assert!(clone.join(".git/shallow").exists());
+    assert_eq!(Database::load_from_repo(&repo).unwrap().iter().count(), 3);
+    let first_commit = repo.latest_commit().unwrap().commit_id;
+    drop(repo);
+
+    fs::write(
+        package_dir.join("RUSTSEC-2001-2104.md"),
+        advisory.replace("RUSTSEC-2001-2101", "RUSTSEC-2001-2104"),
+    )
+    .unwrap();
+    git(&source, &["add", "."]);
+    git(
+        &source,
+        &["commit", "--quiet", "-m", "Add RUSTSEC-2001-2104"],
+    );
+
+    let repo = Repository::fetch_with_options(&url, &clone, &options).unwrap();
+    assert_eq!(Database::load_from_repo(&repo).unwrap().iter().count(), 4);
+
+    // Operations needing the history refuse to run rather than giving
+    // incomplete results
+    for err in [
+        repo.added_advisories(first_commit).unwrap_err(),
+        repo.checkout_commit(first_commit).unwrap_err(),
+    ] {
+        assert_eq!(err.kind(), ErrorKind::Repo);
+        assert!(err.to_string().contains("shallow clone"));
+    }
+}
+
+#[test]
+fn fetch_reports_how_the_repository_was_obtained() {
+    let tmp = tempdir().unwrap();
+    let source = tmp.path().join("source");
+    fs::create_dir_all(&source).unwrap();
+    create_source_repo(&source);
+
+    let url = format!("file://{}", source.display());
+    let clone = tmp.path().join("clone");
+
+    let repo = Repository::fetch(&url, &clone, false, Duration::from_secs(0)).unwrap();
+    assert_eq!(repo.fetch_status(), Some(FetchStatus::FreshClone));
+    drop(repo);
+
+    let repo = Repository::fetch(&url, &clone, false, Duration::from_secs(0)).unwrap();
+    assert_eq!(repo.fetch_status(), Some(FetchStatus::UpToDate));
+    drop(repo);
+
+    fs::write(source.join("crates/example/README.md"), "updated\n").unwrap();
+    git(&source, &["commit", "--quiet", "-am", "Update example"]);
+
+    let repo = Repository::fetch(&url, &clone, false, Duration::from_secs(0)).unwrap();
+    assert_eq!(repo.fetch_status(), Some(FetchStatus::Fetched));
+    drop(repo);
+
+    assert_eq!(Repository::open(&clone).unwrap().fetch_status(), None);
+}
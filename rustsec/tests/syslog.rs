@@ -0,0 +1,52 @@
+//! Tests for emitting findings as syslog messages
+#![cfg(feature = "syslog")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::{
+    report::Settings,
+    syslog::{self, Level, Message, Sink},
+    Database, Lockfile, Report,
+};
+use std::{fs, io};
+use tempfile::tempdir;
+
+/// Sink which records the messages sent to it
+#[derive(Default)]
+struct MockSink(Vec<Message>);
+
+impl Sink for MockSink {
+    fn send(&mut self, message: &Message) -> io::Result<()> {
+        self.0.push(message.clone());
+        Ok(())
+    }
+}
+
+#[test]
+fn one_message_per_finding() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("crates/base")).unwrap();
+    fs::copy(
+        "./tests/support/example_advisory_v3.md",
+        dir.path().join("crates/base/RUSTSEC-2001-2101.md"),
+    )
+    .unwrap();
+
+    let db = Database::open(dir.path()).unwrap();
+    let lockfile = Lockfile::load("./tests/support/transitive_cargo.lock").unwrap();
+    let report = Report::generate(&db, &lockfile, &Settings::default());
+
+    let mut sink = MockSink::default();
+    let count = syslog::emit(&report, &mut sink).unwrap();
+
+    assert_eq!(count, 1);
+    assert_eq!(sink.0.len(), 1);
+
+    // The advisory's CVSS vector has a base score of 10.0
+    let message = &sink.0[0];
+    assert_eq!(message.level, Level::Critical);
+    assert_eq!(message.id.as_ref().unwrap().as_str(), "RUSTSEC-2001-2101");
+    assert_eq!(message.package.name.as_str(), "base");
+    assert!(message
+        .to_rfc5424()
+        .starts_with("<10>1 - - rustsec - VULNERABILITY - RUSTSEC-2001-2101 base 1.0.0: "));
+}
@@ -0,0 +1,86 @@
+//! Tests for the diagnostics emitted with the `tracing` feature
+#![cfg(feature = "tracing")]
+#![warn(rust_2018_idioms, unused_qualifications)]
+
+use rustsec::Database;
+use std::{
+    fmt, fs,
+    sync::{Arc, Mutex},
+};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+/// Name and fields of a recorded event
+type RecordedEvent = (String, Vec<(String, String)>);
+
+/// Subscriber which records every event it receives
+#[derive(Clone, Default)]
+struct Recorder {
+    events: Arc<Mutex<Vec<RecordedEvent>>>,
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        self.events
+            .lock()
+            .unwrap()
+            .push((event.metadata().name().to_owned(), fields.0));
+    }
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+/// Visitor collecting the fields of an event as strings
+#[derive(Default)]
+struct Fields(Vec<(String, String)>);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .push((field.name().to_owned(), format!("{:?}", value)));
+    }
+}
+
+#[test]
+fn db_load_event() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("crates/base")).unwrap();
+    fs::write(
+        dir.path().join("crates/base/RUSTSEC-2001-2101.md"),
+        include_str!("support/example_advisory_v4.md"),
+    )
+    .unwrap();
+
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        Database::open(dir.path()).unwrap();
+    });
+
+    let events = recorder.events.lock().unwrap();
+    let (_, fields) = events
+        .iter()
+        .find(|(name, _)| name == "db.load")
+        .expect("no db.load event emitted");
+
+    assert!(fields
+        .iter()
+        .any(|(name, value)| name == "count" && value == "1"));
+}